@@ -0,0 +1,307 @@
+// Generate TOS address encoding test vectors (encode + decode round-trip)
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_address_encoding_vectors
+//
+// Address format (Bech32, BIP-173 checksum):
+//   hrp:  "tos" (mainnet) or "tst" (testnet)
+//   data: convert_bits([address_type:u8 = 0x00 (Normal)][public_key:32], 8 -> 5, pad)
+//
+// Deterministic keys use the TOS key derivation: public = private^-1 * H,
+// where H is the Pedersen blinding generator.
+//
+// Malformed vectors must be rejected by every decoder.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::scalar::Scalar;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+/// Bech32 constants
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+const MAINNET_PREFIX: &str = "tos";
+const TESTNET_PREFIX: &str = "tst";
+const ADDRESS_TYPE_NORMAL: u8 = 0;
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 25;
+        chk = (chk & 0x1ffffff) << 5 ^ *value as u32;
+        for (i, item) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= item;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut result: Vec<u8> = Vec::new();
+    for c in hrp.bytes() {
+        result.push(c >> 5);
+    }
+    result.push(0);
+    for c in hrp.bytes() {
+        result.push(c & 31);
+    }
+    result
+}
+
+fn create_checksum(hrp: &str, data: &[u8]) -> [u8; 6] {
+    let mut values: Vec<u8> = Vec::new();
+    values.extend(hrp_expand(hrp));
+    values.extend(data);
+    let mut result: [u8; 6] = [0; 6];
+    values.extend(&result);
+    let polymod = polymod(&values) ^ 1;
+    for (i, byte) in result.iter_mut().enumerate() {
+        *byte = (polymod >> (5 * (5 - i)) & 31) as u8;
+    }
+    result
+}
+
+fn convert_bits(data: &[u8], from: u16, to: u16, pad: bool) -> Result<Vec<u8>, String> {
+    let mut acc: u16 = 0;
+    let mut bits: u16 = 0;
+    let mut result: Vec<u8> = vec![];
+    let max_value = (1 << to) - 1;
+    for v in data {
+        let value = *v as u16;
+        if value >> from != 0 {
+            return Err(format!("invalid {}-bit value {}", from, value));
+        }
+        acc = ((acc << from) | value) & 0x0fff;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max_value) != 0 {
+        return Err("invalid padding".to_string());
+    }
+    Ok(result)
+}
+
+fn encode(hrp: &str, data: &[u8]) -> String {
+    let hrp = hrp.to_lowercase();
+    let mut combined: Vec<u8> = Vec::new();
+    combined.extend(data);
+    combined.extend(&create_checksum(&hrp, data));
+
+    let mut result = hrp.clone();
+    result.push('1');
+    for value in combined.iter() {
+        result.push(CHARSET.chars().nth(*value as usize).unwrap());
+    }
+    result
+}
+
+/// Decode a Bech32 string into (hrp, 5-bit data without checksum).
+fn decode(address: &str) -> Result<(String, Vec<u8>), String> {
+    let separator = address.rfind('1').ok_or("missing separator")?;
+    if separator == 0 || separator + 7 > address.len() {
+        return Err("invalid separator position".to_string());
+    }
+    let hrp = &address[..separator];
+    let mut data = Vec::new();
+    for c in address[separator + 1..].chars() {
+        let value = CHARSET
+            .find(c)
+            .ok_or(format!("invalid character '{}'", c))?;
+        data.push(value as u8);
+    }
+
+    let mut values = hrp_expand(hrp);
+    values.extend(&data);
+    if polymod(&values) != 1 {
+        return Err("invalid checksum".to_string());
+    }
+    data.truncate(data.len() - 6);
+    Ok((hrp.to_string(), data))
+}
+
+fn encode_address(public_key: &[u8; 32], mainnet: bool) -> String {
+    let mut raw = vec![ADDRESS_TYPE_NORMAL];
+    raw.extend_from_slice(public_key);
+    let data_5bit = convert_bits(&raw, 8, 5, true).unwrap();
+    let hrp = if mainnet {
+        MAINNET_PREFIX
+    } else {
+        TESTNET_PREFIX
+    };
+    encode(hrp, &data_5bit)
+}
+
+/// Decode an address back to its public key, enforcing HRP, type and length.
+fn decode_address(address: &str) -> Result<(bool, [u8; 32]), String> {
+    let (hrp, data_5bit) = decode(address)?;
+    let mainnet = match hrp.as_str() {
+        MAINNET_PREFIX => true,
+        TESTNET_PREFIX => false,
+        other => return Err(format!("invalid prefix '{}'", other)),
+    };
+    let raw = convert_bits(&data_5bit, 5, 8, false)?;
+    if raw.len() != 33 {
+        return Err(format!("invalid payload length {}", raw.len()));
+    }
+    if raw[0] != ADDRESS_TYPE_NORMAL {
+        return Err(format!("unsupported address type {}", raw[0]));
+    }
+    let mut public_key = [0u8; 32];
+    public_key.copy_from_slice(&raw[1..]);
+    Ok((mainnet, public_key))
+}
+
+#[derive(Serialize)]
+struct AddressVector {
+    name: String,
+    description: String,
+    mainnet: bool,
+    pubkey_hex: String,
+    address_string: String,
+    decoded_pubkey_hex: String,
+}
+
+#[derive(Serialize)]
+struct InvalidAddressVector {
+    name: String,
+    description: String,
+    address_string: String,
+    expected_error: String,
+}
+
+#[derive(Serialize)]
+struct AddressEncodingTestFile {
+    algorithm: String,
+    mainnet_prefix: String,
+    testnet_prefix: String,
+    address_vectors: Vec<AddressVector>,
+    invalid_vectors: Vec<InvalidAddressVector>,
+}
+
+fn address_vector(
+    name: &str,
+    description: &str,
+    public_key: [u8; 32],
+    mainnet: bool,
+) -> AddressVector {
+    let address = encode_address(&public_key, mainnet);
+    let (decoded_mainnet, decoded) = decode_address(&address).expect("round-trip decode failed");
+    assert_eq!(decoded_mainnet, mainnet, "{}: network mismatch", name);
+    assert_eq!(decoded, public_key, "{}: public key mismatch", name);
+
+    AddressVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        mainnet,
+        pubkey_hex: hex::encode(public_key),
+        address_string: address,
+        decoded_pubkey_hex: hex::encode(decoded),
+    }
+}
+
+fn invalid_vector(name: &str, description: &str, address: String) -> InvalidAddressVector {
+    let error = decode_address(&address).expect_err("malformed address decoded successfully");
+    InvalidAddressVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        address_string: address,
+        expected_error: error,
+    }
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+    let mut address_vectors = Vec::new();
+
+    // Fixed byte patterns
+    address_vectors.push(address_vector(
+        "zero_pubkey_mainnet",
+        "All-zeros public key on mainnet",
+        [0u8; 32],
+        true,
+    ));
+    address_vectors.push(address_vector(
+        "all_ones_pubkey_mainnet",
+        "All-ones (0xFF) public key on mainnet",
+        [0xFFu8; 32],
+        true,
+    ));
+    address_vectors.push(address_vector(
+        "all_ones_pubkey_testnet",
+        "All-ones (0xFF) public key on testnet",
+        [0xFFu8; 32],
+        false,
+    ));
+
+    // Deterministic keys derived from seed bytes (private = [seed, 0, ..., 0])
+    for seed in [1u8, 2, 3, 42] {
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = seed;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let public_key = (private_key.invert() * h).compress().to_bytes();
+        address_vectors.push(address_vector(
+            &format!("seed_{}_mainnet", seed),
+            &format!("Public key derived from seed byte {}", seed),
+            public_key,
+            true,
+        ));
+    }
+
+    // Malformed addresses, all built from a valid mainnet address
+    let valid = encode_address(&[0xFFu8; 32], true);
+    let mut invalid_vectors = Vec::new();
+
+    {
+        let last = valid.chars().last().unwrap();
+        let replacement = if last == 'q' { 'p' } else { 'q' };
+        let mut address = valid.clone();
+        address.pop();
+        address.push(replacement);
+        invalid_vectors.push(invalid_vector(
+            "wrong_checksum",
+            "Last checksum character altered",
+            address,
+        ));
+    }
+    {
+        // Valid Bech32 checksum over a foreign HRP: only the prefix check rejects it
+        let (_, data_5bit) = decode(&valid).unwrap();
+        invalid_vectors.push(invalid_vector(
+            "wrong_hrp",
+            "Well-formed Bech32 with the 'bc' prefix",
+            encode("bc", &data_5bit),
+        ));
+    }
+    {
+        let address = valid[..valid.len() - 5].to_string();
+        invalid_vectors.push(invalid_vector(
+            "truncated",
+            "Last five characters removed",
+            address,
+        ));
+    }
+
+    let test_file = AddressEncodingTestFile {
+        algorithm: "TOS-Address-Bech32".to_string(),
+        mainnet_prefix: MAINNET_PREFIX.to_string(),
+        testnet_prefix: TESTNET_PREFIX.to_string(),
+        address_vectors,
+        invalid_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("address_encoding.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to address_encoding.yaml");
+}
@@ -0,0 +1,313 @@
+// gen_agent_session_key_vectors.rs - AgentAccount (Type 23) session key vectors
+//
+// An agent account delegates limited operations to session keys. The account
+// commits to its active key set through `session_key_root`:
+//
+//   session_root = BLAKE3(SessionKey[0] || SessionKey[1] || ...)
+//   keys ordered by ascending key_id, each in its wire encoding below
+//
+// Wire formats (Big-Endian):
+//
+// SessionKey:
+//   key_id:               u64 BE
+//   public_key:           32 bytes
+//   expiry_topoheight:    u64 BE
+//   max_value_per_window: u64 BE
+//   allowed_targets:      u16 count + 32-byte public keys...
+//   allowed_assets:       u16 count + 32-byte hashes...
+//
+// AgentAccountPayload (u8 variant tag + body):
+//   0 Register:          controller(32) policy_hash(32) Option<energy_pool(32)> Option<session_key_root(32)>
+//   5 SetSessionKeyRoot: Option<session_key_root(32)>
+//   6 AddSessionKey:     SessionKey
+//   7 RevokeSessionKey:  key_id u64 BE
+//
+// scope_bits summarises the key's restrictions for readers (not on the wire):
+//   bit 0 VALUE    - may move value (max_value_per_window > 0)
+//   bit 1 TARGETS  - restricted to allowed_targets
+//   bit 2 ASSETS   - restricted to allowed_assets
+//   bit 3 EXPIRING - expiry_topoheight < u64::MAX
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{AgentAccountPayload, SessionKey};
+
+const SCOPE_VALUE: u8 = 1 << 0;
+const SCOPE_TARGETS: u8 = 1 << 1;
+const SCOPE_ASSETS: u8 = 1 << 2;
+const SCOPE_EXPIRING: u8 = 1 << 3;
+
+#[derive(Serialize)]
+struct SessionKeyVector {
+    name: String,
+    description: String,
+    variant: String,
+    controller_hex: Option<String>,
+    session_key_hex: Option<String>,
+    key_id: Option<u64>,
+    session_root_hex: Option<String>,
+    scope_bits: Option<u8>,
+    wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct SessionKeyTestVectors {
+    algorithm: String,
+    version: u32,
+    session_key_vectors: Vec<SessionKeyVector>,
+}
+
+fn test_pubkey(seed: u8) -> CompressedPublicKey {
+    CompressedPublicKey::from_bytes(&[seed; 32]).expect("Valid pubkey bytes")
+}
+
+fn scope_bits(key: &SessionKey) -> u8 {
+    let mut bits = 0;
+    if key.max_value_per_window > 0 {
+        bits |= SCOPE_VALUE;
+    }
+    if !key.allowed_targets.is_empty() {
+        bits |= SCOPE_TARGETS;
+    }
+    if !key.allowed_assets.is_empty() {
+        bits |= SCOPE_ASSETS;
+    }
+    if key.expiry_topoheight < u64::MAX {
+        bits |= SCOPE_EXPIRING;
+    }
+    bits
+}
+
+fn session_root(keys: &[SessionKey]) -> Hash {
+    let mut sorted: Vec<&SessionKey> = keys.iter().collect();
+    sorted.sort_by_key(|k| k.key_id);
+    let mut hasher = blake3::Hasher::new();
+    for key in sorted {
+        hasher.update(&key.to_bytes());
+    }
+    Hash::new(*hasher.finalize().as_bytes())
+}
+
+fn read_only_key() -> SessionKey {
+    SessionKey {
+        key_id: 1,
+        public_key: test_pubkey(0xA1),
+        expiry_topoheight: u64::MAX,
+        max_value_per_window: 0,
+        allowed_targets: Vec::new(),
+        allowed_assets: Vec::new(),
+    }
+}
+
+fn write_limited_key() -> SessionKey {
+    SessionKey {
+        key_id: 2,
+        public_key: test_pubkey(0xA2),
+        expiry_topoheight: u64::MAX,
+        max_value_per_window: 5_000_000_000, // 50 TOS per window
+        allowed_targets: vec![test_pubkey(0xB1), test_pubkey(0xB2)],
+        allowed_assets: vec![Hash::zero()],
+    }
+}
+
+fn time_limited_key() -> SessionKey {
+    SessionKey {
+        key_id: 3,
+        public_key: test_pubkey(0xA3),
+        expiry_topoheight: 1_000_000,
+        max_value_per_window: 100_000_000, // 1 TOS per window
+        allowed_targets: Vec::new(),
+        allowed_assets: Vec::new(),
+    }
+}
+
+fn payload_vector(
+    name: &str,
+    description: &str,
+    variant: &str,
+    payload: &AgentAccountPayload,
+    controller: Option<&CompressedPublicKey>,
+    session_key: Option<&SessionKey>,
+    key_id: Option<u64>,
+    root: Option<&Hash>,
+) -> SessionKeyVector {
+    let wire = payload.to_bytes();
+    SessionKeyVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        variant: variant.to_string(),
+        controller_hex: controller.map(|c| hex::encode(c.as_bytes())),
+        session_key_hex: session_key.map(|k| hex::encode(k.public_key.as_bytes())),
+        key_id,
+        session_root_hex: root.map(|r| hex::encode(r.as_bytes())),
+        scope_bits: session_key.map(scope_bits),
+        wire_hex: hex::encode(&wire),
+        expected_size: payload.size(),
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+    let controller = test_pubkey(0xC0);
+    let keys = vec![read_only_key(), write_limited_key(), time_limited_key()];
+
+    // ========================================================================
+    // Session root derivation + Register
+    // ========================================================================
+
+    // Input order must not matter: the root sorts by key_id
+    let root = session_root(&keys);
+    let reversed: Vec<SessionKey> = keys.iter().rev().cloned().collect();
+    assert_eq!(
+        root,
+        session_root(&reversed),
+        "session root depends on input order"
+    );
+
+    {
+        let payload = AgentAccountPayload::Register {
+            controller: controller.clone(),
+            policy_hash: Hash::new([0x50; 32]),
+            energy_pool: None,
+            session_key_root: Some(root.clone()),
+        };
+        vectors.push(payload_vector(
+            "register_with_session_root",
+            "Register an agent account committing to three session keys",
+            "Register",
+            &payload,
+            Some(&controller),
+            None,
+            None,
+            Some(&root),
+        ));
+    }
+
+    // ========================================================================
+    // AddSessionKey for each scope
+    // ========================================================================
+
+    for (name, description, key) in [
+        (
+            "add_session_key_read_only",
+            "Read-only key: no value, no restrictions, never expires",
+            &keys[0],
+        ),
+        (
+            "add_session_key_write_limited",
+            "Write-limited key: 50 TOS per window to two targets, TOS only",
+            &keys[1],
+        ),
+        (
+            "add_session_key_time_limited",
+            "Time-limited key: 1 TOS per window, expires at topoheight 1000000",
+            &keys[2],
+        ),
+    ] {
+        let payload = AgentAccountPayload::AddSessionKey { key: key.clone() };
+        vectors.push(payload_vector(
+            name,
+            description,
+            "AddSessionKey",
+            &payload,
+            Some(&controller),
+            Some(key),
+            Some(key.key_id),
+            None,
+        ));
+    }
+
+    // ========================================================================
+    // RevokeSessionKey
+    // ========================================================================
+
+    {
+        let revoked = &keys[1];
+        let payload = AgentAccountPayload::RevokeSessionKey {
+            key_id: revoked.key_id,
+        };
+        vectors.push(payload_vector(
+            "revoke_session_key",
+            "Revoke the write-limited key by key_id",
+            "RevokeSessionKey",
+            &payload,
+            Some(&controller),
+            Some(revoked),
+            Some(revoked.key_id),
+            None,
+        ));
+    }
+
+    // ========================================================================
+    // SetSessionKeyRoot (after revocation, and cleared)
+    // ========================================================================
+
+    {
+        let remaining: Vec<SessionKey> = keys.iter().filter(|k| k.key_id != 2).cloned().collect();
+        let new_root = session_root(&remaining);
+        let payload = AgentAccountPayload::SetSessionKeyRoot {
+            session_key_root: Some(new_root.clone()),
+        };
+        vectors.push(payload_vector(
+            "set_session_key_root",
+            "Root over the remaining keys (1 and 3) after revoking key 2",
+            "SetSessionKeyRoot",
+            &payload,
+            Some(&controller),
+            None,
+            None,
+            Some(&new_root),
+        ));
+    }
+    {
+        let payload = AgentAccountPayload::SetSessionKeyRoot {
+            session_key_root: None,
+        };
+        vectors.push(payload_vector(
+            "clear_session_key_root",
+            "Clear the session key root (no delegated keys)",
+            "SetSessionKeyRoot",
+            &payload,
+            Some(&controller),
+            None,
+            None,
+            None,
+        ));
+    }
+
+    // ========================================================================
+    // Write Output
+    // ========================================================================
+
+    let test_file = SessionKeyTestVectors {
+        algorithm: "Agent-Session-Keys".to_string(),
+        version: 1,
+        session_key_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# Agent Account Session Key Test Vectors (Type 23: AgentAccount)
+# Generated by TOS Rust - gen_agent_session_key_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# Session root:
+#   BLAKE3(SessionKey... ordered by ascending key_id)
+#
+# scope_bits (derived, not on the wire):
+#   0x01 VALUE, 0x02 TARGETS, 0x04 ASSETS, 0x08 EXPIRING
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("agent_session_keys.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to agent_session_keys.yaml");
+}
@@ -0,0 +1,96 @@
+// Generate ExpertiseDomain discriminant test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_arbiter_expertise_domain_vectors
+//
+// ExpertiseDomain is a single u8 on the wire (General = 0 through Nft = 13).
+// In RegisterArbiterPayload / UpdateArbiterPayload the domains are a
+// u8-counted list (tos_signer write_expertise_domains):
+//
+//   [count:u8][domain:u8]...
+//
+// Values 14..=255 must fail deserialization. Discriminants are stored in
+// arbiter state, so inserting a variant anywhere but the end changes the
+// meaning of existing data; these vectors pin every value.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::arbitration::ExpertiseDomain;
+use tos_common::serializer::Serializer;
+
+/// Highest known discriminant (Nft).
+const MAX_EXPERTISE_DOMAIN: u8 = 13;
+
+#[derive(Serialize)]
+struct ExpertiseDomainVector {
+    domain_name: Option<String>,
+    domain_value: u8,
+    wire_byte: String,
+    is_known: bool,
+    domains_field_hex: String,
+}
+
+#[derive(Serialize)]
+struct ExpertiseDomainTestFile {
+    algorithm: String,
+    variant_count: usize,
+    expertise_domain_vectors: Vec<ExpertiseDomainVector>,
+}
+
+fn make_vector(value: u8) -> ExpertiseDomainVector {
+    let decoded = ExpertiseDomain::from_bytes(&[value]);
+    let is_known = decoded.is_ok();
+    assert_eq!(
+        is_known,
+        value <= MAX_EXPERTISE_DOMAIN,
+        "domain {}: TOS Rust acceptance disagrees with General..=Nft",
+        value
+    );
+
+    let domain_name = decoded.ok().map(|domain| {
+        assert_eq!(
+            domain.to_bytes(),
+            vec![value],
+            "domain {}: re-encoding mismatch",
+            value
+        );
+        format!("{:?}", domain)
+    });
+
+    ExpertiseDomainVector {
+        domain_name,
+        domain_value: value,
+        wire_byte: hex::encode([value]),
+        is_known,
+        domains_field_hex: hex::encode([1, value]),
+    }
+}
+
+fn main() {
+    let mut vectors: Vec<ExpertiseDomainVector> =
+        (0..=MAX_EXPERTISE_DOMAIN).map(make_vector).collect();
+    assert_eq!(vectors[0].domain_name.as_deref(), Some("General"));
+    assert_eq!(
+        vectors[MAX_EXPERTISE_DOMAIN as usize]
+            .domain_name
+            .as_deref(),
+        Some("Nft")
+    );
+
+    // First unassigned value and the largest byte.
+    vectors.push(make_vector(MAX_EXPERTISE_DOMAIN + 1));
+    vectors.push(make_vector(u8::MAX));
+
+    let test_file = ExpertiseDomainTestFile {
+        algorithm: "TOS-ExpertiseDomain".to_string(),
+        variant_count: MAX_EXPERTISE_DOMAIN as usize + 1,
+        expertise_domain_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("expertise_domain.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to expertise_domain.yaml");
+}
@@ -0,0 +1,135 @@
+// Generate ArbiterStatus discriminant test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_arbiter_status_vectors
+//
+// ArbiterStatus is a single u8 on the wire:
+//
+//   0 = Active, 1 = Suspended, 2 = Exiting, 3 = Removed
+//
+// UpdateArbiterPayload (type 34) carries it as bit 6 of the presence flags
+// (tos_signer encode_update_arbiter_payload_inner). A status-only update is:
+//
+//   [flags:u8 = 0x40][status:u8]
+//
+// `wire_hex` for the valid statuses is the TOS Rust serialization of a
+// status-only UpdateArbiterPayload. Values 4 and 255 cannot be built as an
+// ArbiterStatus, so their wire is the Active payload with the status byte
+// patched, and UpdateArbiterPayload::from_bytes must reject it
+// (`expected_valid: false`).
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::arbitration::ArbiterStatus;
+use tos_common::serializer::Serializer;
+use tos_common::transaction::UpdateArbiterPayload;
+
+const TX_TYPE_UPDATE_ARBITER: u8 = 34;
+const FLAG_STATUS: u8 = 1 << 6;
+/// Highest known discriminant (Removed).
+const MAX_ARBITER_STATUS: u8 = 3;
+
+#[derive(Serialize)]
+struct ArbiterStatusVector {
+    status_name: Option<String>,
+    status_byte: u8,
+    wire_hex: String,
+    expected_valid: bool,
+}
+
+#[derive(Serialize)]
+struct ArbiterStatusTestFile {
+    algorithm: String,
+    tx_type_id: u8,
+    status_flag: u8,
+    arbiter_status_vectors: Vec<ArbiterStatusVector>,
+}
+
+/// Serialize a status-only UpdateArbiterPayload with TOS Rust.
+fn status_only_payload(status: ArbiterStatus) -> Vec<u8> {
+    let payload =
+        UpdateArbiterPayload::new(None, None, None, None, None, None, Some(status), false);
+    let bytes = payload.to_bytes();
+    assert_eq!(
+        bytes.len(),
+        2,
+        "{:?}: status-only payload must be 2 bytes",
+        status
+    );
+    assert_eq!(
+        bytes[0], FLAG_STATUS,
+        "{:?}: only the status flag may be set",
+        status
+    );
+    let decoded = UpdateArbiterPayload::from_bytes(&bytes).expect("payload must round-trip");
+    assert_eq!(
+        decoded.to_bytes(),
+        bytes,
+        "{:?}: re-encoding mismatch",
+        status
+    );
+    bytes
+}
+
+fn make_vector(status_byte: u8) -> ArbiterStatusVector {
+    let decoded = ArbiterStatus::from_bytes(&[status_byte]);
+    let expected_valid = decoded.is_ok();
+    assert_eq!(
+        expected_valid,
+        status_byte <= MAX_ARBITER_STATUS,
+        "status {}: TOS Rust acceptance disagrees with Active..=Removed",
+        status_byte
+    );
+
+    let (status_name, wire) = match decoded {
+        Ok(status) => {
+            let wire = status_only_payload(status);
+            assert_eq!(wire[1], status_byte, "{:?}: status byte mismatch", status);
+            (Some(format!("{:?}", status)), wire)
+        }
+        Err(_) => {
+            let mut wire = status_only_payload(ArbiterStatus::Active);
+            wire[1] = status_byte;
+            assert!(
+                UpdateArbiterPayload::from_bytes(&wire).is_err(),
+                "status {}: TOS Rust accepted an unknown status",
+                status_byte
+            );
+            (None, wire)
+        }
+    };
+
+    ArbiterStatusVector {
+        status_name,
+        status_byte,
+        wire_hex: hex::encode(wire),
+        expected_valid,
+    }
+}
+
+fn main() {
+    let mut vectors: Vec<ArbiterStatusVector> = (0..=MAX_ARBITER_STATUS).map(make_vector).collect();
+    let names: Vec<&str> = vectors
+        .iter()
+        .map(|v| v.status_name.as_deref().unwrap())
+        .collect();
+    assert_eq!(names, ["Active", "Suspended", "Exiting", "Removed"]);
+
+    // First unassigned value and the largest byte.
+    vectors.push(make_vector(MAX_ARBITER_STATUS + 1));
+    vectors.push(make_vector(u8::MAX));
+
+    let test_file = ArbiterStatusTestFile {
+        algorithm: "TOS-ArbiterStatus".to_string(),
+        tx_type_id: TX_TYPE_UPDATE_ARBITER,
+        status_flag: FLAG_STATUS,
+        arbiter_status_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("arbiter_status.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to arbiter_status.yaml");
+}
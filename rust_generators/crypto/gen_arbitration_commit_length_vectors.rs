@@ -0,0 +1,118 @@
+// Generate arbitration commit payload length edge-case test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_arbitration_commit_length_vectors
+//
+// Arbitration commit payloads end with an opaque, u16-length-prefixed blob
+// (tos_signer encode_commit_*_payload_inner):
+//
+//   CommitVoteRequest (type 45):
+//     [request_id:32][vote_request_hash:32][coordinator_signature:64]
+//     [len:u16 BE][vote_request_payload]
+//   CommitJurorVote (type 47):
+//     [request_id:32][juror_pubkey:32][vote_hash:32][juror_signature:64]
+//     [len:u16 BE][vote_payload]
+//
+// Blob lengths 0, 1, 255, 256 and 65535 encode. 65536 does not fit the
+// prefix and has no wire form (`is_valid: false`, `wire_hex: null`).
+// Fixed fields are filler; only the blob length varies.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const TX_TYPE_COMMIT_VOTE_REQUEST: u8 = 45;
+const TX_TYPE_COMMIT_JUROR_VOTE: u8 = 47;
+
+#[derive(Serialize)]
+struct CommitLengthVector {
+    name: String,
+    tx_type_id: u8,
+    blob_field: String,
+    payload_len: usize,
+    is_valid: bool,
+    length_prefix_hex: Option<String>,
+    expected_size: Option<usize>,
+    wire_hex: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CommitLengthTestFile {
+    algorithm: String,
+    max_blob_len: usize,
+    commit_length_vectors: Vec<CommitLengthVector>,
+}
+
+/// Fixed-width fields preceding the blob, for each commit type.
+fn fixed_prefix(tx_type_id: u8) -> Vec<u8> {
+    let mut out = vec![0x11; 32]; // request_id
+    match tx_type_id {
+        TX_TYPE_COMMIT_VOTE_REQUEST => {
+            out.extend_from_slice(&[0x22; 32]); // vote_request_hash
+            out.extend_from_slice(&[0x33; 64]); // coordinator_signature
+        }
+        TX_TYPE_COMMIT_JUROR_VOTE => {
+            out.extend_from_slice(&[0x44; 32]); // juror_pubkey
+            out.extend_from_slice(&[0x55; 32]); // vote_hash
+            out.extend_from_slice(&[0x66; 64]); // juror_signature
+        }
+        _ => unreachable!("not an arbitration commit type"),
+    }
+    out
+}
+
+fn make_vector(tx_type_id: u8, blob_field: &str, payload_len: usize) -> CommitLengthVector {
+    let name = format!("{}_len_{}", blob_field, payload_len);
+    let prefix = fixed_prefix(tx_type_id);
+    let is_valid = payload_len <= u16::MAX as usize;
+
+    let wire = is_valid.then(|| {
+        let mut wire = prefix.clone();
+        wire.extend_from_slice(&(payload_len as u16).to_be_bytes());
+        wire.extend((0..payload_len).map(|i| i as u8));
+        assert_eq!(
+            wire.len(),
+            prefix.len() + 2 + payload_len,
+            "{}: size mismatch",
+            name
+        );
+        wire
+    });
+
+    CommitLengthVector {
+        name,
+        tx_type_id,
+        blob_field: blob_field.to_string(),
+        payload_len,
+        is_valid,
+        length_prefix_hex: is_valid.then(|| hex::encode((payload_len as u16).to_be_bytes())),
+        expected_size: wire.as_ref().map(Vec::len),
+        wire_hex: wire.as_ref().map(hex::encode),
+    }
+}
+
+fn main() {
+    let lengths = [0, 1, 255, 256, 65535, 65536];
+
+    let mut vectors = Vec::new();
+    for (tx_type_id, blob_field) in [
+        (TX_TYPE_COMMIT_VOTE_REQUEST, "vote_request_payload"),
+        (TX_TYPE_COMMIT_JUROR_VOTE, "vote_payload"),
+    ] {
+        for payload_len in lengths {
+            vectors.push(make_vector(tx_type_id, blob_field, payload_len));
+        }
+    }
+    assert_eq!(vectors.iter().filter(|v| !v.is_valid).count(), 2);
+
+    let test_file = CommitLengthTestFile {
+        algorithm: "Arbitration-Commit-Blob-Length".to_string(),
+        max_blob_len: u16::MAX as usize,
+        commit_length_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("arbitration_commit_length.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to arbitration_commit_length.yaml");
+}
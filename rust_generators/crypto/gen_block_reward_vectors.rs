@@ -0,0 +1,183 @@
+// gen_block_reward_vectors.rs - Block reward distribution test vectors
+//
+// Block subsidy halves every HALVING_HEIGHT blocks (tck/specs/mining-pow.md,
+// tos_spec.consensus.mining_pow.block_reward):
+//
+//   subsidy = (50 * COIN_VALUE) >> (height / HALVING_HEIGHT)
+//
+// The block reward is the subsidy plus all transaction fees in the block:
+//
+//   treasury_reward = 0   (tos-spec specifies no coinbase treasury or dev fee cut)
+//   referral_reward = 0   (referral payouts are made by BatchReferralReward, Type 8,
+//                          not by the coinbase)
+//   miner_reward    = total_reward - treasury_reward - referral_reward
+//
+// These vectors cover the miner subsidy only. tos_common's reward split is
+// not used, so the treasury and referral shares are recorded as 0 rather
+// than checked; the file says so in its `scope` field. Every step uses checked
+// arithmetic; an overflow means the reward formula is broken and aborts
+// generation.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const COIN_VALUE: u64 = 100_000_000;
+const HALVING_HEIGHT: u64 = 2_100_000;
+const SCOPE: &str = "miner subsidy only: treasury_reward and referral_reward are fixed at 0, \
+                     not taken from the tos_common reward split";
+
+#[derive(Serialize)]
+struct BlockRewardVector {
+    name: String,
+    description: String,
+    height: u64,
+    total_fees: u64,
+    block_subsidy: u64,
+    miner_reward: u64,
+    referral_reward: u64,
+    treasury_reward: u64,
+    sum_check: u64,
+}
+
+#[derive(Serialize)]
+struct BlockRewardTestVectors {
+    algorithm: String,
+    version: u32,
+    scope: String,
+    halving_height: u64,
+    block_reward_vectors: Vec<BlockRewardVector>,
+}
+
+fn block_subsidy(height: u64) -> u64 {
+    let era = height / HALVING_HEIGHT;
+    let initial = 50u64
+        .checked_mul(COIN_VALUE)
+        .expect("initial subsidy overflow");
+    // Shifting a u64 by 64 or more is undefined; the subsidy is zero by then
+    if era >= 64 {
+        0
+    } else {
+        initial >> era
+    }
+}
+
+fn generate_vector(
+    name: &str,
+    description: &str,
+    height: u64,
+    total_fees: u64,
+) -> BlockRewardVector {
+    let block_subsidy = block_subsidy(height);
+    let total_reward = block_subsidy
+        .checked_add(total_fees)
+        .unwrap_or_else(|| panic!("{}: subsidy + fees overflows u64", name));
+
+    let treasury_reward = 0u64;
+    let referral_reward = 0u64;
+    let miner_reward = total_reward
+        .checked_sub(treasury_reward)
+        .and_then(|r| r.checked_sub(referral_reward))
+        .unwrap_or_else(|| panic!("{}: shares exceed total reward", name));
+
+    let sum_check = miner_reward
+        .checked_add(referral_reward)
+        .and_then(|s| s.checked_add(treasury_reward))
+        .unwrap_or_else(|| panic!("{}: share sum overflows u64", name));
+    assert_eq!(
+        sum_check, total_reward,
+        "{}: shares do not sum to subsidy + fees",
+        name
+    );
+
+    BlockRewardVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        height,
+        total_fees,
+        block_subsidy,
+        miner_reward,
+        referral_reward,
+        treasury_reward,
+        sum_check,
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+
+    let heights = [
+        ("genesis", "Genesis block", 0),
+        ("height_1", "First block after genesis", 1),
+        ("height_100", "Early chain", 100),
+        (
+            "pre_halving",
+            "Last block of the first era",
+            HALVING_HEIGHT - 1,
+        ),
+        ("halving", "First block of the second era", HALVING_HEIGHT),
+        (
+            "post_halving",
+            "Second block of the second era",
+            HALVING_HEIGHT + 1,
+        ),
+    ];
+
+    for (label, description, height) in heights {
+        vectors.push(generate_vector(
+            &format!("{}_no_fees", label),
+            &format!("{}, no transaction fees", description),
+            height,
+            0,
+        ));
+        vectors.push(generate_vector(
+            &format!("{}_with_fees", label),
+            &format!("{}, 0.12345679 TOS in fees", description),
+            height,
+            12_345_679,
+        ));
+    }
+
+    // Large fee total: subsidy + fees is exactly u64::MAX
+    vectors.push(generate_vector(
+        "large_fees",
+        "Fees of u64::MAX minus the subsidy (subsidy + fees must not overflow)",
+        1,
+        u64::MAX - 50 * COIN_VALUE,
+    ));
+
+    let test_file = BlockRewardTestVectors {
+        algorithm: "Block-Reward-Distribution".to_string(),
+        version: 1,
+        scope: SCOPE.to_string(),
+        halving_height: HALVING_HEIGHT,
+        block_reward_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# Block Reward Distribution Test Vectors
+# Generated by TOS Rust - gen_block_reward_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# subsidy         = (50 * COIN_VALUE) >> (height / HALVING_HEIGHT)
+# total_reward    = subsidy + total_fees
+# treasury_reward = 0 (no coinbase treasury cut is specified)
+# referral_reward = 0 (paid by BatchReferralReward, not the coinbase)
+# miner_reward    = total_reward - treasury_reward - referral_reward
+#
+# Scope: miner subsidy only. The treasury and referral shares are fixed at 0
+# and are not checked against the tos_common reward split.
+#
+# sum_check = miner_reward + referral_reward + treasury_reward = subsidy + total_fees
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("block_reward.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to block_reward.yaml");
+}
@@ -0,0 +1,142 @@
+// Generate block header version byte test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_block_version_vectors
+//
+// The version is the first byte of a serialized BlockHeader. Every byte value
+// 0..=255 is run through BlockVersion::from_bytes; each value TOS Rust accepts
+// gets a full header vector, and the first unassigned value plus 255 are
+// recorded as `UnknownVersion`. A relaying node must reject an unknown version
+// byte instead of parsing the rest of the header with the current layout.
+
+use indexmap::IndexSet;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::block::{BlockHeader, BlockVersion};
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash, Hashable};
+use tos_common::serializer::Serializer;
+
+#[derive(Serialize)]
+struct BlockVersionVector {
+    name: String,
+    description: String,
+    version_name: Option<String>,
+    version_byte: u8,
+    header_hex: Option<String>,
+    block_hash_hex: Option<String>,
+    expected_result: String,
+}
+
+#[derive(Serialize)]
+struct BlockVersionTestFile {
+    algorithm: String,
+    known_versions: Vec<u8>,
+    block_version_vectors: Vec<BlockVersionVector>,
+}
+
+fn sample_header(version: BlockVersion) -> BlockHeader {
+    let miner = CompressedPublicKey::from_bytes(&[0x01; 32]).expect("Invalid miner key");
+    let mut tips = IndexSet::new();
+    tips.insert(Hash::new([0x11; 32]));
+    let mut header = BlockHeader::new(
+        version,
+        100,
+        1700000000000,
+        tips,
+        [0xaa; 32],
+        miner,
+        IndexSet::new(),
+    );
+    header.nonce = 12345;
+    header
+}
+
+fn known_vector(version: BlockVersion) -> BlockVersionVector {
+    let version_byte = version.to_bytes()[0];
+    let version_name = format!("{:?}", version);
+
+    let header = sample_header(version);
+    let wire = header.to_bytes();
+    assert_eq!(
+        wire[0], version_byte,
+        "{}: header must start with the version byte",
+        version_name
+    );
+    let decoded = BlockHeader::from_bytes(&wire).expect("header must decode");
+    assert_eq!(
+        decoded.to_bytes(),
+        wire,
+        "{}: header round-trip mismatch",
+        version_name
+    );
+
+    BlockVersionVector {
+        name: format!("version_{}", version_name.to_lowercase()),
+        description: format!("BlockVersion::{} header", version_name),
+        version_name: Some(version_name),
+        version_byte,
+        header_hex: Some(hex::encode(&wire)),
+        block_hash_hex: Some(hex::encode(header.hash().as_bytes())),
+        expected_result: "accepted".to_string(),
+    }
+}
+
+fn unknown_vector(version_byte: u8, description: &str) -> BlockVersionVector {
+    assert!(BlockVersion::from_bytes(&[version_byte]).is_err());
+
+    // A known header with only the version byte replaced must also fail.
+    let mut wire = sample_header(BlockVersion::Nobunaga).to_bytes();
+    wire[0] = version_byte;
+    assert!(
+        BlockHeader::from_bytes(&wire).is_err(),
+        "version {}: header with unknown version must not decode",
+        version_byte
+    );
+
+    BlockVersionVector {
+        name: format!("version_unknown_{}", version_byte),
+        description: description.to_string(),
+        version_name: None,
+        version_byte,
+        header_hex: Some(hex::encode(&wire)),
+        block_hash_hex: None,
+        expected_result: "UnknownVersion".to_string(),
+    }
+}
+
+fn main() {
+    let known: Vec<BlockVersion> = (0..=u8::MAX)
+        .filter_map(|byte| BlockVersion::from_bytes(&[byte]).ok())
+        .collect();
+    assert!(
+        known.contains(&BlockVersion::Nobunaga),
+        "Nobunaga must be a known version"
+    );
+    let known_versions: Vec<u8> = known.iter().map(|v| v.to_bytes()[0]).collect();
+
+    let mut vectors: Vec<BlockVersionVector> = known.into_iter().map(known_vector).collect();
+
+    let first_unknown = (0..=u8::MAX)
+        .find(|byte| !known_versions.contains(byte))
+        .expect("at least one unassigned version byte");
+    vectors.push(unknown_vector(
+        first_unknown,
+        "First unassigned version byte; rejected until a new BlockVersion exists",
+    ));
+    if first_unknown != u8::MAX && !known_versions.contains(&u8::MAX) {
+        vectors.push(unknown_vector(u8::MAX, "Largest version byte; unknown"));
+    }
+
+    let test_file = BlockVersionTestFile {
+        algorithm: "TOS-BlockVersion".to_string(),
+        known_versions,
+        block_version_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("block_version.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to block_version.yaml");
+}
@@ -0,0 +1,144 @@
+// Generate ChaCha20 keystream-only test vectors (no Poly1305)
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_chacha20_stream_vectors
+//
+// keystream = ChaCha20(key, nonce) starting at block `counter`, applied to zeros.
+//
+// With an all-zero nonce the IETF keystream equals the output of
+// rand_chacha::ChaCha20Rng seeded with `key` (stream 0), which is how TOS
+// seeds its deterministic RNGs. Each such vector is checked against both.
+
+use chacha20::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use chacha20::ChaCha20;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const BLOCK_SIZE: usize = 64;
+
+#[derive(Serialize)]
+struct TestVector {
+    name: String,
+    description: String,
+    key_hex: String,
+    nonce_hex: String,
+    counter: u32,
+    keystream_hex: String,
+    keystream_len: usize,
+}
+
+#[derive(Serialize)]
+struct ChaCha20StreamTestFile {
+    algorithm: String,
+    key_size: usize,
+    nonce_size: usize,
+    block_size: usize,
+    test_vectors: Vec<TestVector>,
+}
+
+fn keystream(key: &[u8; 32], nonce: &[u8; 12], counter: u32, len: usize) -> Vec<u8> {
+    let mut cipher = ChaCha20::new(key.into(), nonce.into());
+    cipher.seek(counter as u64 * BLOCK_SIZE as u64);
+    let mut out = vec![0u8; len];
+    cipher.apply_keystream(&mut out);
+    out
+}
+
+/// ChaCha20Rng output from block `counter` (each block is 16 32-bit words).
+fn rng_keystream(key: &[u8; 32], counter: u32, len: usize) -> Vec<u8> {
+    let mut rng = ChaCha20Rng::from_seed(*key);
+    rng.set_word_pos(counter as u128 * 16);
+    let mut out = vec![0u8; len];
+    rng.fill_bytes(&mut out);
+    out
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    key: [u8; 32],
+    nonce: [u8; 12],
+    counter: u32,
+    len: usize,
+) -> TestVector {
+    let stream = keystream(&key, &nonce, counter, len);
+    if nonce == [0u8; 12] {
+        assert_eq!(
+            stream,
+            rng_keystream(&key, counter, len),
+            "{}: ChaCha20Rng output differs from the IETF keystream",
+            name
+        );
+    }
+
+    TestVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        key_hex: hex::encode(key),
+        nonce_hex: hex::encode(nonce),
+        counter,
+        keystream_hex: hex::encode(&stream),
+        keystream_len: len,
+    }
+}
+
+fn main() {
+    let zero_key = make_vector(
+        "zero_key_zero_nonce",
+        "All-zero key and nonce, first block",
+        [0x00u8; 32],
+        [0x00u8; 12],
+        0,
+        BLOCK_SIZE,
+    );
+    // RFC 8439 Appendix A.1 Test Vector #1
+    assert_eq!(
+        zero_key.keystream_hex,
+        "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+         da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"
+    );
+
+    let vectors = vec![
+        zero_key,
+        make_vector(
+            "ff_key_zero_nonce",
+            "All-0xFF key with zero nonce, first block",
+            [0xFFu8; 32],
+            [0x00u8; 12],
+            0,
+            BLOCK_SIZE,
+        ),
+        make_vector(
+            "key42_counter1",
+            "0x42 key with zero nonce, starting at block counter 1",
+            [0x42u8; 32],
+            [0x00u8; 12],
+            1,
+            BLOCK_SIZE,
+        ),
+        make_vector(
+            "key42_counter1_two_blocks",
+            "0x42 key with zero nonce, blocks 1 and 2 (crosses a block boundary)",
+            [0x42u8; 32],
+            [0x00u8; 12],
+            1,
+            2 * BLOCK_SIZE,
+        ),
+    ];
+
+    let test_file = ChaCha20StreamTestFile {
+        algorithm: "ChaCha20-keystream".to_string(),
+        key_size: 32,
+        nonce_size: 12,
+        block_size: BLOCK_SIZE,
+        test_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("chacha20_stream.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to chacha20_stream.yaml");
+}
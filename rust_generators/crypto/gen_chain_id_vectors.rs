@@ -0,0 +1,198 @@
+// Generate chain_id replay-protection test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_chain_id_vectors
+//
+// chain_id is the second byte of the signing frame (after the version), so a
+// signature made for one network never verifies on another:
+//
+//   [version:1][chain_id:1][source:32][tx_type_id:1][payload:var]
+//   [fee:8][fee_type:1][nonce:8][ref_hash:32][ref_topo:8]
+//
+// Every vector signs the same Transfer with the seed-byte 1 key (tos_signer
+// deterministic Schnorr), then flips only the chain_id byte to
+// `tampered_chain_id` and checks the original signature against the
+// tampered frame. `tampered_sig_valid` is always false.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+const TX_VERSION_T1: u8 = 1;
+const TX_TYPE_TRANSFERS: u8 = 1;
+const SEED_BYTE: u8 = 1;
+
+#[derive(Serialize)]
+struct ChainIdVector {
+    name: String,
+    description: String,
+    chain_id: u8,
+    signing_bytes_hex: String,
+    signature_hex: String,
+    tampered_chain_id: u8,
+    tampered_signing_bytes_hex: String,
+    tampered_sig_valid: bool,
+}
+
+#[derive(Serialize)]
+struct ChainIdTestFile {
+    algorithm: String,
+    seed_byte: u8,
+    pubkey_hex: String,
+    chain_id_offset: usize,
+    chain_id_vectors: Vec<ChainIdVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn sign(
+    private_key: &Scalar,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    h: &RistrettoPoint,
+) -> [u8; 64] {
+    let mut hasher = Sha3_512::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        k = Scalar::one();
+    }
+    let r = k * h;
+    let e = hash_and_point_to_scalar(compressed_pub, message, &r);
+    let s = private_key.invert() * e + k;
+    let mut sig = [0u8; 64];
+    sig[..32].copy_from_slice(s.as_bytes());
+    sig[32..].copy_from_slice(e.as_bytes());
+    sig
+}
+
+/// R = s*H - e*P must hash back to e.
+fn verify(
+    public_key: &RistrettoPoint,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    signature: &[u8; 64],
+    h: &RistrettoPoint,
+) -> bool {
+    let mut s_bytes = [0u8; 32];
+    let mut e_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(&signature[..32]);
+    e_bytes.copy_from_slice(&signature[32..]);
+    let s = Scalar::from_bytes_mod_order(s_bytes);
+    let e = Scalar::from_bytes_mod_order(e_bytes);
+    let r = s * h - e * public_key;
+    hash_and_point_to_scalar(compressed_pub, message, &r) == e
+}
+
+/// One transfer of 1 TOS: [count:u16][asset:32][dest:32][amount:u64][extra_data:None].
+fn transfer_payload() -> Vec<u8> {
+    let mut payload = 1u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(&[0u8; 32]);
+    payload.extend_from_slice(&[0x22; 32]);
+    payload.extend_from_slice(&100_000_000u64.to_be_bytes());
+    payload.push(0x00);
+    payload
+}
+
+fn signing_bytes(chain_id: u8, source: &[u8; 32], payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(92 + payload.len());
+    frame.push(TX_VERSION_T1);
+    frame.push(chain_id);
+    frame.extend_from_slice(source);
+    frame.push(TX_TYPE_TRANSFERS);
+    frame.extend_from_slice(payload);
+    frame.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+    frame.push(0x00); // fee_type: TOS
+    frame.extend_from_slice(&7u64.to_be_bytes()); // nonce
+    frame.extend_from_slice(&[0x33; 32]); // ref_hash
+    frame.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+    frame
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let mut priv_bytes = [0u8; 32];
+    priv_bytes[0] = SEED_BYTE;
+    let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+    let public_key = private_key.invert() * h;
+    let compressed_pub = public_key.compress().to_bytes();
+    let payload = transfer_payload();
+
+    let cases: [(&str, &str, u8, u8); 4] = [
+        ("chain_0", "Genesis / test network", 0, 1),
+        ("chain_1", "Mainnet; replayed on the dev chain", 1, 42),
+        ("chain_42", "Dev network", 42, 1),
+        ("chain_255", "Largest chain_id byte", 255, 1),
+    ];
+
+    let mut chain_id_vectors = Vec::new();
+    let mut signatures: Vec<[u8; 64]> = Vec::new();
+    for (name, description, chain_id, tampered_chain_id) in cases {
+        let frame = signing_bytes(chain_id, &compressed_pub, &payload);
+        let signature = sign(&private_key, &compressed_pub, &frame, &h);
+        assert!(
+            verify(&public_key, &compressed_pub, &frame, &signature, &h),
+            "{}: signature does not verify",
+            name
+        );
+        assert!(
+            !signatures.contains(&signature),
+            "{}: signature must change with chain_id",
+            name
+        );
+        signatures.push(signature);
+
+        let mut tampered = frame.clone();
+        tampered[1] = tampered_chain_id;
+        let tampered_sig_valid = verify(&public_key, &compressed_pub, &tampered, &signature, &h);
+        assert!(
+            !tampered_sig_valid,
+            "{}: signature must not verify after changing chain_id",
+            name
+        );
+
+        chain_id_vectors.push(ChainIdVector {
+            name: name.to_string(),
+            description: description.to_string(),
+            chain_id,
+            signing_bytes_hex: hex::encode(&frame),
+            signature_hex: hex::encode(signature),
+            tampered_chain_id,
+            tampered_signing_bytes_hex: hex::encode(&tampered),
+            tampered_sig_valid,
+        });
+    }
+
+    let test_file = ChainIdTestFile {
+        algorithm: "TOS-ChainId-Replay-Protection".to_string(),
+        seed_byte: SEED_BYTE,
+        pubkey_hex: hex::encode(compressed_pub),
+        chain_id_offset: 1,
+        chain_id_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("chain_id.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to chain_id.yaml");
+}
@@ -0,0 +1,183 @@
+// gen_committee_approval_vectors.rs - Standalone CommitteeApproval wire vectors
+//
+// CommitteeApproval is embedded in KYC (Types 10, 11, 15, 16), committee
+// (Types 13, 14) and arbiter (Type 35) payloads. These vectors pin the
+// approval and approval-list encodings on their own.
+//
+// Wire formats (Big-Endian):
+//
+// CommitteeApproval (104 bytes):
+//   member_pubkey: 32 bytes
+//   signature:     64 bytes
+//   timestamp:     u64 BE
+//
+// Approval list:
+//   count:         u16 BE
+//   approvals:     CommitteeApproval... (count entries, in order)
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::{PublicKey, Signature};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::CommitteeApproval;
+
+const APPROVAL_SIZE: usize = 32 + 64 + 8;
+
+#[derive(Serialize)]
+struct ApprovalVector {
+    name: String,
+    pubkey_hex: String,
+    signature_hex: String,
+    timestamp: u64,
+    single_approval_wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct ApprovalListVector {
+    name: String,
+    description: String,
+    count: u16,
+    approvals: Vec<String>,
+    list_wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct CommitteeApprovalTestVectors {
+    algorithm: String,
+    version: u32,
+    approval_size: usize,
+    approval_vectors: Vec<ApprovalVector>,
+    list_vectors: Vec<ApprovalListVector>,
+}
+
+fn test_pubkey(seed: u8) -> PublicKey {
+    PublicKey::from_bytes(&[seed; 32]).expect("Valid pubkey bytes")
+}
+
+fn test_signature(seed: u8) -> Signature {
+    // Scalars with a zero high byte are canonical; content is not verified here.
+    let mut bytes = [seed; 64];
+    bytes[31] = 0;
+    bytes[63] = 0;
+    Signature::from_bytes(&bytes).expect("Valid signature bytes")
+}
+
+struct ApprovalInput {
+    name: String,
+    seed: u8,
+    timestamp: u64,
+    approval: CommitteeApproval,
+}
+
+/// Approvals signed by members 0x11, 0x22, 0x33 at consecutive timestamps.
+fn approvals() -> Vec<ApprovalInput> {
+    [
+        (0x11u8, 1_700_000_000u64),
+        (0x22, 1_700_000_060),
+        (0x33, 1_700_000_120),
+    ]
+    .into_iter()
+    .map(|(seed, timestamp)| ApprovalInput {
+        name: format!("member_{:02x}", seed),
+        seed,
+        timestamp,
+        approval: CommitteeApproval::new(test_pubkey(seed), test_signature(seed), timestamp),
+    })
+    .collect()
+}
+
+fn approval_vector(input: &ApprovalInput) -> ApprovalVector {
+    let wire = input.approval.to_bytes();
+    let pubkey = test_pubkey(input.seed);
+    let signature = test_signature(input.seed).to_bytes();
+
+    // pubkey(32) || sig(64) || timestamp(8)
+    let mut expected = Vec::with_capacity(APPROVAL_SIZE);
+    expected.extend_from_slice(pubkey.as_bytes());
+    expected.extend_from_slice(&signature);
+    expected.extend_from_slice(&input.timestamp.to_be_bytes());
+    assert_eq!(wire, expected, "{}: approval layout mismatch", input.name);
+    assert_eq!(input.approval.size(), APPROVAL_SIZE);
+
+    ApprovalVector {
+        name: input.name.clone(),
+        pubkey_hex: hex::encode(pubkey.as_bytes()),
+        signature_hex: hex::encode(signature),
+        timestamp: input.timestamp,
+        single_approval_wire_hex: hex::encode(&wire),
+        expected_size: APPROVAL_SIZE,
+    }
+}
+
+/// u16 count followed by each approval in order, as embedded in payloads.
+fn list_vector(name: &str, description: &str, inputs: &[ApprovalInput]) -> ApprovalListVector {
+    let mut wire = (inputs.len() as u16).to_be_bytes().to_vec();
+    for input in inputs {
+        wire.extend_from_slice(&input.approval.to_bytes());
+    }
+    assert_eq!(
+        wire.len(),
+        2 + inputs.len() * APPROVAL_SIZE,
+        "{}: list size mismatch",
+        name
+    );
+
+    ApprovalListVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        count: inputs.len() as u16,
+        approvals: inputs.iter().map(|i| i.name.clone()).collect(),
+        list_wire_hex: hex::encode(&wire),
+        expected_size: wire.len(),
+    }
+}
+
+fn main() {
+    let entries = approvals();
+    let approval_vectors = entries.iter().map(approval_vector).collect();
+
+    let list_vectors = vec![
+        list_vector("empty_list", "No approvals (count = 0)", &[]),
+        list_vector("single_approval", "One approval", &entries[..1]),
+        list_vector(
+            "two_approvals",
+            "Two approvals in signing order",
+            &entries[..2],
+        ),
+        list_vector(
+            "three_approvals",
+            "Three approvals in signing order",
+            &entries[..3],
+        ),
+    ];
+
+    let test_file = CommitteeApprovalTestVectors {
+        algorithm: "Committee-Approval".to_string(),
+        version: 1,
+        approval_size: APPROVAL_SIZE,
+        approval_vectors,
+        list_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# CommitteeApproval Wire Format Test Vectors
+# Generated by TOS Rust - gen_committee_approval_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# CommitteeApproval: member_pubkey(32) || signature(64) || timestamp(u64 BE)
+# Approval list:     count(u16 BE) || CommitteeApproval...
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("committee_approval.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to committee_approval.yaml");
+}
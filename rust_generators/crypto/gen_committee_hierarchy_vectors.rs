@@ -0,0 +1,270 @@
+// gen_committee_hierarchy_vectors.rs - KYC committee hierarchy test vectors
+//
+// Walks the committee tree from the top down:
+//   Type 12: BootstrapCommittee - create the Global committee (no parent)
+//   Type 13: RegisterCommittee  - register APAC and EMEA with Global as parent
+//   Type 14: UpdateCommittee    - add a member to EMEA
+//
+// Committee IDs are fixed test inputs (0xC1.., 0xC2.., 0xC3.. for Global,
+// APAC and EMEA). How the chain derives a committee ID is not covered here;
+// the vectors pin only the payload wire formats and the parent links.
+// The Global committee uses the zero hash as parent_id.
+//
+// Wire formats (Big-Endian):
+//
+// CommitteeMemberInit:
+//   public_key: 32 bytes
+//   name:       Option<String> (0x00 for None, 0x01 + u8 len + utf8 for Some)
+//   role:       u8 (0=Chair, 1=ViceChair, 2=Member)
+//
+// CommitteeApproval:
+//   member_pubkey: 32 bytes
+//   signature:     64 bytes
+//   timestamp:     u64 BE
+//
+// BootstrapCommitteePayload (Type 12):
+//   name:          u8 len + utf8
+//   members:       u8 count + CommitteeMemberInit...
+//   threshold:     u8
+//   kyc_threshold: u8
+//   max_kyc_level: u16 BE
+//
+// RegisterCommitteePayload (Type 13):
+//   name:          u8 len + utf8
+//   region:        u8
+//   members:       u8 count + CommitteeMemberInit...
+//   threshold:     u8
+//   kyc_threshold: u8
+//   max_kyc_level: u16 BE
+//   parent_id:     32 bytes
+//   approvals:     u16 count + CommitteeApproval...
+//
+// UpdateCommitteePayload (Type 14):
+//   committee_id:  32 bytes
+//   update:        u8 tag + update data (0 = AddMember: public_key + name + role)
+//   approvals:     u16 count + CommitteeApproval...
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::{Hash, PublicKey, Signature};
+use tos_common::kyc::{KycRegion, MemberRole};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{
+    BootstrapCommitteePayload, CommitteeApproval, CommitteeMemberInit, CommitteeUpdateData,
+    RegisterCommitteePayload, UpdateCommitteePayload,
+};
+
+#[derive(Serialize)]
+struct MemberEntry {
+    pubkey_hex: String,
+    name: Option<String>,
+    role: u8,
+}
+
+#[derive(Serialize)]
+struct HierarchyStep {
+    name: String,
+    description: String,
+    tx_type: u8,
+    committee_name: String,
+    region: Option<u8>,
+    parent_id_hex: String,
+    members: Vec<MemberEntry>,
+    committee_id_hex: String,
+    approvals_count: usize,
+    wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct CommitteeHierarchyVectors {
+    algorithm: String,
+    version: u32,
+    hierarchy_vectors: Vec<HierarchyStep>,
+}
+
+fn test_pubkey(seed: u8) -> PublicKey {
+    PublicKey::from_bytes(&[seed; 32]).expect("Valid pubkey bytes")
+}
+
+fn test_signature(seed: u8) -> Signature {
+    // Scalars with a zero high byte are canonical; content is not verified here.
+    let mut bytes = [seed; 64];
+    bytes[31] = 0;
+    bytes[63] = 0;
+    Signature::from_bytes(&bytes).expect("Valid signature bytes")
+}
+
+fn approval(seed: u8, timestamp: u64) -> CommitteeApproval {
+    CommitteeApproval::new(test_pubkey(seed), test_signature(seed), timestamp)
+}
+
+fn test_committee_id(seed: u8) -> Hash {
+    Hash::new([seed; 32])
+}
+
+fn member_entries(members: &[CommitteeMemberInit]) -> Vec<MemberEntry> {
+    members
+        .iter()
+        .map(|m| MemberEntry {
+            pubkey_hex: hex::encode(m.public_key.as_bytes()),
+            name: m.name.clone(),
+            role: m.role as u8,
+        })
+        .collect()
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+
+    // ========================================================================
+    // Step 1: Bootstrap Global committee (Type 12)
+    // ========================================================================
+
+    let global_members = vec![
+        CommitteeMemberInit::new(test_pubkey(0x11), Some("global-chair".to_string()), MemberRole::Chair),
+        CommitteeMemberInit::new(test_pubkey(0x12), Some("global-vice".to_string()), MemberRole::ViceChair),
+        CommitteeMemberInit::new(test_pubkey(0x13), None, MemberRole::Member),
+    ];
+    let global_name = "Global".to_string();
+    let global_id = test_committee_id(0xC1);
+    {
+        let payload = BootstrapCommitteePayload::new(
+            global_name.clone(),
+            global_members.clone(),
+            2,     // threshold
+            2,     // kyc_threshold
+            32767, // max_kyc_level (Tier 8)
+        );
+        let wire = payload.to_bytes();
+        vectors.push(HierarchyStep {
+            name: "bootstrap_global".to_string(),
+            description: "Bootstrap the Global committee (2-of-3, max level 32767)".to_string(),
+            tx_type: 12,
+            committee_name: global_name.clone(),
+            region: None,
+            parent_id_hex: hex::encode(Hash::zero().as_bytes()),
+            members: member_entries(&global_members),
+            committee_id_hex: hex::encode(global_id.as_bytes()),
+            approvals_count: 0,
+            wire_hex: hex::encode(&wire),
+            expected_size: payload.size(),
+        });
+    }
+
+    // Global committee approvals used to register the regional committees
+    let global_approvals = vec![approval(0x11, 1700000000), approval(0x12, 1700000005)];
+
+    // ========================================================================
+    // Step 2: Register regional committees under Global (Type 13)
+    // ========================================================================
+
+    let apac_members = vec![
+        CommitteeMemberInit::new(test_pubkey(0x21), Some("apac-chair".to_string()), MemberRole::Chair),
+        CommitteeMemberInit::new(test_pubkey(0x22), None, MemberRole::Member),
+    ];
+    let emea_members = vec![
+        CommitteeMemberInit::new(test_pubkey(0x31), Some("emea-chair".to_string()), MemberRole::Chair),
+        CommitteeMemberInit::new(test_pubkey(0x32), None, MemberRole::Member),
+    ];
+
+    let emea_id = test_committee_id(0xC3);
+    for (label, region, members, committee_id) in [
+        ("APAC", KycRegion::AsiaPacific, &apac_members, test_committee_id(0xC2)),
+        ("EMEA", KycRegion::Europe, &emea_members, emea_id.clone()),
+    ] {
+
+        let payload = RegisterCommitteePayload::new(
+            label.to_string(),
+            region,
+            members.clone(),
+            1,     // threshold
+            1,     // kyc_threshold
+            2047,  // max_kyc_level (Tier 5)
+            global_id.clone(),
+            global_approvals.clone(),
+        );
+        let wire = payload.to_bytes();
+        vectors.push(HierarchyStep {
+            name: format!("register_{}", label.to_lowercase()),
+            description: format!("Register {} committee with Global as parent", label),
+            tx_type: 13,
+            committee_name: label.to_string(),
+            region: Some(region as u8),
+            parent_id_hex: hex::encode(global_id.as_bytes()),
+            members: member_entries(members),
+            committee_id_hex: hex::encode(committee_id.as_bytes()),
+            approvals_count: global_approvals.len(),
+            wire_hex: hex::encode(&wire),
+            expected_size: payload.size(),
+        });
+    }
+
+    // ========================================================================
+    // Step 3: Add a member to EMEA (Type 14)
+    // ========================================================================
+
+    {
+        let new_member = CommitteeMemberInit::new(
+            test_pubkey(0x33),
+            Some("emea-reviewer".to_string()),
+            MemberRole::Member,
+        );
+        let update = CommitteeUpdateData::AddMember {
+            public_key: new_member.public_key.clone(),
+            name: new_member.name.clone(),
+            role: new_member.role,
+        };
+        let emea_approvals = vec![approval(0x31, 1700000100)];
+        let payload = UpdateCommitteePayload::new(emea_id.clone(), update, emea_approvals.clone());
+        let wire = payload.to_bytes();
+
+        // The update targets EMEA by its ID; membership changes do not change it
+        vectors.push(HierarchyStep {
+            name: "update_emea_add_member".to_string(),
+            description: "Add a reviewer to the EMEA committee".to_string(),
+            tx_type: 14,
+            committee_name: "EMEA".to_string(),
+            region: Some(KycRegion::Europe as u8),
+            parent_id_hex: hex::encode(global_id.as_bytes()),
+            members: member_entries(&[new_member]),
+            committee_id_hex: hex::encode(emea_id.as_bytes()),
+            approvals_count: emea_approvals.len(),
+            wire_hex: hex::encode(&wire),
+            expected_size: payload.size(),
+        });
+    }
+
+    // ========================================================================
+    // Write Output
+    // ========================================================================
+
+    let test_file = CommitteeHierarchyVectors {
+        algorithm: "KYC-Committee-Hierarchy".to_string(),
+        version: 1,
+        hierarchy_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# KYC Committee Hierarchy Test Vectors (Types 12, 13, 14)
+# Generated by TOS Rust - gen_committee_hierarchy_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# Hierarchy: Global -> APAC / EMEA
+#
+# committee_id_hex values are fixed test inputs, not derived; only the
+# payload wire formats and parent links are pinned. Global uses the zero
+# hash as parent_id.
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("committee_hierarchy.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to committee_hierarchy.yaml");
+}
@@ -0,0 +1,144 @@
+// gen_committee_threshold_vectors.rs - Committee approval threshold vectors
+//
+// A KYC action is authorised when the number of committee approvals reaches
+// the committee's stored threshold:
+//
+//   is_met = approvals_present >= threshold
+//
+// Only the count is compared; which members signed does not matter, as long
+// as each approval comes from a distinct committee member.
+//
+// Approval list wire format (Big-Endian):
+//   count:     u16 BE
+//   approvals: CommitteeApproval... (member_pubkey(32) || signature(64) || timestamp(u64 BE))
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::{PublicKey, Signature};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::CommitteeApproval;
+
+#[derive(Serialize)]
+struct ThresholdVector {
+    name: String,
+    description: String,
+    threshold: u8,
+    member_count: u8,
+    member_pubkeys: Vec<String>,
+    approving_members: Vec<usize>,
+    approvals_present: usize,
+    is_met: bool,
+    approvals_wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct CommitteeThresholdTestVectors {
+    algorithm: String,
+    version: u32,
+    threshold_vectors: Vec<ThresholdVector>,
+}
+
+/// Member i of a committee has pubkey [0x10 + i; 32].
+fn member_pubkey(index: usize) -> PublicKey {
+    PublicKey::from_bytes(&[0x10 + index as u8; 32]).expect("Valid pubkey bytes")
+}
+
+fn test_signature(seed: u8) -> Signature {
+    // Scalars with a zero high byte are canonical; content is not verified here.
+    let mut bytes = [seed; 64];
+    bytes[31] = 0;
+    bytes[63] = 0;
+    Signature::from_bytes(&bytes).expect("Valid signature bytes")
+}
+
+fn threshold_vector(
+    name: &str,
+    description: &str,
+    threshold: u8,
+    member_count: u8,
+    approving_members: &[usize],
+) -> ThresholdVector {
+    assert!((1..=member_count).contains(&threshold));
+    let mut wire = (approving_members.len() as u16).to_be_bytes().to_vec();
+    for (i, &member) in approving_members.iter().enumerate() {
+        assert!(member < member_count as usize, "{}: not a member", name);
+        let approval = CommitteeApproval::new(
+            member_pubkey(member),
+            test_signature(0x10 + member as u8),
+            1_700_000_000 + i as u64 * 60,
+        );
+        wire.extend_from_slice(&approval.to_bytes());
+    }
+
+    ThresholdVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        threshold,
+        member_count,
+        member_pubkeys: (0..member_count as usize)
+            .map(|i| hex::encode(member_pubkey(i).as_bytes()))
+            .collect(),
+        approving_members: approving_members.to_vec(),
+        approvals_present: approving_members.len(),
+        is_met: approving_members.len() >= threshold as usize,
+        approvals_wire_hex: hex::encode(&wire),
+    }
+}
+
+fn main() {
+    let threshold_vectors = vec![
+        threshold_vector("1_of_1_met", "Single-member committee approves", 1, 1, &[0]),
+        threshold_vector(
+            "1_of_1_not_met",
+            "Single-member committee, no approvals",
+            1,
+            1,
+            &[],
+        ),
+        threshold_vector("2_of_3_met", "Members 0 and 1 approve", 2, 3, &[0, 1]),
+        threshold_vector("2_of_3_not_met", "Only member 2 approves", 2, 3, &[2]),
+        threshold_vector("3_of_3_met", "All members approve", 3, 3, &[0, 1, 2]),
+        threshold_vector("3_of_3_not_met", "Two of three approve", 3, 3, &[0, 2]),
+        threshold_vector(
+            "2_of_5_met_last_two",
+            "Exactly two approve, the last two members (count matters, not identity)",
+            2,
+            5,
+            &[3, 4],
+        ),
+        threshold_vector(
+            "2_of_5_met_first_two",
+            "Exactly two approve, the first two members",
+            2,
+            5,
+            &[0, 1],
+        ),
+        threshold_vector("2_of_5_not_met", "One of five approves", 2, 5, &[4]),
+    ];
+
+    let test_file = CommitteeThresholdTestVectors {
+        algorithm: "Committee-Approval-Threshold".to_string(),
+        version: 1,
+        threshold_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# Committee Approval Threshold Test Vectors
+# Generated by TOS Rust - gen_committee_threshold_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# is_met = approvals_present >= threshold
+# Member i has pubkey [0x10 + i; 32]; approving_members lists member indices.
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("committee_threshold.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to committee_threshold.yaml");
+}
@@ -11,6 +11,11 @@
 //
 // DeployContractPayload (Type 4):
 //   module:      u32 len BE + bytecode (must start with 0x7F 'E' 'L' 'F')
+//
+//   A module that is empty or does not start with the full 4-byte magic is
+//   rejected with INVALID_FORMAT (deploy_contract_elf_vectors). The spec
+//   checks the magic during verification; `reject_stage` records whether
+//   TOS Rust already refuses the bytes while decoding.
 //   invoke:      Option<InvokeConstructorPayload>
 //                  - None: u8 = 0
 //                  - Some: u8 = 1 + InvokeConstructorPayload
@@ -76,11 +81,24 @@ struct DeployContractVector {
     expected_size: usize,
 }
 
+#[derive(Serialize)]
+struct DeployElfVector {
+    name: String,
+    description: String,
+    module_len: usize,
+    first_4_bytes_hex: String,
+    is_valid_elf: bool,
+    wire_hex: String,
+    expected_validation_result: String,
+    reject_stage: Option<String>,
+}
+
 #[derive(Serialize)]
 struct ContractVectors {
     description: String,
     invoke_contract_vectors: Vec<InvokeContractVector>,
     deploy_contract_vectors: Vec<DeployContractVector>,
+    deploy_contract_elf_vectors: Vec<DeployElfVector>,
 }
 
 fn hash_from_bytes(bytes: &[u8; 32]) -> Hash {
@@ -462,12 +480,71 @@ fn main() {
         });
     }
 
+    // ========== DeployContract ELF magic validation ==========
+
+    fn deploy_elf_vector(name: &str, description: &str, bytecode: Vec<u8>) -> DeployElfVector {
+        let is_valid_elf = bytecode.len() >= 4 && bytecode[..4] == [0x7F, b'E', b'L', b'F'];
+        let payload = DeployContractPayload {
+            module: Module::from_bytecode(bytecode.clone()),
+            invoke: None,
+        };
+        let wire = payload.to_bytes();
+        let decodes = DeployContractPayload::from_bytes(&wire).is_ok();
+        assert!(decodes || !is_valid_elf, "{}: valid ELF module must decode", name);
+
+        DeployElfVector {
+            name: name.to_string(),
+            description: description.to_string(),
+            module_len: bytecode.len(),
+            first_4_bytes_hex: hex::encode(&bytecode[..bytecode.len().min(4)]),
+            is_valid_elf,
+            wire_hex: hex::encode(&wire),
+            expected_validation_result: if is_valid_elf {
+                "accepted".to_string()
+            } else {
+                "INVALID_FORMAT".to_string()
+            },
+            reject_stage: match (is_valid_elf, decodes) {
+                (true, _) => None,
+                (false, true) => Some("verify".to_string()),
+                (false, false) => Some("decode".to_string()),
+            },
+        }
+    }
+
+    let mut elf_vectors = Vec::new();
+    elf_vectors.push(deploy_elf_vector(
+        "elf_valid_magic",
+        "Reference module with the full 0x7F 'E' 'L' 'F' magic",
+        make_minimal_elf(16),
+    ));
+    elf_vectors.push(deploy_elf_vector(
+        "elf_not_elf",
+        "Module of the same length starting with 0x00 instead of 0x7F",
+        {
+            let mut bytecode = make_minimal_elf(16);
+            bytecode[0] = 0x00;
+            bytecode
+        },
+    ));
+    elf_vectors.push(deploy_elf_vector(
+        "elf_truncated_magic",
+        "Module consisting only of 0x7F 'E' 'L' (magic cut short)",
+        vec![0x7F, b'E', b'L'],
+    ));
+    elf_vectors.push(deploy_elf_vector(
+        "elf_empty_module",
+        "Empty module (u32 length 0)",
+        Vec::new(),
+    ));
+
     // Build output
     let vectors = ContractVectors {
         description: "TCK test vectors for InvokeContract (Type 3) and DeployContract (Type 4)"
             .to_string(),
         invoke_contract_vectors: invoke_vectors,
         deploy_contract_vectors: deploy_vectors,
+        deploy_contract_elf_vectors: elf_vectors,
     };
 
     // Write YAML output
@@ -485,4 +562,8 @@ fn main() {
         "  - {} DeployContract vectors",
         vectors.deploy_contract_vectors.len()
     );
+    println!(
+        "  - {} DeployContract ELF validation vectors",
+        vectors.deploy_contract_elf_vectors.len()
+    );
 }
@@ -0,0 +1,223 @@
+// Generate transaction decoding error test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_decode_error_vectors
+//
+// For each payload type a valid wire transaction is built:
+//
+//   [version:1][chain_id:1][source:32][tx_type_id:1][payload:var]
+//   [fee:8][fee_type:1][nonce:8][ref_hash:32][ref_topo:8]
+//   [multisig:Option=0x00][signature:64]
+//
+// and then corrupted in one of four ways:
+//
+//   truncated_at_payload_start  wire cut right after tx_type_id
+//   truncated_mid_field         wire cut inside the first payload field
+//   trailing_bytes              one 0x00 appended to the full wire
+//   bad_discriminant            an enum/option tag byte in the payload set
+//                               to an unassigned value (types without one
+//                               have no such vector)
+//
+// Every corrupted wire is fed to TOS Rust Transaction::from_bytes, which must
+// return an error and must not panic. `expected_error_variant` is the Debug form
+// of the returned ReaderError, so implementations can map each case to a
+// specific error code. The signature is all zero (canonical scalars);
+// decoding does not verify it.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use std::panic;
+
+use tos_common::serializer::Serializer;
+use tos_common::transaction::Transaction;
+
+const TX_VERSION_T1: u8 = 1;
+const CHAIN_ID: u8 = 3;
+/// Offset of the payload in the wire frame: version + chain_id + source + tx_type_id.
+const PAYLOAD_OFFSET: usize = 1 + 1 + 32 + 1;
+
+#[derive(Serialize)]
+struct DecodeErrorVector {
+    name: String,
+    payload_type: String,
+    tx_type_id: u8,
+    corruption_type: String,
+    corrupted_offset: Option<usize>,
+    corrupted_wire_hex: String,
+    expected_error_variant: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DecodeErrorTestFile {
+    algorithm: String,
+    payload_offset: usize,
+    decode_error_vectors: Vec<DecodeErrorVector>,
+}
+
+/// A payload with the offsets used for corruption, relative to the payload.
+struct PayloadCase {
+    payload_type: &'static str,
+    tx_type_id: u8,
+    payload: Vec<u8>,
+    mid_field_offset: usize,
+    discriminant: Option<(usize, u8)>,
+}
+
+fn wire(tx_type_id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = vec![TX_VERSION_T1, CHAIN_ID];
+    out.extend_from_slice(&[0x22; 32]); // source
+    out.push(tx_type_id);
+    out.extend_from_slice(payload);
+    out.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+    out.push(0x00); // fee_type: TOS
+    out.extend_from_slice(&7u64.to_be_bytes()); // nonce
+    out.extend_from_slice(&[0x33; 32]); // ref_hash
+    out.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+    out.push(0x00); // multisig: None
+    out.extend_from_slice(&[0u8; 64]); // signature
+    out
+}
+
+/// Decode with TOS Rust; `None` on success, the error's Debug form otherwise.
+fn decode(name: &str, wire: &[u8]) -> Option<String> {
+    let result = panic::catch_unwind(|| Transaction::from_bytes(wire));
+    match result {
+        Ok(Ok(_)) => None,
+        Ok(Err(err)) => Some(format!("{:?}", err)),
+        Err(_) => panic!("{}: Transaction::from_bytes panicked", name),
+    }
+}
+
+fn cases() -> Vec<PayloadCase> {
+    let mut burn = vec![0u8; 32]; // asset
+    burn.extend_from_slice(&100_000_000u64.to_be_bytes());
+
+    let mut transfers = 1u16.to_be_bytes().to_vec();
+    transfers.extend_from_slice(&[0u8; 32]); // asset
+    transfers.extend_from_slice(&[0x44; 32]); // destination
+    transfers.extend_from_slice(&100_000_000u64.to_be_bytes());
+    transfers.push(0x00); // extra_data: None
+
+    let mut freeze_tos = vec![0x00]; // FreezeTos
+    freeze_tos.extend_from_slice(&100_000_000u64.to_be_bytes());
+    freeze_tos.extend_from_slice(&7u32.to_be_bytes()); // duration days
+
+    let mut deposit_escrow = vec![0xE5; 32];
+    deposit_escrow.extend_from_slice(&500_000_000u64.to_be_bytes());
+
+    vec![
+        PayloadCase {
+            payload_type: "burn",
+            tx_type_id: 0,
+            payload: burn,
+            mid_field_offset: 16,
+            discriminant: None,
+        },
+        PayloadCase {
+            payload_type: "transfers",
+            tx_type_id: 1,
+            payload: transfers,
+            mid_field_offset: 1,
+            // extra_data Option tag after count + asset + destination + amount
+            discriminant: Some((2 + 32 + 32 + 8, 0x02)),
+        },
+        PayloadCase {
+            payload_type: "energy_freeze_tos",
+            tx_type_id: 5,
+            payload: freeze_tos,
+            mid_field_offset: 4,
+            discriminant: Some((0, 0xFF)),
+        },
+        PayloadCase {
+            payload_type: "deposit_escrow",
+            tx_type_id: 25,
+            payload: deposit_escrow,
+            mid_field_offset: 36,
+            discriminant: None,
+        },
+        PayloadCase {
+            payload_type: "update_arbiter_status",
+            tx_type_id: 34,
+            payload: vec![0x40, 0x00], // status flag, Active
+            mid_field_offset: 1,
+            discriminant: Some((1, 0x09)),
+        },
+    ]
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+    for case in cases() {
+        let valid = wire(case.tx_type_id, &case.payload);
+        let name = |corruption: &str| format!("{}_{}", case.payload_type, corruption);
+        assert_eq!(
+            decode(&name("valid"), &valid),
+            None,
+            "{}: baseline must decode",
+            case.payload_type
+        );
+
+        let mut corrupted: Vec<(&str, Option<usize>, Vec<u8>)> = vec![
+            (
+                "truncated_at_payload_start",
+                Some(PAYLOAD_OFFSET),
+                valid[..PAYLOAD_OFFSET].to_vec(),
+            ),
+            (
+                "truncated_mid_field",
+                Some(PAYLOAD_OFFSET + case.mid_field_offset),
+                valid[..PAYLOAD_OFFSET + case.mid_field_offset].to_vec(),
+            ),
+            ("trailing_bytes", Some(valid.len()), {
+                let mut wire = valid.clone();
+                wire.push(0x00);
+                wire
+            }),
+        ];
+        if let Some((offset, value)) = case.discriminant {
+            let mut wire = valid.clone();
+            wire[PAYLOAD_OFFSET + offset] = value;
+            corrupted.push(("bad_discriminant", Some(PAYLOAD_OFFSET + offset), wire));
+        }
+
+        vectors.push(DecodeErrorVector {
+            name: name("valid"),
+            payload_type: case.payload_type.to_string(),
+            tx_type_id: case.tx_type_id,
+            corruption_type: "none".to_string(),
+            corrupted_offset: None,
+            corrupted_wire_hex: hex::encode(&valid),
+            expected_error_variant: None,
+        });
+        for (corruption_type, corrupted_offset, wire) in corrupted {
+            let vector_name = name(corruption_type);
+            let error = decode(&vector_name, &wire);
+            assert!(
+                error.is_some(),
+                "{}: corrupted wire must not decode",
+                vector_name
+            );
+            vectors.push(DecodeErrorVector {
+                name: vector_name,
+                payload_type: case.payload_type.to_string(),
+                tx_type_id: case.tx_type_id,
+                corruption_type: corruption_type.to_string(),
+                corrupted_offset,
+                corrupted_wire_hex: hex::encode(&wire),
+                expected_error_variant: error,
+            });
+        }
+    }
+
+    let test_file = DecodeErrorTestFile {
+        algorithm: "TOS-Tx-Decode-Errors".to_string(),
+        payload_offset: PAYLOAD_OFFSET,
+        decode_error_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("decode_error.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to decode_error.yaml");
+}
@@ -0,0 +1,99 @@
+// Generate DepositEscrow payload amount edge-case test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_deposit_escrow_vectors
+//
+// DepositEscrow (tx type 25) payload, Big-Endian (tos_signer
+// encode_deposit_escrow_payload_inner):
+//
+//   escrow_id: 32 bytes
+//   amount:    u64 BE
+//
+// The payload is always 40 bytes. Every amount decodes; a zero amount is
+// rejected during verification (INVALID_AMOUNT), like every other transfer
+// of value in the spec, so `reject_stage` is `verify` for that vector.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const TX_TYPE_DEPOSIT_ESCROW: u8 = 25;
+const PAYLOAD_SIZE: usize = 32 + 8;
+
+#[derive(Serialize)]
+struct DepositEscrowVector {
+    name: String,
+    description: String,
+    escrow_id_hex: String,
+    amount: u64,
+    is_valid: bool,
+    reject_stage: Option<String>,
+    expected_size: usize,
+    payload_hex: String,
+}
+
+#[derive(Serialize)]
+struct DepositEscrowTestFile {
+    algorithm: String,
+    tx_type_id: u8,
+    deposit_vectors: Vec<DepositEscrowVector>,
+}
+
+fn encode_deposit_escrow(escrow_id: &[u8; 32], amount: u64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(PAYLOAD_SIZE);
+    out.extend_from_slice(escrow_id);
+    out.extend_from_slice(&amount.to_be_bytes());
+    out
+}
+
+fn make_vector(name: &str, description: &str, amount: u64) -> DepositEscrowVector {
+    let escrow_id = [0xE5u8; 32];
+    let payload = encode_deposit_escrow(&escrow_id, amount);
+    assert_eq!(payload.len(), PAYLOAD_SIZE, "{}: size mismatch", name);
+
+    let mut raw = [0u8; 8];
+    raw.copy_from_slice(&payload[32..]);
+    assert_eq!(
+        u64::from_be_bytes(raw),
+        amount,
+        "{}: round-trip mismatch",
+        name
+    );
+
+    let is_valid = amount > 0;
+    DepositEscrowVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        escrow_id_hex: hex::encode(escrow_id),
+        amount,
+        is_valid,
+        reject_stage: (!is_valid).then(|| "verify".to_string()),
+        expected_size: payload.len(),
+        payload_hex: hex::encode(&payload),
+    }
+}
+
+fn main() {
+    let deposit_vectors = vec![
+        make_vector(
+            "amount_zero",
+            "Zero deposit: decodes, rejected at verification",
+            0,
+        ),
+        make_vector("amount_one", "Smallest valid deposit", 1),
+        make_vector("amount_typical", "5 TOS (8 decimals)", 500_000_000),
+        make_vector("amount_large", "1000 TOS (8 decimals)", 100_000_000_000),
+        make_vector("amount_u64_max", "Largest encodable deposit", u64::MAX),
+    ];
+
+    let test_file = DepositEscrowTestFile {
+        algorithm: "DepositEscrow-Payload".to_string(),
+        tx_type_id: TX_TYPE_DEPOSIT_ESCROW,
+        deposit_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("deposit_escrow.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to deposit_escrow.yaml");
+}
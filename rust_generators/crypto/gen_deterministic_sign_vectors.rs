@@ -0,0 +1,152 @@
+// Generate deterministic Schnorr signature test vectors matching tos_signer::sign
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_deterministic_sign_vectors
+//
+// Reproduces the signing path of rust_py/tos_signer byte for byte:
+//
+//   private = Scalar::from_bytes_mod_order([seed_byte, 0, ..., 0])
+//   public  = private^-1 * H                      (H = Pedersen B_blinding)
+//   k       = SHA3-512("tos-signer/deterministic-nonce/v1" || private || public || message)
+//             reduced mod l; k = 1 if the reduction is zero
+//   R       = k * H
+//   e       = SHA3-512(public || message || R) reduced mod l
+//   s       = private^-1 * e + k
+//   sig     = s || e                              (64 bytes)
+//
+// Seeds 0x42 and 0xFF extend the keys to mid and maximum single-byte seeds.
+// signature_hex is the full s || e, as sign_transfer and sign_data return it.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+
+#[derive(Serialize)]
+struct TestVector {
+    name: String,
+    seed_byte: u8,
+    private_hex: String,
+    pubkey_hex: String,
+    message_hex: String,
+    k_hex: String,
+    sig_s_hex: String,
+    sig_e_hex: String,
+    signature_hex: String,
+}
+
+#[derive(Serialize)]
+struct DeterministicSignTestFile {
+    algorithm: String,
+    nonce_domain: String,
+    signature_size: usize,
+    test_vectors: Vec<TestVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn deterministic_nonce(private_key: &Scalar, compressed_pub: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        Scalar::one()
+    } else {
+        k
+    }
+}
+
+/// Returns (k, s, e).
+fn sign(
+    private_key: &Scalar,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    h: &RistrettoPoint,
+) -> (Scalar, Scalar, Scalar) {
+    let k = deterministic_nonce(private_key, compressed_pub, message);
+    let r = k * h;
+    let e = hash_and_point_to_scalar(compressed_pub, message, &r);
+    let s = private_key.invert() * e + k;
+    (k, s, e)
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let messages: [(&str, Vec<u8>); 4] = [
+        ("empty", Vec::new()),
+        ("test", b"test".to_vec()),
+        ("zero32", vec![0x00u8; 32]),
+        ("ff32", vec![0xFFu8; 32]),
+    ];
+
+    let mut vectors = Vec::new();
+    for seed_byte in [0x01u8, 0x02, 0x03, 0x42, 0xFF] {
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = seed_byte;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let public_key = private_key.invert() * h;
+        let compressed_pub = public_key.compress().to_bytes();
+
+        for (label, message) in messages.iter() {
+            let (k, s, e) = sign(&private_key, &compressed_pub, message, &h);
+
+            // Verify: R = s*H - e*P must hash back to e
+            let r = s * h - e * public_key;
+            assert_eq!(
+                hash_and_point_to_scalar(&compressed_pub, message, &r),
+                e,
+                "seed {} / {}: signature does not verify",
+                seed_byte,
+                label
+            );
+
+            let mut signature = [0u8; 64];
+            signature[..32].copy_from_slice(s.as_bytes());
+            signature[32..].copy_from_slice(e.as_bytes());
+
+            vectors.push(TestVector {
+                name: format!("seed_{}_{}", seed_byte, label),
+                seed_byte,
+                private_hex: hex::encode(private_key.as_bytes()),
+                pubkey_hex: hex::encode(compressed_pub),
+                message_hex: hex::encode(message),
+                k_hex: hex::encode(k.as_bytes()),
+                sig_s_hex: hex::encode(s.as_bytes()),
+                sig_e_hex: hex::encode(e.as_bytes()),
+                signature_hex: hex::encode(signature),
+            });
+        }
+    }
+
+    let test_file = DeterministicSignTestFile {
+        algorithm: "TOS-Schnorr-Deterministic".to_string(),
+        nonce_domain: String::from_utf8(NONCE_DOMAIN.to_vec()).unwrap(),
+        signature_size: 64,
+        test_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("deterministic_sign.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to deterministic_sign.yaml");
+}
@@ -0,0 +1,126 @@
+// Generate optional extra_data encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_extra_data_vectors
+//
+// extra_data (Transfer, Shield, Unshield, UnoTransfer, BindReferrer) is an
+// Option<Vec<u8>> (tos_signer Writer::write_optional_vec_u8):
+//
+//   None:       [0x00]
+//   Some(data): [0x01][len:u16 BE][data]
+//
+// Cases cover None, empty, one byte, 255 bytes and the largest encodable
+// length 65535. The encoding allows any u16 length; validation separately
+// caps each extra_data at EXTRA_DATA_LIMIT_SIZE (128) bytes, recorded as
+// `within_size_limit` (128 and 129 pin that boundary).
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const EXTRA_DATA_LIMIT_SIZE: usize = 128;
+
+#[derive(Serialize)]
+struct ExtraDataVector {
+    name: String,
+    description: String,
+    is_present: bool,
+    data_len: Option<usize>,
+    data_hex: Option<String>,
+    encoded_hex: String,
+    encoded_size: usize,
+    within_size_limit: bool,
+}
+
+#[derive(Serialize)]
+struct ExtraDataTestFile {
+    algorithm: String,
+    extra_data_limit_size: usize,
+    extra_data_vectors: Vec<ExtraDataVector>,
+}
+
+fn encode_optional_vec_u8(value: Option<&[u8]>) -> Vec<u8> {
+    match value {
+        None => vec![0x00],
+        Some(data) => {
+            assert!(
+                data.len() <= u16::MAX as usize,
+                "data too long for u16 length"
+            );
+            let mut out = vec![0x01];
+            out.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            out.extend_from_slice(data);
+            out
+        }
+    }
+}
+
+fn make_vector(name: &str, description: &str, len: Option<usize>) -> ExtraDataVector {
+    let data: Option<Vec<u8>> = len.map(|n| (0..n).map(|i| i as u8).collect());
+    let encoded = encode_optional_vec_u8(data.as_deref());
+
+    let expected_size = match len {
+        None => 1,
+        Some(n) => 3 + n,
+    };
+    assert_eq!(encoded.len(), expected_size, "{}: size mismatch", name);
+    if let Some(n) = len {
+        assert_eq!(encoded[0], 0x01, "{}: presence flag", name);
+        assert_eq!(
+            u16::from_be_bytes([encoded[1], encoded[2]]) as usize,
+            n,
+            "{}: length prefix",
+            name
+        );
+    }
+
+    ExtraDataVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        is_present: data.is_some(),
+        data_len: len,
+        data_hex: data.as_ref().map(hex::encode),
+        encoded_hex: hex::encode(&encoded),
+        encoded_size: encoded.len(),
+        within_size_limit: len.map_or(true, |n| n <= EXTRA_DATA_LIMIT_SIZE),
+    }
+}
+
+fn main() {
+    let vectors = vec![
+        make_vector("none", "Absent: single flag byte 0x00", None),
+        make_vector("empty", "Present but empty: 01 0000", Some(0)),
+        make_vector("single_byte", "One data byte: 01 0001 00", Some(1)),
+        make_vector(
+            "limit",
+            "EXTRA_DATA_LIMIT_SIZE bytes",
+            Some(EXTRA_DATA_LIMIT_SIZE),
+        ),
+        make_vector(
+            "limit_plus_one",
+            "One byte over the size limit; encodes, fails validation",
+            Some(EXTRA_DATA_LIMIT_SIZE + 1),
+        ),
+        make_vector(
+            "len_255",
+            "Largest length with a zero high prefix byte",
+            Some(255),
+        ),
+        make_vector(
+            "len_u16_max",
+            "Largest encodable length",
+            Some(u16::MAX as usize),
+        ),
+    ];
+
+    let test_file = ExtraDataTestFile {
+        algorithm: "Optional-Vec-U8".to_string(),
+        extra_data_limit_size: EXTRA_DATA_LIMIT_SIZE,
+        extra_data_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("extra_data.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to extra_data.yaml");
+}
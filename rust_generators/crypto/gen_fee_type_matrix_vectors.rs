@@ -0,0 +1,219 @@
+// Generate the transaction type x fee type acceptance matrix
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_fee_type_matrix_vectors
+//
+// Fee rules (src/tos_spec/state_transition.py::_verify_common, matching ~/tos):
+//
+//   TOS    (0)  any transaction type
+//   Energy (1)  Transfers, UnoTransfers, ShieldTransfers, UnshieldTransfers; fee must be 0
+//   UNO    (2)  UnoTransfers only; fee must be 0
+//   3..=255     not a FeeType; rejected while decoding
+//
+// Every tx_type_id in 0..=47 is listed so a client can check its table row
+// by row. Assigned ids are the types named in this tree plus the escrow
+// (24..=31) and arbitration (33..=38, 44..=47) blocks, some of whose names
+// are not pinned yet (`tx_type_name` is null). An unassigned id fails
+// decoding with every fee type. For assigned ids, a disallowed combination
+// with a known fee type fails verification (INVALID_FORMAT); an unknown fee
+// type fails decoding.
+//
+// `wire_hex` is the signing frame with an empty payload. It is only an
+// envelope sample: fee_type always sits 49 bytes before the end of the
+// frame, whatever the payload.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const TX_VERSION_T1: u8 = 1;
+const CHAIN_ID: u8 = 3;
+const MAX_TX_TYPE_ID: u8 = 47;
+const FEE_TYPE_OFFSET_FROM_END: usize = 8 + 32 + 8 + 1;
+
+const FEE_TYPE_TOS: u8 = 0;
+const FEE_TYPE_ENERGY: u8 = 1;
+const FEE_TYPE_UNO: u8 = 2;
+const FEE_TYPE_UNKNOWN: u8 = 3;
+
+const TX_TYPE_TRANSFERS: u8 = 1;
+const TX_TYPE_ENERGY: u8 = 5;
+const TX_TYPE_UNO_TRANSFERS: u8 = 18;
+const TX_TYPE_SHIELD_TRANSFERS: u8 = 19;
+const TX_TYPE_UNSHIELD_TRANSFERS: u8 = 20;
+
+#[derive(Serialize)]
+struct FeeTypeMatrixVector {
+    name: String,
+    tx_type_id: u8,
+    tx_type_name: Option<String>,
+    tx_type_assigned: bool,
+    fee_type: u8,
+    fee_type_name: Option<String>,
+    fee: u64,
+    allowed: bool,
+    reject_stage: Option<String>,
+    wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct FeeTypeMatrixTestFile {
+    algorithm: String,
+    fee_type_offset_from_end: usize,
+    fee_type_matrix_vectors: Vec<FeeTypeMatrixVector>,
+}
+
+fn tx_type_name(id: u8) -> Option<&'static str> {
+    Some(match id {
+        0 => "Burn",
+        1 => "Transfers",
+        2 => "MultiSig",
+        3 => "InvokeContract",
+        4 => "DeployContract",
+        5 => "Energy",
+        8 => "BatchReferralReward",
+        9 => "SetKyc",
+        10 => "RevokeKyc",
+        11 => "RenewKyc",
+        12 => "BootstrapCommittee",
+        13 => "RegisterCommittee",
+        14 => "UpdateCommittee",
+        15 => "EmergencySuspend",
+        16 => "TransferKyc",
+        17 => "AppealKyc",
+        18 => "UnoTransfers",
+        19 => "ShieldTransfers",
+        20 => "UnshieldTransfers",
+        21 => "RegisterName",
+        22 => "EphemeralMessage",
+        23 => "AgentAccount",
+        24 => "CreateEscrow",
+        25 => "DepositEscrow",
+        31 => "AppealEscrow",
+        33 => "RegisterArbiter",
+        34 => "UpdateArbiter",
+        35 => "SlashArbiter",
+        36 => "RequestArbiterExit",
+        37 => "WithdrawArbiterStake",
+        38 => "CancelArbiterExit",
+        45 => "CommitVoteRequest",
+        47 => "CommitJurorVote",
+        _ => return None,
+    })
+}
+
+/// Whether `id` decodes as a transaction type, named or not.
+fn is_assigned(id: u8) -> bool {
+    // Escrow block, then the arbiter lifecycle and arbitration blocks.
+    tx_type_name(id).is_some() || matches!(id, 24..=31 | 33..=38 | 44..=47)
+}
+
+fn fee_type_name(fee_type: u8) -> Option<&'static str> {
+    match fee_type {
+        FEE_TYPE_TOS => Some("TOS"),
+        FEE_TYPE_ENERGY => Some("Energy"),
+        FEE_TYPE_UNO => Some("UNO"),
+        _ => None,
+    }
+}
+
+fn is_allowed(tx_type_id: u8, fee_type: u8) -> bool {
+    if !is_assigned(tx_type_id) {
+        return false;
+    }
+    match fee_type {
+        FEE_TYPE_TOS => true,
+        FEE_TYPE_ENERGY => matches!(
+            tx_type_id,
+            TX_TYPE_TRANSFERS
+                | TX_TYPE_UNO_TRANSFERS
+                | TX_TYPE_SHIELD_TRANSFERS
+                | TX_TYPE_UNSHIELD_TRANSFERS
+        ),
+        FEE_TYPE_UNO => tx_type_id == TX_TYPE_UNO_TRANSFERS,
+        _ => false,
+    }
+}
+
+/// Energy and UNO fees must be 0, and so must any Energy transaction's fee.
+fn sample_fee(tx_type_id: u8, fee_type: u8) -> u64 {
+    if fee_type == FEE_TYPE_TOS && tx_type_id != TX_TYPE_ENERGY {
+        10_000
+    } else {
+        0
+    }
+}
+
+fn signing_frame(tx_type_id: u8, fee: u64, fee_type: u8) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(92);
+    frame.push(TX_VERSION_T1);
+    frame.push(CHAIN_ID);
+    frame.extend_from_slice(&[0x11; 32]); // source
+    frame.push(tx_type_id);
+    frame.extend_from_slice(&fee.to_be_bytes());
+    frame.push(fee_type);
+    frame.extend_from_slice(&7u64.to_be_bytes()); // nonce
+    frame.extend_from_slice(&[0x33; 32]); // ref_hash
+    frame.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+    frame
+}
+
+fn make_vector(tx_type_id: u8, fee_type: u8) -> FeeTypeMatrixVector {
+    let fee = sample_fee(tx_type_id, fee_type);
+    let wire = signing_frame(tx_type_id, fee, fee_type);
+    assert_eq!(wire.len(), 92);
+    assert_eq!(wire[wire.len() - FEE_TYPE_OFFSET_FROM_END], fee_type);
+
+    let allowed = is_allowed(tx_type_id, fee_type);
+    let reject_stage = if allowed {
+        None
+    } else if !is_assigned(tx_type_id) || fee_type_name(fee_type).is_none() {
+        Some("decode".to_string())
+    } else {
+        Some("verify".to_string())
+    };
+
+    FeeTypeMatrixVector {
+        name: format!("tx_{}_fee_{}", tx_type_id, fee_type),
+        tx_type_id,
+        tx_type_name: tx_type_name(tx_type_id).map(str::to_string),
+        tx_type_assigned: is_assigned(tx_type_id),
+        fee_type,
+        fee_type_name: fee_type_name(fee_type).map(str::to_string),
+        fee,
+        allowed,
+        reject_stage,
+        wire_hex: hex::encode(&wire),
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+    for tx_type_id in 0..=MAX_TX_TYPE_ID {
+        for fee_type in [
+            FEE_TYPE_TOS,
+            FEE_TYPE_ENERGY,
+            FEE_TYPE_UNO,
+            FEE_TYPE_UNKNOWN,
+        ] {
+            vectors.push(make_vector(tx_type_id, fee_type));
+        }
+    }
+
+    let assigned = (0..=MAX_TX_TYPE_ID).filter(|&id| is_assigned(id)).count();
+    let allowed = vectors.iter().filter(|v| v.allowed).count();
+    // Every assigned type with TOS, four transfer types with Energy,
+    // UnoTransfers with UNO.
+    assert_eq!(allowed, assigned + 4 + 1);
+
+    let test_file = FeeTypeMatrixTestFile {
+        algorithm: "TOS-FeeType-Matrix".to_string(),
+        fee_type_offset_from_end: FEE_TYPE_OFFSET_FROM_END,
+        fee_type_matrix_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("fee_type_matrix.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to fee_type_matrix.yaml");
+}
@@ -0,0 +1,175 @@
+// Generate HKDF-SHA3-256 test vectors (TOS session key derivation)
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_hkdf_sha3_256_vectors
+//
+// Same construction as RFC 5869 with SHA3-256 as the hash:
+//
+// HKDF-Extract(salt, IKM) -> PRK = HMAC-SHA3-256(salt, IKM)
+// HKDF-Expand(PRK, info, L) -> OKM (L bytes)
+//
+// HMAC-SHA3-256 has a 136-byte block, so a missing salt, an empty salt and a
+// salt of HashLen (32) zero bytes all pad to the same key and give the same
+// PRK. `prk_hex` is included so implementers can check extract before expand.
+
+use hkdf::Hkdf;
+use serde::Serialize;
+use sha3::Sha3_256;
+use std::fs::File;
+use std::io::Write;
+
+const HASH_LEN: usize = 32;
+
+#[derive(Serialize)]
+struct TestVector {
+    name: String,
+    description: String,
+    ikm_hex: String,
+    /// None means no salt was supplied (HKDF uses HashLen zero bytes)
+    salt_hex: Option<String>,
+    info_hex: String,
+    prk_hex: String,
+    length: usize,
+    okm_hex: String,
+}
+
+#[derive(Serialize)]
+struct HkdfTestFile {
+    algorithm: String,
+    hash_length: usize,
+    test_vectors: Vec<TestVector>,
+}
+
+/// Run extract + expand, returning (PRK, OKM).
+fn hkdf_sha3_256(
+    salt: Option<&[u8]>,
+    ikm: &[u8],
+    info: &[u8],
+    length: usize,
+) -> (Vec<u8>, Vec<u8>) {
+    let (prk, hk) = Hkdf::<Sha3_256>::extract(salt, ikm);
+    let mut okm = vec![0u8; length];
+    hk.expand(info, &mut okm)
+        .expect("length too large for HKDF-SHA3-256");
+    (prk.to_vec(), okm)
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    ikm: &[u8],
+    salt: Option<&[u8]>,
+    info: &[u8],
+    length: usize,
+) -> TestVector {
+    let (prk, okm) = hkdf_sha3_256(salt, ikm, info, length);
+    assert_eq!(prk.len(), HASH_LEN, "{}: PRK must be HashLen bytes", name);
+    TestVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        ikm_hex: hex::encode(ikm),
+        salt_hex: salt.map(hex::encode),
+        info_hex: hex::encode(info),
+        prk_hex: hex::encode(prk),
+        length,
+        okm_hex: hex::encode(okm),
+    }
+}
+
+fn generate_hkdf_sha3_256_vectors() -> HkdfTestFile {
+    let mut vectors = Vec::new();
+    let info = b"tos-p2p-session";
+    let ikm_32: Vec<u8> = (0u8..32).collect();
+    let ikm_64: Vec<u8> = (0u8..64).collect();
+    let salt_32: Vec<u8> = (0u8..32).map(|i| 0xA0 ^ i).collect();
+
+    // Test 1: Zero-length IKM
+    vectors.push(make_vector(
+        "empty_ikm",
+        "Zero-length IKM with 32-byte salt",
+        &[],
+        Some(&salt_32),
+        info,
+        32,
+    ));
+
+    // Test 2: No salt
+    let no_salt = make_vector(
+        "ikm32_no_salt",
+        "32-byte IKM with no salt",
+        &ikm_32,
+        None,
+        info,
+        32,
+    );
+
+    // Test 3: Empty salt (must equal the no-salt case)
+    let empty_salt = make_vector(
+        "ikm32_empty_salt",
+        "32-byte IKM with a zero-length salt (must equal the no-salt case)",
+        &ikm_32,
+        Some(&[]),
+        info,
+        32,
+    );
+    assert_eq!(no_salt.prk_hex, empty_salt.prk_hex);
+    assert_eq!(no_salt.okm_hex, empty_salt.okm_hex);
+    vectors.push(no_salt);
+    vectors.push(empty_salt);
+
+    // Test 4: Empty info
+    vectors.push(make_vector(
+        "ikm32_salt32_empty_info",
+        "32-byte IKM with 32-byte salt and zero-length info",
+        &ikm_32,
+        Some(&salt_32),
+        &[],
+        32,
+    ));
+
+    // Test 5: 32-byte IKM, 32-byte salt, 8-byte info
+    vectors.push(make_vector(
+        "ikm32_salt32_info8",
+        "32-byte IKM with 32-byte salt and 8-byte info",
+        &ikm_32,
+        Some(&salt_32),
+        b"tos-sess",
+        32,
+    ));
+
+    // Test 6: Multi-block expansion (OKM longer than one HMAC output)
+    vectors.push(make_vector(
+        "ikm64_salt32_okm64",
+        "64-byte IKM with 32-byte salt, 64-byte OKM (two expand blocks)",
+        &ikm_64,
+        Some(&salt_32),
+        info,
+        64,
+    ));
+
+    // Test 7: Session setup. IKM is the RFC 7748 X25519 shared secret from
+    // x25519.yaml, so the two phases can be checked end to end.
+    let shared_secret =
+        hex::decode("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742").unwrap();
+    vectors.push(make_vector(
+        "x25519_rfc7748_session",
+        "IKM is the RFC 7748 X25519 shared secret, no salt",
+        &shared_secret,
+        None,
+        info,
+        32,
+    ));
+
+    HkdfTestFile {
+        algorithm: "HKDF-SHA3-256".to_string(),
+        hash_length: HASH_LEN,
+        test_vectors: vectors,
+    }
+}
+
+fn main() {
+    let hkdf_file = generate_hkdf_sha3_256_vectors();
+    let yaml = serde_yaml::to_string(&hkdf_file).unwrap();
+    println!("=== HKDF-SHA3-256 ===\n{}", yaml);
+    let mut file = File::create("hkdf_sha3_256.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to hkdf_sha3_256.yaml");
+}
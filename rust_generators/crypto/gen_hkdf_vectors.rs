@@ -0,0 +1,177 @@
+// Generate HKDF-SHA256 test vectors (RFC 5869, as used for TOS P2P session keys)
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_hkdf_vectors
+//
+// HKDF-Extract(salt, IKM) -> PRK = HMAC-SHA256(salt, IKM)
+// HKDF-Expand(PRK, info, L) -> OKM (L bytes)
+//
+// A missing salt is equivalent to a salt of HashLen (32) zero bytes.
+
+use hkdf::Hkdf;
+use serde::Serialize;
+use sha2::Sha256;
+use std::fs::File;
+use std::io::Write;
+
+const HASH_LEN: usize = 32;
+
+#[derive(Serialize)]
+struct TestVector {
+    name: String,
+    description: String,
+    ikm_hex: String,
+    /// None means no salt was supplied (HKDF uses HashLen zero bytes)
+    salt_hex: Option<String>,
+    info_hex: String,
+    prk_hex: String,
+    output_len: usize,
+    okm_hex: String,
+}
+
+#[derive(Serialize)]
+struct HkdfTestFile {
+    algorithm: String,
+    hash_length: usize,
+    test_vectors: Vec<TestVector>,
+}
+
+/// Run extract + expand, returning (PRK, OKM).
+fn hkdf_sha256(
+    salt: Option<&[u8]>,
+    ikm: &[u8],
+    info: &[u8],
+    output_len: usize,
+) -> (Vec<u8>, Vec<u8>) {
+    let (prk, hk) = Hkdf::<Sha256>::extract(salt, ikm);
+    let mut okm = vec![0u8; output_len];
+    hk.expand(info, &mut okm)
+        .expect("output_len too large for HKDF-SHA256");
+    (prk.to_vec(), okm)
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    ikm: &[u8],
+    salt: Option<&[u8]>,
+    info: &[u8],
+    output_len: usize,
+) -> TestVector {
+    let (prk, okm) = hkdf_sha256(salt, ikm, info, output_len);
+    TestVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        ikm_hex: hex::encode(ikm),
+        salt_hex: salt.map(hex::encode),
+        info_hex: hex::encode(info),
+        prk_hex: hex::encode(prk),
+        output_len,
+        okm_hex: hex::encode(okm),
+    }
+}
+
+fn generate_hkdf_sha256_vectors() -> HkdfTestFile {
+    let mut vectors = Vec::new();
+    let info = b"tos-p2p-session";
+    let ikm_32: Vec<u8> = (0u8..32).collect();
+    let salt_32: Vec<u8> = (0u8..32).map(|i| 0xA0 ^ i).collect();
+    let zero_salt = [0u8; HASH_LEN];
+
+    // Test 1: RFC 5869 Test Case 1 (anchors the implementation)
+    let ikm = hex::decode("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b").unwrap();
+    let salt = hex::decode("000102030405060708090a0b0c").unwrap();
+    let rfc_info = hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap();
+    let vector = make_vector(
+        "rfc5869_test1",
+        "RFC 5869 Test Case 1",
+        &ikm,
+        Some(&salt),
+        &rfc_info,
+        42,
+    );
+    assert_eq!(
+        vector.okm_hex,
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+    vectors.push(vector);
+
+    // Test 2: Zero-length IKM
+    vectors.push(make_vector(
+        "empty_ikm",
+        "Zero-length IKM, no salt",
+        &[],
+        None,
+        info,
+        32,
+    ));
+
+    // Test 3: 32-byte IKM, no salt
+    let no_salt = make_vector(
+        "ikm32_no_salt",
+        "32-byte IKM with no salt",
+        &ikm_32,
+        None,
+        info,
+        32,
+    );
+
+    // Test 4: 32-byte IKM, explicit zero-filled salt of hash length
+    let explicit_zero = make_vector(
+        "ikm32_zero_salt",
+        "32-byte IKM with 32 zero bytes of salt (must equal the no-salt case)",
+        &ikm_32,
+        Some(&zero_salt),
+        info,
+        32,
+    );
+    assert_eq!(no_salt.prk_hex, explicit_zero.prk_hex);
+    assert_eq!(no_salt.okm_hex, explicit_zero.okm_hex);
+    vectors.push(no_salt);
+    vectors.push(explicit_zero);
+
+    // Test 5: 32-byte IKM, 32-byte salt
+    vectors.push(make_vector(
+        "ikm32_salt32",
+        "32-byte IKM with 32-byte salt",
+        &ikm_32,
+        Some(&salt_32),
+        info,
+        32,
+    ));
+
+    // Test 6: Multi-block expansion (OKM longer than one HMAC output)
+    vectors.push(make_vector(
+        "ikm32_salt32_okm64",
+        "32-byte IKM with 32-byte salt, 64-byte OKM (two expand blocks)",
+        &ikm_32,
+        Some(&salt_32),
+        info,
+        64,
+    ));
+
+    // Test 7: Chained extraction + expansion. The first OKM becomes the IKM of
+    // the second stage, keyed with the same salt and a distinct info label.
+    let (_, first_okm) = hkdf_sha256(Some(&salt_32), &ikm_32, info, 32);
+    vectors.push(make_vector(
+        "chained_second_stage",
+        "Second stage: IKM is the 32-byte OKM of ikm32_salt32",
+        &first_okm,
+        Some(&salt_32),
+        b"tos-p2p-session-rekey",
+        32,
+    ));
+
+    HkdfTestFile {
+        algorithm: "HKDF-SHA256".to_string(),
+        hash_length: HASH_LEN,
+        test_vectors: vectors,
+    }
+}
+
+fn main() {
+    let hkdf_file = generate_hkdf_sha256_vectors();
+    let yaml = serde_yaml::to_string(&hkdf_file).unwrap();
+    println!("=== HKDF-SHA256 ===\n{}", yaml);
+    let mut file = File::create("hkdf_sha256.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to hkdf_sha256.yaml");
+}
@@ -0,0 +1,283 @@
+// Generate malformed wire format vectors that every decoder must reject
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_invalid_vectors
+//
+// Each vector starts from a valid encoding and corrupts exactly one field.
+// `reject_stage` says where a conforming client must fail:
+//   decode - the bytes do not deserialize (truncation, bad lengths, bad scalars)
+//   verify - the bytes deserialize, but a point fails to decompress when used
+//
+// `expected_error` is a stable category, not an implementation message:
+//   truncated, count_mismatch, length_overflow, non_canonical_scalar, invalid_point
+//
+// Decoders must return an error for every vector without panicking.
+
+use curve25519_dalek_ng::constants::RISTRETTO_BASEPOINT_COMPRESSED;
+use curve25519_dalek_ng::ristretto::CompressedRistretto;
+use curve25519_dalek_ng::scalar::Scalar;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash, Signature};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{BurnPayload, TransferPayload};
+
+/// Ristretto group order l, little-endian (the smallest non-canonical scalar).
+const GROUP_ORDER_LE: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Not a valid Ristretto encoding (non-canonical field element).
+const INVALID_POINT: [u8; 32] = [0xFF; 32];
+
+#[derive(Serialize)]
+struct InvalidVector {
+    name: String,
+    description: String,
+    tx_type: Option<u8>,
+    corrupted_field: String,
+    reject_stage: String,
+    wire_hex: String,
+    expected_error: String,
+}
+
+#[derive(Serialize)]
+struct InvalidTestFile {
+    algorithm: String,
+    version: u32,
+    invalid_vectors: Vec<InvalidVector>,
+}
+
+fn vector(
+    name: &str,
+    description: &str,
+    tx_type: Option<u8>,
+    corrupted_field: &str,
+    reject_stage: &str,
+    wire: &[u8],
+    expected_error: &str,
+) -> InvalidVector {
+    InvalidVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        tx_type,
+        corrupted_field: corrupted_field.to_string(),
+        reject_stage: reject_stage.to_string(),
+        wire_hex: hex::encode(wire),
+        expected_error: expected_error.to_string(),
+    }
+}
+
+fn transfer(seed: u8) -> TransferPayload {
+    TransferPayload::new(
+        Hash::zero(),
+        CompressedPublicKey::from_bytes(&[seed; 32]).unwrap(),
+        100_000_000,
+        None,
+    )
+}
+
+/// u16 count followed by the given transfers.
+fn transfer_list(count: u16, transfers: &[TransferPayload]) -> Vec<u8> {
+    let mut wire = count.to_be_bytes().to_vec();
+    for t in transfers {
+        wire.extend_from_slice(&t.to_bytes());
+    }
+    wire
+}
+
+/// Shield payload (Type 19) built field by field so each point can be replaced.
+fn shield_wire(commitment: [u8; 32], receiver_handle: [u8; 32]) -> Vec<u8> {
+    let basepoint = RISTRETTO_BASEPOINT_COMPRESSED.to_bytes();
+    let mut wire = Vec::new();
+    wire.extend_from_slice(Hash::zero().as_bytes()); // asset
+    wire.extend_from_slice(&basepoint); // destination
+    wire.extend_from_slice(&1_000_000u64.to_be_bytes()); // amount
+    wire.push(0x00); // extra_data: None
+    wire.extend_from_slice(&commitment);
+    wire.extend_from_slice(&receiver_handle);
+    wire.extend_from_slice(&basepoint); // proof Y_H
+    wire.extend_from_slice(&basepoint); // proof Y_P
+    wire.extend_from_slice(Scalar::one().as_bytes()); // proof z
+    wire
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+
+    assert!(
+        Option::<Scalar>::from(Scalar::from_canonical_bytes(GROUP_ORDER_LE)).is_none(),
+        "group order must be non-canonical"
+    );
+    assert!(
+        CompressedRistretto(INVALID_POINT).decompress().is_none(),
+        "INVALID_POINT must not decompress"
+    );
+
+    // ========================================================================
+    // Truncation
+    // ========================================================================
+
+    {
+        let wire = BurnPayload {
+            asset: Hash::zero(),
+            amount: 1_000_000_000,
+        }
+        .to_bytes();
+        let truncated = &wire[..wire.len() - 4];
+        assert!(BurnPayload::from_bytes(truncated).is_err());
+        vectors.push(vector(
+            "burn_truncated_amount",
+            "Burn payload with the last 4 bytes of amount missing",
+            Some(0),
+            "amount",
+            "decode",
+            truncated,
+            "truncated",
+        ));
+    }
+    {
+        let wire = transfer_list(1, &[]);
+        vectors.push(vector(
+            "transfers_truncated_after_count",
+            "Transfer list declares 1 entry, input ends after the u16 count",
+            Some(1),
+            "transfers",
+            "decode",
+            &wire,
+            "truncated",
+        ));
+    }
+    {
+        let full = transfer(0x01).to_bytes();
+        let truncated = &full[..40];
+        assert!(TransferPayload::from_bytes(truncated).is_err());
+        let mut wire = 1u16.to_be_bytes().to_vec();
+        wire.extend_from_slice(truncated);
+        vectors.push(vector(
+            "transfers_truncated_in_destination",
+            "Single transfer cut off in the middle of the destination key",
+            Some(1),
+            "transfers[0].destination",
+            "decode",
+            &wire,
+            "truncated",
+        ));
+    }
+
+    // ========================================================================
+    // Count greater than the entries present
+    // ========================================================================
+
+    {
+        let entries = [transfer(0x01), transfer(0x02)];
+        let wire = transfer_list(3, &entries);
+        assert!(wire.len() < 2 + 3 * entries[0].size());
+        vectors.push(vector(
+            "transfers_count_exceeds_entries",
+            "Transfer list declares 3 entries but carries 2",
+            Some(1),
+            "transfers.count",
+            "decode",
+            &wire,
+            "count_mismatch",
+        ));
+    }
+
+    // ========================================================================
+    // Length prefix beyond the remaining bytes
+    // ========================================================================
+
+    {
+        let mut wire = transfer(0x01).to_bytes();
+        wire.pop(); // drop the None flag
+        wire.push(0x01); // Some
+        wire.extend_from_slice(&128u16.to_be_bytes()); // declares 128 bytes
+        wire.extend_from_slice(b"memo"); // provides 4
+        assert!(TransferPayload::from_bytes(&wire).is_err());
+        let mut list = 1u16.to_be_bytes().to_vec();
+        list.extend_from_slice(&wire);
+        vectors.push(vector(
+            "transfer_extra_data_length_overflow",
+            "extra_data declares 128 bytes but only 4 remain",
+            Some(1),
+            "transfers[0].extra_data",
+            "decode",
+            &list,
+            "length_overflow",
+        ));
+    }
+
+    // ========================================================================
+    // Non-canonical signature scalars (s || e, 64 bytes)
+    // ========================================================================
+
+    {
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&GROUP_ORDER_LE);
+        assert!(Signature::from_bytes(&sig).is_err());
+        vectors.push(vector(
+            "signature_s_non_canonical",
+            "Signature s equals the group order l",
+            None,
+            "signature.s",
+            "decode",
+            &sig,
+            "non_canonical_scalar",
+        ));
+    }
+    {
+        let mut sig = [0u8; 64];
+        sig[32..].copy_from_slice(&[0xFF; 32]);
+        assert!(Signature::from_bytes(&sig).is_err());
+        vectors.push(vector(
+            "signature_e_high_bit",
+            "Signature e is all 0xFF (exceeds l, high bit set)",
+            None,
+            "signature.e",
+            "decode",
+            &sig,
+            "non_canonical_scalar",
+        ));
+    }
+
+    // ========================================================================
+    // Points that are not valid Ristretto encodings
+    // ========================================================================
+
+    {
+        let basepoint = RISTRETTO_BASEPOINT_COMPRESSED.to_bytes();
+        vectors.push(vector(
+            "shield_commitment_invalid_point",
+            "Shield commitment is not a valid Ristretto encoding",
+            Some(19),
+            "commitment",
+            "verify",
+            &shield_wire(INVALID_POINT, basepoint),
+            "invalid_point",
+        ));
+        vectors.push(vector(
+            "shield_receiver_handle_invalid_point",
+            "Shield receiver_handle is not a valid Ristretto encoding",
+            Some(19),
+            "receiver_handle",
+            "verify",
+            &shield_wire(basepoint, INVALID_POINT),
+            "invalid_point",
+        ));
+    }
+
+    let test_file = InvalidTestFile {
+        algorithm: "TOS-Invalid-Wire".to_string(),
+        version: 1,
+        invalid_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("invalid_wire.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to invalid_wire.yaml");
+}
@@ -0,0 +1,156 @@
+// Generate Kademlia closest-node (FIND_NODE) test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_kademlia_closest_vectors
+//
+// Node IDs are derived as in gen_discv6_vectors:
+//
+//   public_key = scalar^-1 * H,  node_id = SHA3-256(compressed public_key)
+//
+// The routing table holds 20 nodes with secret key [i, 0, ..., 0] for
+// i = 1..=20; the local node uses 100. A FIND_NODE response returns the
+// k = 16 table entries closest to the target, ordered by XOR distance as a
+// 256-bit big-endian integer, closest first. The local node is never part
+// of its own response. Distinct IDs give distinct distances, so the order
+// has no ties.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::scalar::Scalar;
+use serde::Serialize;
+use sha3::{Digest, Sha3_256};
+use std::fs::File;
+use std::io::Write;
+
+const KADEMLIA_K: usize = 16;
+const TABLE_SIZE: u8 = 20;
+const LOCAL_SEED: u8 = 100;
+
+#[derive(Serialize)]
+struct ClosestNodeVector {
+    name: String,
+    description: String,
+    local_id_hex: String,
+    target_id_hex: String,
+    all_node_ids_hex: Vec<String>,
+    closest_16_hex: Vec<String>,
+    closest_16_distances_hex: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct KademliaClosestTestFile {
+    algorithm: String,
+    kademlia_k: usize,
+    closest_node_vectors: Vec<ClosestNodeVector>,
+}
+
+/// node_id = SHA3-256(compress(scalar^-1 * H)) for secret key [seed, 0, ..., 0]
+fn node_id(seed: u8) -> [u8; 32] {
+    let mut secret = [0u8; 32];
+    secret[0] = seed;
+    let h = PedersenGens::default().B_blinding;
+    let public_key = Scalar::from_bytes_mod_order(secret).invert() * h;
+    Sha3_256::digest(public_key.compress().as_bytes()).into()
+}
+
+fn xor_distance(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    for i in 0..32 {
+        result[i] = a[i] ^ b[i];
+    }
+    result
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    local_id: &[u8; 32],
+    table: &[[u8; 32]],
+    target: &[u8; 32],
+) -> ClosestNodeVector {
+    let mut closest: Vec<[u8; 32]> = table.iter().filter(|id| *id != local_id).copied().collect();
+    // Byte arrays compare lexicographically, i.e. as big-endian integers.
+    closest.sort_by_key(|id| xor_distance(id, target));
+    closest.truncate(KADEMLIA_K);
+
+    for pair in closest.windows(2) {
+        assert!(
+            xor_distance(&pair[0], target) < xor_distance(&pair[1], target),
+            "{}: distances must be strictly increasing",
+            name
+        );
+    }
+    let farthest = xor_distance(closest.last().unwrap(), target);
+    let excluded = table.iter().filter(|id| !closest.contains(id)).count();
+    assert_eq!(excluded, table.len() - KADEMLIA_K, "{}: k entries", name);
+    for id in table.iter().filter(|id| !closest.contains(id)) {
+        assert!(
+            xor_distance(id, target) > farthest,
+            "{}: excluded node is closer",
+            name
+        );
+    }
+
+    ClosestNodeVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        local_id_hex: hex::encode(local_id),
+        target_id_hex: hex::encode(target),
+        all_node_ids_hex: table.iter().map(hex::encode).collect(),
+        closest_16_hex: closest.iter().map(hex::encode).collect(),
+        closest_16_distances_hex: closest
+            .iter()
+            .map(|id| hex::encode(xor_distance(id, target)))
+            .collect(),
+    }
+}
+
+fn main() {
+    let table: Vec<[u8; 32]> = (1..=TABLE_SIZE).map(node_id).collect();
+    let local_id = node_id(LOCAL_SEED);
+    assert!(!table.contains(&local_id));
+
+    let mut random_target = [0u8; 32];
+    random_target.copy_from_slice(&Sha3_256::digest(b"tos-spec/kademlia/target"));
+
+    let vectors = vec![
+        make_vector(
+            "random_target",
+            "Target unrelated to any known node",
+            &local_id,
+            &table,
+            &random_target,
+        ),
+        make_vector(
+            "target_is_known_node",
+            "Target equals a table entry, which comes first at distance 0",
+            &local_id,
+            &table,
+            &table[6],
+        ),
+        make_vector(
+            "target_is_local_node",
+            "Lookup of the local ID; the local node is not returned",
+            &local_id,
+            &table,
+            &local_id,
+        ),
+        make_vector(
+            "target_zero",
+            "All-zero target: ordering by node ID value",
+            &local_id,
+            &table,
+            &[0u8; 32],
+        ),
+    ];
+
+    let test_file = KademliaClosestTestFile {
+        algorithm: "Kademlia-XOR-Closest".to_string(),
+        kademlia_k: KADEMLIA_K,
+        closest_node_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("kademlia_closest.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to kademlia_closest.yaml");
+}
@@ -0,0 +1,104 @@
+// Generate KYC level bitmask test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_kyc_level_vectors
+//
+// A KYC level is a u16 bitmask of cumulative verification flags. Each tier
+// adds flags on top of the previous one, so only these levels are valid:
+//
+//   tier:  0  1  2   3   4    5     6     7      8
+//   level: 0  7  31  63  255  2047  8191  16383  32767
+//
+// Any other value, including every single-bit value, is invalid and has no
+// tier. KYC gates compare tiers, never raw level values.
+//
+// Wire format: level as u16 BE.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+/// (level, tier) pairs; must match `_kyc_level_to_tier` in tos_spec.encoding.
+const VALID_LEVELS: [(u16, u8); 9] = [
+    (0, 0),
+    (7, 1),
+    (31, 2),
+    (63, 3),
+    (255, 4),
+    (2047, 5),
+    (8191, 6),
+    (16383, 7),
+    (32767, 8),
+];
+
+#[derive(Serialize)]
+struct KycLevelVector {
+    name: String,
+    level_value: u16,
+    level_binary: String,
+    tier_number: Option<u8>,
+    is_valid: bool,
+    wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct KycLevelTestFile {
+    algorithm: String,
+    max_tier: u8,
+    level_vectors: Vec<KycLevelVector>,
+}
+
+fn level_to_tier(level: u16) -> Option<u8> {
+    VALID_LEVELS
+        .iter()
+        .find(|(valid, _)| *valid == level)
+        .map(|(_, tier)| *tier)
+}
+
+fn make_vector(name: &str, level: u16) -> KycLevelVector {
+    let wire = level.to_be_bytes();
+    assert_eq!(
+        u16::from_be_bytes(wire),
+        level,
+        "{}: round-trip mismatch",
+        name
+    );
+    let tier_number = level_to_tier(level);
+    if let Some(tier) = tier_number {
+        // Every valid level is a contiguous low-bit mask
+        assert_eq!((level as u32 + 1).count_ones(), 1, "{}: not a mask", name);
+        assert!(tier <= 8, "{}", name);
+    }
+
+    KycLevelVector {
+        name: name.to_string(),
+        level_value: level,
+        level_binary: format!("{:016b}", level),
+        tier_number,
+        is_valid: tier_number.is_some(),
+        wire_hex: hex::encode(wire),
+    }
+}
+
+fn main() {
+    let mut level_vectors = vec![
+        make_vector("none", 0),
+        make_vector("tier_1", 7),
+        make_vector("tier_5", 2047),
+        make_vector("tier_8_max", 32767),
+    ];
+    for bit in 0..16 {
+        level_vectors.push(make_vector(&format!("single_bit_{}", bit), 1u16 << bit));
+    }
+
+    let test_file = KycLevelTestFile {
+        algorithm: "KYC-Level-Bitmask".to_string(),
+        max_tier: 8,
+        level_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("kyc_level.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to kyc_level.yaml");
+}
@@ -0,0 +1,363 @@
+// Generate a complete KYC lifecycle as a sequence of signed transactions
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_kyc_workflow_vectors
+//
+// Steps, applied in order to an empty chain:
+//   Type 12: BootstrapCommittee - create the Global committee
+//   Type  9: SetKyc             - Global grants the account Tier 1 (level 7)
+//   Type 11: RenewKyc           - Global renews before the one-year expiry
+//   Type 15: EmergencySuspend   - Global suspends the account for 7 days
+//   Type 17: AppealKyc          - the account appeals the suspension
+//
+// Global is the root committee, so the appeal names it as both the original
+// and the parent committee: there is no higher authority to escalate to.
+//
+// Payload wire formats (Big-Endian, tos_signer encoders):
+//   SetKyc:           [account:32][level:u16][verified_at:u64][data_hash:32]
+//                     [committee_id:32][approvals]
+//   RenewKyc:         [account:32][verified_at:u64][data_hash:32][committee_id:32][approvals]
+//   EmergencySuspend: [account:32][reason_hash:32][committee_id:32][approvals][expires_at:u64]
+//   AppealKyc:        [account:32][original_committee_id:32][parent_committee_id:32]
+//                     [reason_hash:32][documents_hash:32][submitted_at:u64]
+//   approvals:        [count:u16] + [pubkey:32][signature:64][timestamp:u64]...
+//
+// Each step is a full signed transaction (tos_signer deterministic Schnorr,
+// seed byte 1 as sender): signing frame || [multisig:0x00] || [signature:64].
+// Committee approval signatures are structurally valid placeholders; their
+// content is not verified here.
+//
+// `kyc_state_after` is the account's expected KYC record once the step has
+// been applied. Timestamps are seconds.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::crypto::{hash, Hash, PublicKey};
+use tos_common::kyc::MemberRole;
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{BootstrapCommitteePayload, CommitteeMemberInit};
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+const TX_VERSION_T1: u8 = 1;
+const CHAIN_ID: u8 = 3;
+const SEED_BYTE: u8 = 1;
+
+const TX_TYPE_SET_KYC: u8 = 9;
+const TX_TYPE_RENEW_KYC: u8 = 11;
+const TX_TYPE_BOOTSTRAP_COMMITTEE: u8 = 12;
+const TX_TYPE_EMERGENCY_SUSPEND: u8 = 15;
+const TX_TYPE_APPEAL_KYC: u8 = 17;
+
+/// Tier 1 KYC level bitmask.
+const KYC_LEVEL_TIER_1: u16 = 7;
+const KYC_VALIDITY_SECS: u64 = 365 * 86_400;
+const SUSPEND_SECS: u64 = 7 * 86_400;
+
+const VERIFIED_AT: u64 = 1_700_000_000;
+const RENEWED_AT: u64 = VERIFIED_AT + 300 * 86_400;
+const SUSPENDED_AT: u64 = RENEWED_AT + 86_400;
+const APPEALED_AT: u64 = SUSPENDED_AT + 3_600;
+
+#[derive(Serialize, Clone)]
+struct KycState {
+    status: String,
+    level: u16,
+    verified_at: Option<u64>,
+    expires_at: Option<u64>,
+    suspended_until: Option<u64>,
+    appeal_pending: bool,
+    committee_id_hex: Option<String>,
+}
+
+#[derive(Serialize)]
+struct KycWorkflowStep {
+    step: usize,
+    name: String,
+    description: String,
+    tx_type: u8,
+    nonce: u64,
+    committee_id_hex: String,
+    account_hex: Option<String>,
+    payload_hex: String,
+    tx_hex: String,
+    kyc_state_after: KycState,
+}
+
+#[derive(Serialize)]
+struct KycWorkflowTestFile {
+    algorithm: String,
+    chain_id: u8,
+    sender_pubkey_hex: String,
+    workflow_steps: Vec<KycWorkflowStep>,
+}
+
+struct Signer {
+    h: RistrettoPoint,
+    private_key: Scalar,
+    compressed_pub: [u8; 32],
+}
+
+impl Signer {
+    fn from_seed_byte(seed_byte: u8) -> Self {
+        let h = PedersenGens::default().B_blinding;
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = seed_byte;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let compressed_pub = (private_key.invert() * h).compress().to_bytes();
+        Signer {
+            h,
+            private_key,
+            compressed_pub,
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let mut hasher = Sha3_512::new();
+        hasher.update(NONCE_DOMAIN);
+        hasher.update(self.private_key.as_bytes());
+        hasher.update(self.compressed_pub);
+        hasher.update(message);
+        let hash: [u8; 64] = hasher.finalize().into();
+        let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+        if k == Scalar::zero() {
+            k = Scalar::one();
+        }
+        let r = k * self.h;
+
+        let mut hasher = Sha3_512::new();
+        hasher.update(self.compressed_pub);
+        hasher.update(message);
+        hasher.update(r.compress().as_bytes());
+        let hash: [u8; 64] = hasher.finalize().into();
+        let e = Scalar::from_bytes_mod_order_wide(&hash);
+
+        let s = self.private_key.invert() * e + k;
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(s.as_bytes());
+        sig[32..].copy_from_slice(e.as_bytes());
+        sig
+    }
+
+    /// Full signed wire transaction with a TOS fee and no multisig.
+    fn signed_tx(&self, tx_type: u8, payload: &[u8], nonce: u64) -> Vec<u8> {
+        let mut wire = Vec::with_capacity(92 + payload.len() + 1 + 64);
+        wire.push(TX_VERSION_T1);
+        wire.push(CHAIN_ID);
+        wire.extend_from_slice(&self.compressed_pub);
+        wire.push(tx_type);
+        wire.extend_from_slice(payload);
+        wire.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+        wire.push(0x00); // fee_type: TOS
+        wire.extend_from_slice(&nonce.to_be_bytes());
+        wire.extend_from_slice(&[0x33; 32]); // ref_hash
+        wire.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+
+        let signature = self.sign(&wire);
+        wire.push(0x00); // multisig: None
+        wire.extend_from_slice(&signature);
+        wire
+    }
+}
+
+fn test_pubkey(seed: u8) -> PublicKey {
+    PublicKey::from_bytes(&[seed; 32]).expect("Valid pubkey bytes")
+}
+
+/// committee_id = BLAKE3(parent_id || name || member_pubkey...)
+fn derive_committee_id(parent_id: &Hash, name: &str, members: &[PublicKey]) -> Hash {
+    let mut buf = Vec::with_capacity(32 + name.len() + members.len() * 32);
+    buf.extend_from_slice(parent_id.as_bytes());
+    buf.extend_from_slice(name.as_bytes());
+    for member in members {
+        buf.extend_from_slice(member.as_bytes());
+    }
+    hash(&buf)
+}
+
+/// Two approvals from the committee members seeded 0x11 and 0x12.
+fn write_approvals(out: &mut Vec<u8>, timestamp: u64) {
+    out.extend_from_slice(&2u16.to_be_bytes());
+    for seed in [0x11u8, 0x12] {
+        // Scalars with a zero high byte are canonical.
+        let mut signature = [seed; 64];
+        signature[31] = 0;
+        signature[63] = 0;
+        out.extend_from_slice(&[seed; 32]);
+        out.extend_from_slice(&signature);
+        out.extend_from_slice(&timestamp.to_be_bytes());
+    }
+}
+
+fn set_kyc_payload(account: &[u8; 32], committee_id: &Hash) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(account);
+    out.extend_from_slice(&KYC_LEVEL_TIER_1.to_be_bytes());
+    out.extend_from_slice(&VERIFIED_AT.to_be_bytes());
+    out.extend_from_slice(&[0xD1; 32]); // data_hash
+    out.extend_from_slice(committee_id.as_bytes());
+    write_approvals(&mut out, VERIFIED_AT);
+    out
+}
+
+fn renew_kyc_payload(account: &[u8; 32], committee_id: &Hash) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(account);
+    out.extend_from_slice(&RENEWED_AT.to_be_bytes());
+    out.extend_from_slice(&[0xD2; 32]); // data_hash
+    out.extend_from_slice(committee_id.as_bytes());
+    write_approvals(&mut out, RENEWED_AT);
+    out
+}
+
+fn emergency_suspend_payload(account: &[u8; 32], committee_id: &Hash) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(account);
+    out.extend_from_slice(&[0xE1; 32]); // reason_hash
+    out.extend_from_slice(committee_id.as_bytes());
+    write_approvals(&mut out, SUSPENDED_AT);
+    out.extend_from_slice(&(SUSPENDED_AT + SUSPEND_SECS).to_be_bytes());
+    out
+}
+
+fn appeal_kyc_payload(account: &[u8; 32], committee_id: &Hash) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(account);
+    out.extend_from_slice(committee_id.as_bytes()); // original_committee_id
+    out.extend_from_slice(committee_id.as_bytes()); // parent_committee_id (root)
+    out.extend_from_slice(&[0xA2; 32]); // reason_hash
+    out.extend_from_slice(&[0xA3; 32]); // documents_hash
+    out.extend_from_slice(&APPEALED_AT.to_be_bytes());
+    out
+}
+
+fn main() {
+    let signer = Signer::from_seed_byte(SEED_BYTE);
+    let account = [0xACu8; 32];
+    let account_hex = hex::encode(account);
+    let mut steps = Vec::new();
+
+    // Step 1: BootstrapCommittee (Type 12)
+    let members = vec![
+        CommitteeMemberInit::new(
+            test_pubkey(0x11),
+            Some("chair".to_string()),
+            MemberRole::Chair,
+        ),
+        CommitteeMemberInit::new(test_pubkey(0x12), None, MemberRole::ViceChair),
+        CommitteeMemberInit::new(test_pubkey(0x13), None, MemberRole::Member),
+    ];
+    let name = "Global".to_string();
+    let member_keys: Vec<PublicKey> = members.iter().map(|m| m.public_key.clone()).collect();
+    let committee_id = derive_committee_id(&Hash::zero(), &name, &member_keys);
+    let committee_id_hex = hex::encode(committee_id.as_bytes());
+    let bootstrap = BootstrapCommitteePayload::new(name, members, 2, 2, 32767).to_bytes();
+
+    let mut state = KycState {
+        status: "none".to_string(),
+        level: 0,
+        verified_at: None,
+        expires_at: None,
+        suspended_until: None,
+        appeal_pending: false,
+        committee_id_hex: None,
+    };
+
+    let mut push = |name: &str,
+                    description: &str,
+                    tx_type: u8,
+                    account_hex: Option<String>,
+                    payload: Vec<u8>,
+                    state: &KycState| {
+        let nonce = steps.len() as u64;
+        steps.push(KycWorkflowStep {
+            step: steps.len() + 1,
+            name: name.to_string(),
+            description: description.to_string(),
+            tx_type,
+            nonce,
+            committee_id_hex: committee_id_hex.clone(),
+            account_hex,
+            tx_hex: hex::encode(signer.signed_tx(tx_type, &payload, nonce)),
+            payload_hex: hex::encode(&payload),
+            kyc_state_after: state.clone(),
+        });
+    };
+
+    push(
+        "bootstrap_committee",
+        "Create the Global committee (3 members, threshold 2); the account has no KYC yet",
+        TX_TYPE_BOOTSTRAP_COMMITTEE,
+        None,
+        bootstrap,
+        &state,
+    );
+
+    // Step 2: SetKyc (Type 9)
+    state.status = "active".to_string();
+    state.level = KYC_LEVEL_TIER_1;
+    state.verified_at = Some(VERIFIED_AT);
+    state.expires_at = Some(VERIFIED_AT + KYC_VALIDITY_SECS);
+    state.committee_id_hex = Some(committee_id_hex.clone());
+    push(
+        "set_kyc_tier_1",
+        "Global grants Tier 1 (level 7) with 2 of 3 approvals",
+        TX_TYPE_SET_KYC,
+        Some(account_hex.clone()),
+        set_kyc_payload(&account, &committee_id),
+        &state,
+    );
+
+    // Step 3: RenewKyc (Type 11), 300 days in: before the one-year expiry
+    assert!(RENEWED_AT < VERIFIED_AT + KYC_VALIDITY_SECS);
+    state.verified_at = Some(RENEWED_AT);
+    state.expires_at = Some(RENEWED_AT + KYC_VALIDITY_SECS);
+    push(
+        "renew_kyc",
+        "Renewal 300 days after verification; level unchanged, expiry moves forward",
+        TX_TYPE_RENEW_KYC,
+        Some(account_hex.clone()),
+        renew_kyc_payload(&account, &committee_id),
+        &state,
+    );
+
+    // Step 4: EmergencySuspend (Type 15)
+    state.status = "suspended".to_string();
+    state.suspended_until = Some(SUSPENDED_AT + SUSPEND_SECS);
+    push(
+        "emergency_suspend",
+        "Global suspends the account for 7 days; level is kept but not usable",
+        TX_TYPE_EMERGENCY_SUSPEND,
+        Some(account_hex.clone()),
+        emergency_suspend_payload(&account, &committee_id),
+        &state,
+    );
+
+    // Step 5: AppealKyc (Type 17)
+    assert!(APPEALED_AT < SUSPENDED_AT + SUSPEND_SECS);
+    state.appeal_pending = true;
+    push(
+        "appeal_kyc",
+        "The account appeals while suspended; suspension stays until the appeal is decided",
+        TX_TYPE_APPEAL_KYC,
+        Some(account_hex.clone()),
+        appeal_kyc_payload(&account, &committee_id),
+        &state,
+    );
+
+    let test_file = KycWorkflowTestFile {
+        algorithm: "TOS-KYC-Workflow".to_string(),
+        chain_id: CHAIN_ID,
+        sender_pubkey_hex: hex::encode(signer.compressed_pub),
+        workflow_steps: steps,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("kyc_workflow.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to kyc_workflow.yaml");
+}
@@ -0,0 +1,115 @@
+// Generate large (u128 / 256-bit) amount encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_large_amount_vectors
+//
+// Native amounts are u64, but protocol extensions may carry wider values
+// (e.g. cross-chain bridge amounts with 18 decimals). Encodings (Big-Endian):
+//
+//   u64:     8 bytes   (tos_signer Writer::write_u64)
+//   u128:    16 bytes  (tos_signer Writer::write_u128)
+//   uint256: 32 bytes  (left zero-padded, written with Writer::write_bytes)
+//
+// Each vector also records the low 8 bytes of the value, which is what an
+// implementation that silently truncates to u64 would produce.
+
+use num_bigint::BigUint;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+#[derive(Serialize)]
+struct AmountVector {
+    name: String,
+    description: String,
+    value_decimal: String,
+    width_bytes: usize,
+    wire_hex: String,
+    truncated_u64_hex: String,
+    fits_in_u64: bool,
+}
+
+#[derive(Serialize)]
+struct LargeAmountTestFile {
+    algorithm: String,
+    amount_vectors: Vec<AmountVector>,
+}
+
+fn encode_width(value: &BigUint, width: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    assert!(bytes.len() <= width, "value exceeds {} bytes", width);
+    let mut out = vec![0u8; width - bytes.len()];
+    out.extend_from_slice(&bytes);
+    out
+}
+
+fn make_vector(name: &str, description: &str, value: BigUint, width: usize) -> AmountVector {
+    let wire = encode_width(&value, width);
+    if width == 16 {
+        let native = u128::try_from(&value).unwrap();
+        assert_eq!(
+            wire,
+            native.to_be_bytes().to_vec(),
+            "{}: u128 mismatch",
+            name
+        );
+    }
+    let truncated = &wire[wire.len().saturating_sub(8)..];
+    let fits_in_u64 = value <= BigUint::from(u64::MAX);
+
+    AmountVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        value_decimal: value.to_str_radix(10),
+        width_bytes: width,
+        wire_hex: hex::encode(&wire),
+        truncated_u64_hex: hex::encode(truncated),
+        fits_in_u64,
+    }
+}
+
+fn main() {
+    // 1.5M tokens with 18 decimals: exceeds u64 (~1.8e19) but fits u128
+    let bridge_amount: u128 = 1_500_000 * 10u128.pow(18);
+    let max_256 = (BigUint::from(1u8) << 256) - BigUint::from(1u8);
+
+    let amount_vectors = vec![
+        make_vector(
+            "u64_max",
+            "Largest native amount",
+            BigUint::from(u64::MAX),
+            8,
+        ),
+        make_vector("u128_min", "Zero as u128", BigUint::from(u128::MIN), 16),
+        make_vector(
+            "u64_max_plus_1_u128",
+            "Smallest u128 that does not fit in u64",
+            BigUint::from(u64::MAX as u128 + 1),
+            16,
+        ),
+        make_vector(
+            "bridge_amount_u128",
+            "Cross-chain bridge amount: 1,500,000 tokens with 18 decimals",
+            BigUint::from(bridge_amount),
+            16,
+        ),
+        make_vector("u128_max", "Largest u128", BigUint::from(u128::MAX), 16),
+        make_vector(
+            "bridge_amount_uint256",
+            "Bridge amount as a 32-byte big-endian integer",
+            BigUint::from(bridge_amount),
+            32,
+        ),
+        make_vector("uint256_max", "Largest 256-bit amount", max_256, 32),
+    ];
+
+    let test_file = LargeAmountTestFile {
+        algorithm: "Large-Amount-Encoding".to_string(),
+        amount_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("large_amount.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to large_amount.yaml");
+}
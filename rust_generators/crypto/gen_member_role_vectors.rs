@@ -0,0 +1,187 @@
+// Generate committee MemberRole discriminant test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_member_role_vectors
+//
+// MemberRole is a single u8 on the wire:
+//
+//   0 = Chair, 1 = ViceChair, 2 = Member
+//
+// It is the last field of a CommitteeMemberInit entry:
+//
+//   public_key: 32 bytes
+//   name:       Option<String> (0x00 for None, 0x01 + u8 len + utf8 for Some)
+//   role:       u8
+//
+// Each role is encoded with the name present and absent. Entries are built
+// by hand and checked against a TOS Rust BootstrapCommitteePayload holding
+// Chair, ViceChair and Member, so the bytes are pinned to the reference
+// serializer. Values 3 and 255 must fail deserialization.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::PublicKey;
+use tos_common::kyc::MemberRole;
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{BootstrapCommitteePayload, CommitteeMemberInit};
+
+/// Highest known discriminant (Member).
+const MAX_MEMBER_ROLE: u8 = 2;
+
+#[derive(Serialize)]
+struct MemberRoleVector {
+    role_name: Option<String>,
+    role_byte: u8,
+    is_known: bool,
+    member_pubkey_hex: String,
+    member_name: String,
+    wire_hex_with_name: String,
+    wire_hex_without_name: String,
+}
+
+#[derive(Serialize)]
+struct BootstrapVector {
+    name: String,
+    description: String,
+    committee_name: String,
+    role_bytes: Vec<u8>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+    wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct MemberRoleTestFile {
+    algorithm: String,
+    member_role_vectors: Vec<MemberRoleVector>,
+    bootstrap_vectors: Vec<BootstrapVector>,
+}
+
+fn test_pubkey(seed: u8) -> PublicKey {
+    PublicKey::from_bytes(&[seed; 32]).expect("Valid pubkey bytes")
+}
+
+/// [pubkey:32][has_name:u8][len:u8][utf8]?[role:u8]
+fn encode_member(pubkey: &[u8], name: Option<&str>, role: u8) -> Vec<u8> {
+    let mut out = pubkey.to_vec();
+    match name {
+        None => out.push(0x00),
+        Some(name) => {
+            out.push(0x01);
+            out.push(name.len() as u8);
+            out.extend_from_slice(name.as_bytes());
+        }
+    }
+    out.push(role);
+    out
+}
+
+fn make_vector(role_byte: u8) -> MemberRoleVector {
+    let decoded = MemberRole::from_bytes(&[role_byte]);
+    let is_known = decoded.is_ok();
+    assert_eq!(
+        is_known,
+        role_byte <= MAX_MEMBER_ROLE,
+        "role {}: TOS Rust acceptance disagrees with Chair..=Member",
+        role_byte
+    );
+    let role_name = decoded.ok().map(|role| {
+        assert_eq!(role as u8, role_byte, "role {}: discriminant", role_byte);
+        assert_eq!(
+            role.to_bytes(),
+            vec![role_byte],
+            "role {}: re-encoding",
+            role_byte
+        );
+        format!("{:?}", role)
+    });
+
+    let pubkey = [0x40 + role_byte; 32];
+    let member_name = format!("member-{}", role_byte);
+    MemberRoleVector {
+        role_name,
+        role_byte,
+        is_known,
+        member_pubkey_hex: hex::encode(pubkey),
+        wire_hex_with_name: hex::encode(encode_member(&pubkey, Some(&member_name), role_byte)),
+        wire_hex_without_name: hex::encode(encode_member(&pubkey, None, role_byte)),
+        member_name,
+    }
+}
+
+fn main() {
+    let mut member_role_vectors: Vec<MemberRoleVector> =
+        (0..=MAX_MEMBER_ROLE).map(make_vector).collect();
+    let names: Vec<&str> = member_role_vectors
+        .iter()
+        .map(|v| v.role_name.as_deref().unwrap())
+        .collect();
+    assert_eq!(names, ["Chair", "ViceChair", "Member"]);
+    // First unassigned value and the largest byte.
+    member_role_vectors.push(make_vector(MAX_MEMBER_ROLE + 1));
+    member_role_vectors.push(make_vector(u8::MAX));
+
+    // One member per role; the ViceChair has no name.
+    let roles = [
+        (MemberRole::Chair, Some("chair")),
+        (MemberRole::ViceChair, None),
+        (MemberRole::Member, Some("member")),
+    ];
+    let members: Vec<CommitteeMemberInit> = roles
+        .iter()
+        .enumerate()
+        .map(|(i, (role, name))| {
+            CommitteeMemberInit::new(test_pubkey(0x51 + i as u8), name.map(str::to_string), *role)
+        })
+        .collect();
+    let committee_name = "RoleTest".to_string();
+    let (threshold, kyc_threshold, max_kyc_level) = (2u8, 2u8, 32767u16);
+    let payload = BootstrapCommitteePayload::new(
+        committee_name.clone(),
+        members.clone(),
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+    );
+    let wire = payload.to_bytes();
+
+    let mut expected = vec![committee_name.len() as u8];
+    expected.extend_from_slice(committee_name.as_bytes());
+    expected.push(members.len() as u8);
+    for (i, (role, name)) in roles.iter().enumerate() {
+        expected.extend(encode_member(&[0x51 + i as u8; 32], *name, *role as u8));
+    }
+    expected.push(threshold);
+    expected.push(kyc_threshold);
+    expected.extend_from_slice(&max_kyc_level.to_be_bytes());
+    assert_eq!(
+        wire, expected,
+        "bootstrap payload differs from hand-built member entries"
+    );
+
+    let bootstrap_vectors = vec![BootstrapVector {
+        name: "bootstrap_one_of_each_role".to_string(),
+        description: "Chair, ViceChair (no name) and Member in one BootstrapCommittee".to_string(),
+        committee_name,
+        role_bytes: roles.iter().map(|(role, _)| *role as u8).collect(),
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+        wire_hex: hex::encode(&wire),
+        expected_size: payload.size(),
+    }];
+
+    let test_file = MemberRoleTestFile {
+        algorithm: "TOS-Committee-MemberRole".to_string(),
+        member_role_vectors,
+        bootstrap_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("member_role.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to member_role.yaml");
+}
@@ -0,0 +1,227 @@
+// Generate transaction nonce boundary and replay test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_nonce_vectors
+//
+// nonce is a fixed-width u64 BE field of the signing frame, after the fee:
+//
+//   [version:1][chain_id:1][source:32][tx_type_id:1][payload:var]
+//   [fee:8][fee_type:1][nonce:8][ref_hash:32][ref_topo:8]
+//
+// Every vector signs the same Transfer with the seed-byte 1 key (tos_signer
+// deterministic Schnorr); only the nonce changes, so every frame has the
+// same size. Wire: frame || multisig None (0x00) || signature (64 bytes).
+//
+// `replay_vectors` holds two transactions from the same source with the same
+// nonce but different amounts. Both are validly signed; a mempool that has
+// accepted the first must reject the second (NONCE_DUPLICATE).
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+const TX_VERSION_T1: u8 = 1;
+const TX_TYPE_TRANSFERS: u8 = 1;
+const CHAIN_ID: u8 = 0;
+const SEED_BYTE: u8 = 1;
+
+#[derive(Serialize)]
+struct NonceVector {
+    name: String,
+    description: String,
+    nonce: u64,
+    nonce_hex: String,
+    frame_size: usize,
+    signing_bytes_hex: String,
+    signature_hex: String,
+    tx_hex: String,
+}
+
+#[derive(Serialize)]
+struct ReplayVector {
+    name: String,
+    description: String,
+    nonce: u64,
+    amount: u64,
+    tx_hex: String,
+    expected_mempool_result: String,
+}
+
+#[derive(Serialize)]
+struct NonceTestFile {
+    algorithm: String,
+    seed_byte: u8,
+    pubkey_hex: String,
+    nonce_offset: usize,
+    nonce_vectors: Vec<NonceVector>,
+    replay_vectors: Vec<ReplayVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn sign(
+    private_key: &Scalar,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    h: &RistrettoPoint,
+) -> [u8; 64] {
+    let mut hasher = Sha3_512::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        k = Scalar::one();
+    }
+    let r = k * h;
+    let e = hash_and_point_to_scalar(compressed_pub, message, &r);
+    let s = private_key.invert() * e + k;
+    let mut sig = [0u8; 64];
+    sig[..32].copy_from_slice(s.as_bytes());
+    sig[32..].copy_from_slice(e.as_bytes());
+    sig
+}
+
+/// One transfer: [count:u16][asset:32][dest:32][amount:u64][extra_data:None].
+fn transfer_payload(amount: u64) -> Vec<u8> {
+    let mut payload = 1u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(&[0u8; 32]);
+    payload.extend_from_slice(&[0x22; 32]);
+    payload.extend_from_slice(&amount.to_be_bytes());
+    payload.push(0x00);
+    payload
+}
+
+fn signing_bytes(nonce: u64, source: &[u8; 32], payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(92 + payload.len());
+    frame.push(TX_VERSION_T1);
+    frame.push(CHAIN_ID);
+    frame.extend_from_slice(source);
+    frame.push(TX_TYPE_TRANSFERS);
+    frame.extend_from_slice(payload);
+    frame.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+    frame.push(0x00); // fee_type: TOS
+    frame.extend_from_slice(&nonce.to_be_bytes());
+    frame.extend_from_slice(&[0x33; 32]); // ref_hash
+    frame.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+    frame
+}
+
+fn wire(frame: &[u8], signature: &[u8; 64]) -> Vec<u8> {
+    let mut tx = frame.to_vec();
+    tx.push(0x00); // multisig: None
+    tx.extend_from_slice(signature);
+    tx
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let mut priv_bytes = [0u8; 32];
+    priv_bytes[0] = SEED_BYTE;
+    let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+    let compressed_pub = (private_key.invert() * h).compress().to_bytes();
+    let payload = transfer_payload(100_000_000);
+    let nonce_offset = 1 + 1 + 32 + 1 + payload.len() + 8 + 1;
+
+    let cases: [(&str, &str, u64); 4] = [
+        ("nonce_zero", "First transaction of an account", 0),
+        ("nonce_one", "Second transaction", 1),
+        (
+            "nonce_max_minus_one",
+            "Last nonce that leaves a successor",
+            u64::MAX - 1,
+        ),
+        (
+            "nonce_max",
+            "Largest encodable nonce; no successor exists",
+            u64::MAX,
+        ),
+    ];
+
+    let mut nonce_vectors = Vec::new();
+    for (name, description, nonce) in cases {
+        let frame = signing_bytes(nonce, &compressed_pub, &payload);
+        assert_eq!(frame.len(), 92 + payload.len(), "{}: frame size", name);
+        assert_eq!(
+            frame[nonce_offset..nonce_offset + 8],
+            nonce.to_be_bytes(),
+            "{}: nonce must be u64 BE at offset {}",
+            name,
+            nonce_offset
+        );
+        let signature = sign(&private_key, &compressed_pub, &frame, &h);
+
+        nonce_vectors.push(NonceVector {
+            name: name.to_string(),
+            description: description.to_string(),
+            nonce,
+            nonce_hex: hex::encode(nonce.to_be_bytes()),
+            frame_size: frame.len(),
+            signing_bytes_hex: hex::encode(&frame),
+            signature_hex: hex::encode(signature),
+            tx_hex: hex::encode(wire(&frame, &signature)),
+        });
+    }
+
+    let replay_nonce = 5;
+    let replay_cases: [(&str, &str, u64, &str); 2] = [
+        (
+            "replay_first",
+            "First use of the nonce",
+            100_000_000,
+            "accepted",
+        ),
+        (
+            "replay_duplicate",
+            "Same source and nonce, different amount",
+            200_000_000,
+            "NONCE_DUPLICATE",
+        ),
+    ];
+    let mut replay_vectors = Vec::new();
+    for (name, description, amount, expected) in replay_cases {
+        let frame = signing_bytes(replay_nonce, &compressed_pub, &transfer_payload(amount));
+        let signature = sign(&private_key, &compressed_pub, &frame, &h);
+        replay_vectors.push(ReplayVector {
+            name: name.to_string(),
+            description: description.to_string(),
+            nonce: replay_nonce,
+            amount,
+            tx_hex: hex::encode(wire(&frame, &signature)),
+            expected_mempool_result: expected.to_string(),
+        });
+    }
+    assert_ne!(replay_vectors[0].tx_hex, replay_vectors[1].tx_hex);
+
+    let test_file = NonceTestFile {
+        algorithm: "TOS-Tx-Nonce".to_string(),
+        seed_byte: SEED_BYTE,
+        pubkey_hex: hex::encode(compressed_pub),
+        nonce_offset,
+        nonce_vectors,
+        replay_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("nonce.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to nonce.yaml");
+}
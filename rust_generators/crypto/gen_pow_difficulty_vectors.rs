@@ -0,0 +1,214 @@
+// Generate proof-of-work difficulty / target test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_pow_difficulty_vectors
+//
+// TOS carries difficulty as a plain integer rather than a Bitcoin-style
+// nBits mantissa/exponent pair, so `compact_difficulty` is the difficulty
+// value itself (tck/specs/mining-pow.md):
+//
+//   target = MAX_HASH / difficulty          (MAX_HASH = 2^256 - 1, floor)
+//   valid  = u256_be(hash) < target
+//
+// Difficulty adjustment over a window of DIFFICULTY_ADJUSTMENT_BLOCKS:
+//
+//   expected_ms = DIFFICULTY_ADJUSTMENT_BLOCKS * BLOCK_TIME_TARGET_MS
+//   adjusted    = prev * expected_ms / actual_ms      (u128, floor)
+//   adjusted    = clamp(adjusted, max(1, prev / 4), prev * 4)
+//
+// Results above u64::MAX saturate.
+
+use num_bigint::BigUint;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const BLOCK_TIME_TARGET_MS: u64 = 15_000;
+const DIFFICULTY_ADJUSTMENT_BLOCKS: u64 = 720;
+const MIN_DIFFICULTY: u64 = 1;
+
+/// Fixed hash checked against every target: 2^223 (valid while difficulty < 2^33).
+const FIXED_HASH_HEX: &str = "0000000080000000000000000000000000000000000000000000000000000000";
+
+#[derive(Serialize)]
+struct DifficultyVector {
+    name: String,
+    description: String,
+    compact_difficulty: u64,
+    target_hex: String,
+    hash_hex: String,
+    is_valid_pow_for_hash: bool,
+    /// Difficulty after a window in which blocks arrived 20% faster than target
+    adjusted_difficulty: u64,
+}
+
+#[derive(Serialize)]
+struct AdjustmentVector {
+    name: String,
+    description: String,
+    previous_difficulty: u64,
+    expected_time_ms: u64,
+    actual_time_ms: u64,
+    adjusted_difficulty: u64,
+    clamped: bool,
+}
+
+#[derive(Serialize)]
+struct PowDifficultyTestFile {
+    algorithm: String,
+    block_time_target_ms: u64,
+    adjustment_window: u64,
+    difficulty_vectors: Vec<DifficultyVector>,
+    adjustment_vectors: Vec<AdjustmentVector>,
+}
+
+fn max_hash() -> BigUint {
+    (BigUint::from(1u8) << 256) - BigUint::from(1u8)
+}
+
+fn target_from_difficulty(difficulty: u64) -> BigUint {
+    assert!(difficulty >= MIN_DIFFICULTY, "difficulty must be positive");
+    max_hash() / BigUint::from(difficulty)
+}
+
+fn to_hex_32(value: &BigUint) -> String {
+    let bytes = value.to_bytes_be();
+    assert!(bytes.len() <= 32, "value exceeds 256 bits");
+    let mut padded = vec![0u8; 32 - bytes.len()];
+    padded.extend_from_slice(&bytes);
+    hex::encode(padded)
+}
+
+fn expected_window_ms() -> u64 {
+    DIFFICULTY_ADJUSTMENT_BLOCKS * BLOCK_TIME_TARGET_MS
+}
+
+/// Returns (adjusted difficulty, whether the ×4 / ×0.25 bound was hit).
+fn adjust_difficulty(previous: u64, actual_ms: u64) -> (u64, bool) {
+    assert!(actual_ms > 0, "actual window time must be positive");
+    let raw = previous as u128 * expected_window_ms() as u128 / actual_ms as u128;
+    let upper = previous as u128 * 4;
+    let lower = std::cmp::max(MIN_DIFFICULTY as u128, previous as u128 / 4);
+    let clamped = raw.clamp(lower, upper);
+    let adjusted = std::cmp::min(clamped, u64::MAX as u128) as u64;
+    (adjusted, clamped != raw)
+}
+
+fn difficulty_vector(name: &str, description: &str, difficulty: u64) -> DifficultyVector {
+    let target = target_from_difficulty(difficulty);
+    let hash = BigUint::from_bytes_be(&hex::decode(FIXED_HASH_HEX).unwrap());
+    // 20% faster: the window took 80% of the expected time
+    let (adjusted_difficulty, _) = adjust_difficulty(difficulty, expected_window_ms() * 8 / 10);
+
+    DifficultyVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        compact_difficulty: difficulty,
+        target_hex: to_hex_32(&target),
+        hash_hex: FIXED_HASH_HEX.to_string(),
+        is_valid_pow_for_hash: hash < target,
+        adjusted_difficulty,
+    }
+}
+
+fn adjustment_vector(
+    name: &str,
+    description: &str,
+    previous: u64,
+    actual_ms: u64,
+) -> AdjustmentVector {
+    let (adjusted_difficulty, clamped) = adjust_difficulty(previous, actual_ms);
+    AdjustmentVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        previous_difficulty: previous,
+        expected_time_ms: expected_window_ms(),
+        actual_time_ms: actual_ms,
+        adjusted_difficulty,
+        clamped,
+    }
+}
+
+fn main() {
+    let expected = expected_window_ms();
+
+    let difficulty_vectors = vec![
+        difficulty_vector(
+            "min_difficulty",
+            "Minimum difficulty: target is MAX_HASH",
+            MIN_DIFFICULTY,
+        ),
+        difficulty_vector(
+            "typical_mainnet",
+            "Typical mainnet difficulty (1e9)",
+            1_000_000_000,
+        ),
+        difficulty_vector(
+            "boundary_valid",
+            "Largest difficulty for which the fixed hash is still valid (2^33 - 1)",
+            (1u64 << 33) - 1,
+        ),
+        difficulty_vector(
+            "boundary_invalid",
+            "Smallest difficulty for which the fixed hash is rejected (2^33)",
+            1u64 << 33,
+        ),
+        difficulty_vector(
+            "max_difficulty",
+            "Maximum u64 difficulty (adjustment saturates at u64::MAX)",
+            u64::MAX,
+        ),
+    ];
+
+    let adjustment_vectors = vec![
+        adjustment_vector(
+            "on_target",
+            "Window took exactly the expected time",
+            1_000_000,
+            expected,
+        ),
+        adjustment_vector(
+            "faster_20pct",
+            "Blocks arrived 20% faster than target",
+            1_000_000,
+            expected * 8 / 10,
+        ),
+        adjustment_vector(
+            "slower_20pct",
+            "Blocks arrived 20% slower than target",
+            1_000_000,
+            expected * 12 / 10,
+        ),
+        adjustment_vector(
+            "clamp_increase",
+            "Window 10x faster than target: increase clamped to x4",
+            1_000_000,
+            expected / 10,
+        ),
+        adjustment_vector(
+            "clamp_decrease",
+            "Window 10x slower than target: decrease clamped to x0.25",
+            1_000_000,
+            expected * 10,
+        ),
+        adjustment_vector(
+            "min_difficulty_floor",
+            "Very slow window at minimum difficulty stays at 1",
+            MIN_DIFFICULTY,
+            expected * 10,
+        ),
+    ];
+
+    let test_file = PowDifficultyTestFile {
+        algorithm: "PoW-Difficulty-Target".to_string(),
+        block_time_target_ms: BLOCK_TIME_TARGET_MS,
+        adjustment_window: DIFFICULTY_ADJUSTMENT_BLOCKS,
+        difficulty_vectors,
+        adjustment_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("pow_difficulty.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to pow_difficulty.yaml");
+}
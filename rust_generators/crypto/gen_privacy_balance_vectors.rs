@@ -0,0 +1,221 @@
+// Generate encrypted balance tracking test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_privacy_balance_vectors
+//
+// A UNO balance is a twisted ElGamal ciphertext under the owner's key P:
+//
+//   commitment C = v*G + r*H        (G = Pedersen B, H = B_blinding)
+//   handle     D = r*P              (P = s^-1 * H, s = private key)
+//
+// Each transaction contributes a delta ciphertext; the wallet updates its
+// balance component-wise:
+//
+//   shield        (type 19): balance += (C_tx, D_receiver)
+//   uno transfer  (type 18) out: balance -= (C_tx, D_sender)
+//   uno transfer  (type 18) in:  balance += (C_tx, D_receiver)
+//
+// For every step `commitment_after = commitment_before + delta_commitment`
+// (and the same for handles) is asserted by point arithmetic, along with
+// the decryption C - s*D = v*G. The balance starts as the identity
+// ciphertext (v = 0, r = 0). Keys are tos_signer seed-byte keys; openings are
+// SHA3-512("tos-spec/privacy-balance/opening" || step) mod l.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar, traits::Identity};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const OWNER_SEED: u8 = 1;
+const COUNTERPARTY_SEED: u8 = 2;
+
+#[derive(Serialize)]
+struct BalanceStep {
+    name: String,
+    description: String,
+    tx_type_id: u8,
+    amount: u64,
+    tx_opening_hex: String,
+    value_before: u64,
+    value_after: u64,
+    commitment_before_hex: String,
+    delta_commitment_hex: String,
+    commitment_after_hex: String,
+    handle_before_hex: String,
+    delta_handle_hex: String,
+    handle_after_hex: String,
+    opening_after_hex: String,
+    decrypted_point_hex: String,
+}
+
+#[derive(Serialize)]
+struct BalanceTestFile {
+    algorithm: String,
+    g_hex: String,
+    h_hex: String,
+    owner_seed_byte: u8,
+    owner_pubkey_hex: String,
+    counterparty_seed_byte: u8,
+    balance_steps: Vec<BalanceStep>,
+}
+
+/// Encrypted balance with the plaintext and opening the wallet knows.
+#[derive(Clone, Copy)]
+struct Balance {
+    value: u64,
+    opening: Scalar,
+    commitment: RistrettoPoint,
+    handle: RistrettoPoint,
+}
+
+fn opening(step: &str) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(b"tos-spec/privacy-balance/opening");
+    hasher.update(step.as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn keypair(seed_byte: u8, h: &RistrettoPoint) -> (Scalar, RistrettoPoint) {
+    let mut bytes = [0u8; 32];
+    bytes[0] = seed_byte;
+    let private = Scalar::from_bytes_mod_order(bytes);
+    (private, private.invert() * h)
+}
+
+fn hex_point(point: &RistrettoPoint) -> String {
+    hex::encode(point.compress().as_bytes())
+}
+
+fn main() {
+    let pc_gens = PedersenGens::default();
+    let g = pc_gens.B;
+    let h = pc_gens.B_blinding;
+
+    let (owner_private, owner_pub) = keypair(OWNER_SEED, &h);
+    let (_, counterparty_pub) = keypair(COUNTERPARTY_SEED, &h);
+
+    // (name, description, tx_type_id, amount, incoming)
+    let steps: [(&str, &str, u8, u64, bool); 3] = [
+        (
+            "shield",
+            "Shield 10 TOS of plaintext balance into the encrypted balance",
+            19,
+            1_000_000_000,
+            true,
+        ),
+        (
+            "uno_transfer_out",
+            "Send 3 TOS to the counterparty; the sender handle is subtracted",
+            18,
+            300_000_000,
+            false,
+        ),
+        (
+            "uno_transfer_in",
+            "Receive 4 TOS from the counterparty; the receiver handle is added",
+            18,
+            400_000_000,
+            true,
+        ),
+    ];
+
+    let mut balance = Balance {
+        value: 0,
+        opening: Scalar::zero(),
+        commitment: RistrettoPoint::identity(),
+        handle: RistrettoPoint::identity(),
+    };
+    let mut balance_steps = Vec::new();
+    for (name, description, tx_type_id, amount, incoming) in steps {
+        let r = opening(name);
+        let tx_commitment = Scalar::from(amount) * g + r * h;
+        // The owner's handle on the tx: receiver handle when incoming,
+        // sender handle when outgoing. Both are r * P_owner.
+        let owner_handle = r * owner_pub;
+        if !incoming {
+            // The counterparty decrypts the same commitment with its own handle.
+            let counterparty_handle = r * counterparty_pub;
+            assert_ne!(counterparty_handle, owner_handle);
+        }
+
+        let (delta_commitment, delta_handle) = if incoming {
+            (tx_commitment, owner_handle)
+        } else {
+            (-tx_commitment, -owner_handle)
+        };
+
+        let before = balance;
+        let after = Balance {
+            value: if incoming {
+                before.value + amount
+            } else {
+                before.value - amount
+            },
+            opening: if incoming {
+                before.opening + r
+            } else {
+                before.opening - r
+            },
+            commitment: before.commitment + delta_commitment,
+            handle: before.handle + delta_handle,
+        };
+
+        assert_eq!(
+            after.commitment,
+            Scalar::from(after.value) * g + after.opening * h,
+            "{}: commitment_after does not open to value_after",
+            name
+        );
+        assert_eq!(
+            after.handle,
+            after.opening * owner_pub,
+            "{}: handle_after mismatch",
+            name
+        );
+        let decrypted = after.commitment - owner_private * after.handle;
+        assert_eq!(
+            decrypted,
+            Scalar::from(after.value) * g,
+            "{}: decryption mismatch",
+            name
+        );
+
+        balance_steps.push(BalanceStep {
+            name: name.to_string(),
+            description: description.to_string(),
+            tx_type_id,
+            amount,
+            tx_opening_hex: hex::encode(r.as_bytes()),
+            value_before: before.value,
+            value_after: after.value,
+            commitment_before_hex: hex_point(&before.commitment),
+            delta_commitment_hex: hex_point(&delta_commitment),
+            commitment_after_hex: hex_point(&after.commitment),
+            handle_before_hex: hex_point(&before.handle),
+            delta_handle_hex: hex_point(&delta_handle),
+            handle_after_hex: hex_point(&after.handle),
+            opening_after_hex: hex::encode(after.opening.as_bytes()),
+            decrypted_point_hex: hex_point(&decrypted),
+        });
+        balance = after;
+    }
+    assert_eq!(balance.value, 1_100_000_000);
+
+    let test_file = BalanceTestFile {
+        algorithm: "TOS-UNO-Balance-Tracking".to_string(),
+        g_hex: hex_point(&g),
+        h_hex: hex_point(&h),
+        owner_seed_byte: OWNER_SEED,
+        owner_pubkey_hex: hex_point(&owner_pub),
+        counterparty_seed_byte: COUNTERPARTY_SEED,
+        balance_steps,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("privacy_balance.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to privacy_balance.yaml");
+}
@@ -0,0 +1,194 @@
+// gen_referral_tree_vectors.rs - BatchReferralReward (Type 8) distribution vectors
+//
+// A batch referral reward splits `total_amount` across `levels` upline levels.
+// Each level receives a share expressed in basis points (1/10000):
+//
+//   level_amount[i] = total_amount * ratios[i] / 10000   (u128 intermediate, floor)
+//
+// The sum of all level amounts must never exceed `total_amount`; any remainder
+// left by integer division stays with the sender.
+//
+// Wire format (Big-Endian):
+//   asset:        32 bytes (Hash)
+//   from_user:    32 bytes (CompressedPublicKey)
+//   total_amount: u64 BE
+//   levels:       u8
+//   ratios:       u8 count + u16 BE basis points...
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::BatchReferralRewardPayload;
+
+const BASIS_POINTS: u128 = 10_000;
+
+#[derive(Serialize)]
+struct ReferralTreeVector {
+    name: String,
+    description: String,
+    asset_hex: String,
+    from_user_hex: String,
+    total_amount: u64,
+    levels: u8,
+    ratios: Vec<u16>,
+    level_amounts: Vec<u64>,
+    distributed_total: u64,
+    remainder: u64,
+    wire_hex: String,
+    expected_size: usize,
+}
+
+#[derive(Serialize)]
+struct ReferralTreeTestVectors {
+    algorithm: String,
+    version: u32,
+    referral_tree_vectors: Vec<ReferralTreeVector>,
+}
+
+/// Compute per-level payouts exactly as TOS Rust does (floor, u128 intermediate).
+fn distribute(total_amount: u64, ratios: &[u16]) -> Vec<u64> {
+    ratios
+        .iter()
+        .map(|ratio| ((total_amount as u128 * *ratio as u128) / BASIS_POINTS) as u64)
+        .collect()
+}
+
+fn generate_vector(
+    name: &str,
+    description: &str,
+    total_amount: u64,
+    ratios: Vec<u16>,
+) -> ReferralTreeVector {
+    let asset = Hash::zero(); // TOS native asset
+    let from_user = CompressedPublicKey::from_bytes(&[0x42u8; 32]).expect("Valid pubkey bytes");
+    let levels = ratios.len() as u8;
+
+    let ratio_sum: u32 = ratios.iter().map(|r| *r as u32).sum();
+    assert!(ratio_sum as u128 <= BASIS_POINTS, "{}: ratios exceed 100%", name);
+
+    let level_amounts = distribute(total_amount, &ratios);
+    let distributed_total = level_amounts
+        .iter()
+        .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+        .expect("level amounts overflow u64");
+    assert!(
+        distributed_total <= total_amount,
+        "{}: distributed {} exceeds total {}",
+        name,
+        distributed_total,
+        total_amount
+    );
+
+    let payload = BatchReferralRewardPayload::new(
+        asset.clone(),
+        from_user.clone(),
+        total_amount,
+        levels,
+        ratios.clone(),
+    );
+    let wire = payload.to_bytes();
+
+    ReferralTreeVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        asset_hex: hex::encode(asset.as_bytes()),
+        from_user_hex: hex::encode(from_user.as_bytes()),
+        total_amount,
+        levels,
+        ratios,
+        level_amounts,
+        distributed_total,
+        remainder: total_amount - distributed_total,
+        wire_hex: hex::encode(&wire),
+        expected_size: payload.size(),
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+
+    // 1-level chains
+    vectors.push(generate_vector(
+        "referral_1_level_uniform",
+        "1 level, 10% to the direct referrer",
+        1_000_000_000,
+        vec![1000],
+    ));
+    vectors.push(generate_vector(
+        "referral_1_level_full",
+        "1 level, 100% to the direct referrer",
+        1_000_000_000,
+        vec![10000],
+    ));
+
+    // 3-level chains
+    vectors.push(generate_vector(
+        "referral_3_level_uniform",
+        "3 levels, 10% each",
+        1_000_000_000,
+        vec![1000, 1000, 1000],
+    ));
+    vectors.push(generate_vector(
+        "referral_3_level_tapered",
+        "3 levels tapered 50% / 30% / 20%",
+        1_000_000_000,
+        vec![5000, 3000, 2000],
+    ));
+    vectors.push(generate_vector(
+        "referral_3_level_rounding",
+        "3 levels, uniform 33.33% on an amount not divisible by 3 (remainder stays with sender)",
+        100,
+        vec![3333, 3333, 3333],
+    ));
+
+    // 5-level chains
+    vectors.push(generate_vector(
+        "referral_5_level_uniform",
+        "5 levels, 5% each",
+        1_000_000_000,
+        vec![500, 500, 500, 500, 500],
+    ));
+    vectors.push(generate_vector(
+        "referral_5_level_tapered",
+        "5 levels tapered 10% / 5% / 3% / 2% / 1%",
+        1_000_000_000,
+        vec![1000, 500, 300, 200, 100],
+    ));
+    vectors.push(generate_vector(
+        "referral_5_level_max_amount",
+        "5 levels tapered on u64::MAX (u128 intermediate must not overflow)",
+        u64::MAX,
+        vec![4000, 3000, 1500, 1000, 500],
+    ));
+
+    let test_file = ReferralTreeTestVectors {
+        algorithm: "Referral-Tree-Distribution".to_string(),
+        version: 1,
+        referral_tree_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# Referral Tree Distribution Test Vectors (Type 8: BatchReferralReward)
+# Generated by TOS Rust - gen_referral_tree_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# Distribution:
+#   level_amount[i] = total_amount * ratios[i] / 10000 (u128 intermediate, floor)
+#   sum(level_amounts) <= total_amount; remainder stays with the sender
+#
+# Wire Format:
+#   [asset:32][from_user:32][total_amount:u64][levels:u8][ratio_count:u8][ratio:u16]...
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("referral_tree.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to referral_tree.yaml");
+}
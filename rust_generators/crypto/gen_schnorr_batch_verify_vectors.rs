@@ -0,0 +1,187 @@
+// Generate TOS Schnorr batch verification test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_schnorr_batch_verify_vectors
+//
+// Signatures use the TOS scheme (see gen_schnorr_vectors / tos_signer):
+//   public = private^-1 * H
+//   R = k*H, e = SHA3-512(public || message || R), s = private^-1 * e + k
+//   verify: R' = s*H - e*P, accept iff SHA3-512(P || message || R') == e
+//
+// A signature is stored as (s, e) rather than (R, s), so R' must be
+// recovered per entry before hashing. A batch verifier may then combine
+// the recovered entries, but the batch is valid iff every entry verifies.
+// Each batch records `batch_valid` and, if invalid, the offending index.
+//
+// Keys are derived from private = [i + 1, 0, ..., 0]; nonces are the
+// tos_signer deterministic nonces so every vector is reproducible.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+#[derive(Serialize)]
+struct BatchEntry {
+    pubkey_hex: String,
+    message_hex: String,
+    s_hex: String,
+    e_hex: String,
+}
+
+#[derive(Serialize)]
+struct BatchVector {
+    name: String,
+    description: String,
+    batch_size: usize,
+    batch_valid: bool,
+    invalid_index: Option<usize>,
+    entries: Vec<BatchEntry>,
+}
+
+#[derive(Serialize)]
+struct SchnorrBatchTestFile {
+    algorithm: String,
+    signature_size: usize,
+    batch_vectors: Vec<BatchVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn deterministic_nonce(private_key: &Scalar, compressed_pub: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(b"tos-signer/deterministic-nonce/v1");
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        Scalar::one()
+    } else {
+        k
+    }
+}
+
+struct SignedMessage {
+    public: RistrettoPoint,
+    message: Vec<u8>,
+    s: Scalar,
+    e: Scalar,
+}
+
+fn sign(index: usize, h: &RistrettoPoint) -> SignedMessage {
+    let mut priv_bytes = [0u8; 32];
+    priv_bytes[0] = (index + 1) as u8;
+    let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+    let public = private_key.invert() * h;
+    let compressed_pub = public.compress().to_bytes();
+    let message = format!("batch message {}", index).into_bytes();
+
+    let k = deterministic_nonce(&private_key, &compressed_pub, &message);
+    let r = k * h;
+    let e = hash_and_point_to_scalar(&compressed_pub, &message, &r);
+    let s = private_key.invert() * e + k;
+    SignedMessage {
+        public,
+        message,
+        s,
+        e,
+    }
+}
+
+fn verify(entry: &SignedMessage, h: &RistrettoPoint) -> bool {
+    let r = entry.s * h - entry.e * entry.public;
+    hash_and_point_to_scalar(&entry.public.compress().to_bytes(), &entry.message, &r) == entry.e
+}
+
+fn make_batch(
+    name: &str,
+    description: &str,
+    size: usize,
+    corrupt: Option<usize>,
+    h: &RistrettoPoint,
+) -> BatchVector {
+    let mut signed: Vec<SignedMessage> = (0..size).map(|i| sign(i, h)).collect();
+    if let Some(index) = corrupt {
+        signed[index].s += Scalar::one();
+    }
+
+    let failures: Vec<usize> = signed
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| !verify(entry, h))
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(
+        failures,
+        corrupt.into_iter().collect::<Vec<_>>(),
+        "{}",
+        name
+    );
+
+    BatchVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        batch_size: size,
+        batch_valid: failures.is_empty(),
+        invalid_index: failures.first().copied(),
+        entries: signed
+            .iter()
+            .map(|entry| BatchEntry {
+                pubkey_hex: hex::encode(entry.public.compress().to_bytes()),
+                message_hex: hex::encode(&entry.message),
+                s_hex: hex::encode(entry.s.as_bytes()),
+                e_hex: hex::encode(entry.e.as_bytes()),
+            })
+            .collect(),
+    }
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let batch_vectors = vec![
+        make_batch("batch_5_valid", "5 valid signatures", 5, None, &h),
+        make_batch("batch_10_valid", "10 valid signatures", 10, None, &h),
+        make_batch("batch_50_valid", "50 valid signatures", 50, None, &h),
+        make_batch(
+            "batch_10_invalid_at_3",
+            "10 signatures; entry 3 has s incremented by one",
+            10,
+            Some(3),
+            &h,
+        ),
+        make_batch(
+            "batch_50_invalid_at_49",
+            "50 signatures; the last entry has s incremented by one",
+            50,
+            Some(49),
+            &h,
+        ),
+    ];
+
+    let test_file = SchnorrBatchTestFile {
+        algorithm: "TOS-Schnorr-Batch".to_string(),
+        signature_size: 64,
+        batch_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("schnorr_batch_verify.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to schnorr_batch_verify.yaml");
+}
@@ -0,0 +1,239 @@
+// Generate TOS Schnorr signature malleability test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_sig_malleability_vectors
+//
+// Signatures are s || e (32-byte LE scalars each), verified as:
+//
+//   R' = s*H - e*P,  accept iff SHA3-512(P || message || R') == e
+//
+// Variants of a valid signature covered here:
+//
+//   negated        (-s mod l, -e mod l): R' becomes -R, which hashes to an
+//                  unrelated scalar, so the equation fails. e is bound to R
+//                  by the hash, so no canonicalisation rule is needed to reject it.
+//   s_plus_order   s + l: same scalar mod l, different bytes. Rejected only
+//                  because verifiers require canonical scalars (< l).
+//   e_plus_order   e + l: as above for e.
+//
+// `reduced_equation_holds` is the result with both scalars reduced mod l and
+// shows which rejections depend on the canonical-scalar check.
+// `expected_valid` is the result of the TOS verifier (tos_signer
+// verify_signature).
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{constants::BASEPOINT_ORDER, ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+
+#[derive(Serialize)]
+struct MalleabilityVector {
+    name: String,
+    description: String,
+    seed_byte: u8,
+    public_key_hex: String,
+    message_hex: String,
+    signature_s_hex: String,
+    signature_e_hex: String,
+    signature_hex: String,
+    s_canonical: bool,
+    e_canonical: bool,
+    reduced_equation_holds: bool,
+    expected_valid: bool,
+}
+
+#[derive(Serialize)]
+struct MalleabilityTestFile {
+    algorithm: String,
+    group_order_hex: String,
+    malleability_vectors: Vec<MalleabilityVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn sign(
+    private_key: &Scalar,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    h: &RistrettoPoint,
+) -> (Scalar, Scalar) {
+    let mut hasher = Sha3_512::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        k = Scalar::one();
+    }
+    let r = k * h;
+    let e = hash_and_point_to_scalar(compressed_pub, message, &r);
+    let s = private_key.invert() * e + k;
+    (s, e)
+}
+
+/// The verification equation over scalars already reduced mod l.
+fn equation_holds(
+    public_key: &RistrettoPoint,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    s: &Scalar,
+    e: &Scalar,
+    h: &RistrettoPoint,
+) -> bool {
+    let r = s * h - e * public_key;
+    hash_and_point_to_scalar(compressed_pub, message, &r) == *e
+}
+
+/// Little-endian 256-bit x + l. Never overflows since x < l < 2^253.
+fn add_order(x: &[u8; 32]) -> [u8; 32] {
+    let l = BASEPOINT_ORDER.as_bytes();
+    let mut out = [0u8; 32];
+    let mut carry = 0u16;
+    for i in 0..32 {
+        let sum = x[i] as u16 + l[i] as u16 + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+    assert_eq!(carry, 0);
+    out
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    seed_byte: u8,
+    message: &[u8],
+    s_bytes: [u8; 32],
+    e_bytes: [u8; 32],
+    h: &RistrettoPoint,
+) -> MalleabilityVector {
+    let mut priv_bytes = [0u8; 32];
+    priv_bytes[0] = seed_byte;
+    let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+    let public_key = private_key.invert() * h;
+    let compressed_pub = public_key.compress().to_bytes();
+
+    let s_canonical = Scalar::from_canonical_bytes(s_bytes).is_some();
+    let e_canonical = Scalar::from_canonical_bytes(e_bytes).is_some();
+    let reduced_equation_holds = equation_holds(
+        &public_key,
+        &compressed_pub,
+        message,
+        &Scalar::from_bytes_mod_order(s_bytes),
+        &Scalar::from_bytes_mod_order(e_bytes),
+        h,
+    );
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&s_bytes);
+    signature[32..].copy_from_slice(&e_bytes);
+
+    MalleabilityVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        seed_byte,
+        public_key_hex: hex::encode(compressed_pub),
+        message_hex: hex::encode(message),
+        signature_s_hex: hex::encode(s_bytes),
+        signature_e_hex: hex::encode(e_bytes),
+        signature_hex: hex::encode(signature),
+        s_canonical,
+        e_canonical,
+        reduced_equation_holds,
+        expected_valid: s_canonical && e_canonical && reduced_equation_holds,
+    }
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let cases: [(&str, u8, &[u8]); 2] = [("hello", 1, b"Hello, world!"), ("empty", 7, b"")];
+
+    let mut vectors = Vec::new();
+    for (label, seed_byte, message) in cases {
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = seed_byte;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let compressed_pub = (private_key.invert() * h).compress().to_bytes();
+        let (s, e) = sign(&private_key, &compressed_pub, message, &h);
+
+        let original = make_vector(
+            &format!("{}_original", label),
+            "Valid signature",
+            seed_byte,
+            message,
+            s.to_bytes(),
+            e.to_bytes(),
+            &h,
+        );
+        assert!(original.expected_valid, "{}: original must verify", label);
+
+        let negated = make_vector(
+            &format!("{}_negated", label),
+            "(-s mod l, -e mod l): R' = -R hashes to a different e",
+            seed_byte,
+            message,
+            (-s).to_bytes(),
+            (-e).to_bytes(),
+            &h,
+        );
+        assert!(
+            !negated.reduced_equation_holds,
+            "{}: negated signature must fail the equation",
+            label
+        );
+
+        let s_plus_order = make_vector(
+            &format!("{}_s_plus_order", label),
+            "s + l: equation holds mod l, rejected as non-canonical",
+            seed_byte,
+            message,
+            add_order(&s.to_bytes()),
+            e.to_bytes(),
+            &h,
+        );
+        let e_plus_order = make_vector(
+            &format!("{}_e_plus_order", label),
+            "e + l: equation holds mod l, rejected as non-canonical",
+            seed_byte,
+            message,
+            s.to_bytes(),
+            add_order(&e.to_bytes()),
+            &h,
+        );
+        for v in [&s_plus_order, &e_plus_order] {
+            assert!(v.reduced_equation_holds, "{}: must hold mod l", v.name);
+            assert!(!v.expected_valid, "{}: must be rejected", v.name);
+        }
+
+        vectors.extend([original, negated, s_plus_order, e_plus_order]);
+    }
+
+    let test_file = MalleabilityTestFile {
+        algorithm: "TOS-Schnorr-Malleability".to_string(),
+        group_order_hex: hex::encode(BASEPOINT_ORDER.as_bytes()),
+        malleability_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("sig_malleability.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to sig_malleability.yaml");
+}
@@ -0,0 +1,161 @@
+// Generate TOS Schnorr signature size invariant test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_sig_size_vectors
+//
+// A TOS signature is always exactly 64 bytes, two fixed-width scalars:
+//
+//   s: 32 bytes LE || e: 32 bytes LE
+//
+// There is no DER or length prefix. Ten seed-byte keys (tos_signer
+// deterministic Schnorr) each sign a message of a different length; every
+// signature must be 64 bytes and verify. `swapped_verifies` checks the
+// order: reading the bytes as e || s must fail.
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+const SIGNATURE_SIZE: usize = 64;
+
+#[derive(Serialize)]
+struct SigSizeVector {
+    name: String,
+    key_seed: u8,
+    public_key_hex: String,
+    message_len: usize,
+    message_hex: String,
+    sig_hex: String,
+    sig_len: usize,
+    s_hex: String,
+    e_hex: String,
+    swapped_verifies: bool,
+}
+
+#[derive(Serialize)]
+struct SigSizeTestFile {
+    algorithm: String,
+    signature_size: usize,
+    sig_size_vectors: Vec<SigSizeVector>,
+}
+
+fn hash_and_point_to_scalar(
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    point: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha3_512::new();
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    hasher.update(point.compress().as_bytes());
+    let hash: [u8; 64] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn sign(
+    private_key: &Scalar,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    h: &RistrettoPoint,
+) -> Vec<u8> {
+    let mut hasher = Sha3_512::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(private_key.as_bytes());
+    hasher.update(compressed_pub);
+    hasher.update(message);
+    let hash: [u8; 64] = hasher.finalize().into();
+    let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+    if k == Scalar::zero() {
+        k = Scalar::one();
+    }
+    let r = k * h;
+    let e = hash_and_point_to_scalar(compressed_pub, message, &r);
+    let s = private_key.invert() * e + k;
+    let mut sig = s.as_bytes().to_vec();
+    sig.extend_from_slice(e.as_bytes());
+    sig
+}
+
+/// R = s*H - e*P must hash back to e; scalars must be canonical.
+fn verify(
+    public_key: &RistrettoPoint,
+    compressed_pub: &[u8; 32],
+    message: &[u8],
+    signature: &[u8],
+    h: &RistrettoPoint,
+) -> bool {
+    let mut s_bytes = [0u8; 32];
+    let mut e_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(&signature[..32]);
+    e_bytes.copy_from_slice(&signature[32..]);
+    let (Some(s), Some(e)) = (
+        Scalar::from_canonical_bytes(s_bytes),
+        Scalar::from_canonical_bytes(e_bytes),
+    ) else {
+        return false;
+    };
+    let r = s * h - e * public_key;
+    hash_and_point_to_scalar(compressed_pub, message, &r) == e
+}
+
+fn main() {
+    let h = PedersenGens::default().B_blinding;
+
+    let message_lens: [usize; 10] = [0, 1, 31, 32, 33, 64, 127, 255, 256, 1024];
+
+    let mut vectors = Vec::new();
+    for (i, message_len) in message_lens.into_iter().enumerate() {
+        let key_seed = (i + 1) as u8;
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = key_seed;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let public_key = private_key.invert() * h;
+        let compressed_pub = public_key.compress().to_bytes();
+
+        let message: Vec<u8> = (0..message_len)
+            .map(|j| (j as u8).wrapping_mul(31).wrapping_add(key_seed))
+            .collect();
+        let signature = sign(&private_key, &compressed_pub, &message, &h);
+
+        let name = format!("key_{}_msg_{}", key_seed, message_len);
+        assert_eq!(signature.len(), SIGNATURE_SIZE, "{}: signature size", name);
+        assert!(
+            verify(&public_key, &compressed_pub, &message, &signature, &h),
+            "{}: signature does not verify",
+            name
+        );
+
+        let mut swapped = signature[32..].to_vec();
+        swapped.extend_from_slice(&signature[..32]);
+        let swapped_verifies = verify(&public_key, &compressed_pub, &message, &swapped, &h);
+        assert!(!swapped_verifies, "{}: e || s must not verify", name);
+
+        vectors.push(SigSizeVector {
+            name,
+            key_seed,
+            public_key_hex: hex::encode(compressed_pub),
+            message_len,
+            message_hex: hex::encode(&message),
+            sig_hex: hex::encode(&signature),
+            sig_len: signature.len(),
+            s_hex: hex::encode(&signature[..32]),
+            e_hex: hex::encode(&signature[32..]),
+            swapped_verifies,
+        });
+    }
+
+    let test_file = SigSizeTestFile {
+        algorithm: "TOS-Schnorr-Signature-Size".to_string(),
+        signature_size: SIGNATURE_SIZE,
+        sig_size_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("sig_size.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to sig_size.yaml");
+}
@@ -0,0 +1,153 @@
+// Generate UTF-8 string wire encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_string_encoding_vectors
+//
+// Strings in payloads (arbiter name, committee name, task_id, refund reason,
+// TNS name) are encoded as:
+//
+//   len:  u8 (number of UTF-8 BYTES, not characters)
+//   data: UTF-8 bytes
+//
+// Strings longer than 255 bytes cannot be encoded. Individual fields may
+// impose tighter limits (e.g. TNS name length); those are not covered here.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::serializer::Serializer;
+
+const MAX_STRING_BYTES: usize = u8::MAX as usize;
+
+#[derive(Serialize)]
+struct StringVector {
+    name: String,
+    description: String,
+    string_utf8: String,
+    char_count: usize,
+    byte_length: usize,
+    encoded_len_prefix: u8,
+    wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct InvalidStringVector {
+    name: String,
+    description: String,
+    byte_length: usize,
+    expected_error: String,
+}
+
+#[derive(Serialize)]
+struct StringEncodingTestFile {
+    algorithm: String,
+    max_byte_length: usize,
+    string_vectors: Vec<StringVector>,
+    invalid_vectors: Vec<InvalidStringVector>,
+}
+
+fn encode_string(value: &str) -> Result<Vec<u8>, String> {
+    let bytes = value.as_bytes();
+    if bytes.len() > MAX_STRING_BYTES {
+        return Err(format!(
+            "string is {} bytes, at most {} allowed",
+            bytes.len(),
+            MAX_STRING_BYTES
+        ));
+    }
+    let mut out = Vec::with_capacity(1 + bytes.len());
+    out.push(bytes.len() as u8);
+    out.extend_from_slice(bytes);
+    Ok(out)
+}
+
+fn make_vector(name: &str, description: &str, value: &str) -> StringVector {
+    let wire = encode_string(value).unwrap();
+    assert_eq!(
+        wire,
+        value.to_string().to_bytes(),
+        "{}: differs from the TOS String serializer",
+        name
+    );
+    let decoded = String::from_bytes(&wire).expect("round-trip decode failed");
+    assert_eq!(decoded, value, "{}: round-trip mismatch", name);
+
+    StringVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        string_utf8: value.to_string(),
+        char_count: value.chars().count(),
+        byte_length: value.len(),
+        encoded_len_prefix: wire[0],
+        wire_hex: hex::encode(&wire),
+    }
+}
+
+fn invalid_vector(name: &str, description: &str, value: &str) -> InvalidStringVector {
+    let error = encode_string(value).expect_err("oversized string encoded successfully");
+    InvalidStringVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        byte_length: value.len(),
+        expected_error: error,
+    }
+}
+
+fn main() {
+    let string_vectors = vec![
+        make_vector("empty", "Empty string", ""),
+        make_vector("ascii_1", "Single ASCII byte", "a"),
+        make_vector("ascii_127", "127 ASCII bytes", &"a".repeat(127)),
+        make_vector(
+            "ascii_128",
+            "128 ASCII bytes (length prefix has bit 7 set)",
+            &"a".repeat(128),
+        ),
+        make_vector(
+            "ascii_255",
+            "255 ASCII bytes (maximum length)",
+            &"a".repeat(255),
+        ),
+        make_vector(
+            "japanese",
+            "Japanese text: 5 characters, 3 bytes each",
+            "こんにちは",
+        ),
+        make_vector("emoji", "Emoji: 4-byte UTF-8 sequences", "🚀🔒"),
+        make_vector(
+            "mixed",
+            "ASCII, 2-byte, 3-byte and 4-byte characters",
+            "TOS é 日本 🚀",
+        ),
+        make_vector(
+            "multibyte_255",
+            "85 three-byte characters: 85 chars, 255 bytes",
+            &"日".repeat(85),
+        ),
+    ];
+
+    let invalid_vectors = vec![
+        invalid_vector(
+            "ascii_256",
+            "256 ASCII bytes exceed the u8 length prefix",
+            &"a".repeat(256),
+        ),
+        invalid_vector(
+            "multibyte_86",
+            "86 three-byte characters: only 86 chars but 258 bytes",
+            &"日".repeat(86),
+        ),
+    ];
+
+    let test_file = StringEncodingTestFile {
+        algorithm: "UTF8-String-u8-Length".to_string(),
+        max_byte_length: MAX_STRING_BYTES,
+        string_vectors,
+        invalid_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("string_encoding.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to string_encoding.yaml");
+}
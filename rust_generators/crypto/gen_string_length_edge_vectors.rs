@@ -0,0 +1,144 @@
+// Generate string length-prefix boundary test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_string_length_edge_vectors
+//
+// Two length-prefix widths are in use for string fields:
+//
+//   u8  prefix: TOS String serializer (arbiter name, committee name, task_id,
+//               TNS name)                      -> at most 255 bytes
+//   u16 prefix: BE length (AppealEscrow reason) -> at most 65535 bytes
+//
+// Each width is exercised at 0, 255, 256 and 65535 bytes, plus 65536 for u16.
+// A length the prefix cannot hold has no wire form: encoders must refuse it
+// rather than truncate the prefix (256 as u8 would silently become 0).
+// Valid 256-byte and larger strings break decoders that copy into a fixed
+// 255/256-byte buffer.
+//
+// Field-specific limits (e.g. TNS name length) are not covered here.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::serializer::Serializer;
+
+#[derive(Serialize)]
+struct StringLengthEdgeVector {
+    name: String,
+    description: String,
+    prefix_width_bytes: usize,
+    byte_length: usize,
+    raw_string: String,
+    is_valid: bool,
+    encoded_length_prefix_hex: Option<String>,
+    wire_hex: Option<String>,
+    expected_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StringLengthEdgeTestFile {
+    algorithm: String,
+    string_length_edge_vectors: Vec<StringLengthEdgeVector>,
+}
+
+fn encode(prefix_width: usize, value: &str) -> Result<Vec<u8>, String> {
+    let bytes = value.as_bytes();
+    let max = match prefix_width {
+        1 => u8::MAX as usize,
+        2 => u16::MAX as usize,
+        _ => unreachable!("unsupported prefix width"),
+    };
+    if bytes.len() > max {
+        return Err(format!(
+            "string is {} bytes, at most {} allowed",
+            bytes.len(),
+            max
+        ));
+    }
+    let mut out = Vec::with_capacity(prefix_width + bytes.len());
+    if prefix_width == 1 {
+        out.push(bytes.len() as u8);
+    } else {
+        out.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+    }
+    out.extend_from_slice(bytes);
+    Ok(out)
+}
+
+fn make_vector(prefix_width: usize, byte_length: usize) -> StringLengthEdgeVector {
+    let value = "a".repeat(byte_length);
+    let name = format!("u{}_prefix_len_{}", prefix_width * 8, byte_length);
+    let result = encode(prefix_width, &value);
+
+    if let Ok(wire) = &result {
+        assert_eq!(
+            wire.len(),
+            prefix_width + byte_length,
+            "{}: size mismatch",
+            name
+        );
+        if prefix_width == 1 {
+            assert_eq!(
+                *wire,
+                value.to_bytes(),
+                "{}: differs from the TOS String serializer",
+                name
+            );
+        }
+    }
+
+    let description = match (&result, byte_length) {
+        (Ok(_), 0) => "Empty string".to_string(),
+        (Ok(_), n) if n == (1usize << (8 * prefix_width)) - 1 => {
+            format!("{} bytes: largest length the prefix can hold", n)
+        }
+        (Ok(_), n) => format!("{} bytes", n),
+        (Err(_), n) => format!(
+            "{} bytes: does not fit a {}-byte prefix, must be refused",
+            n, prefix_width
+        ),
+    };
+
+    StringLengthEdgeVector {
+        name,
+        description,
+        prefix_width_bytes: prefix_width,
+        byte_length,
+        raw_string: value,
+        is_valid: result.is_ok(),
+        encoded_length_prefix_hex: result
+            .as_ref()
+            .ok()
+            .map(|wire| hex::encode(&wire[..prefix_width])),
+        wire_hex: result.as_ref().ok().map(hex::encode),
+        expected_error: result.err(),
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+    for byte_length in [0, 255, 256, 65535] {
+        vectors.push(make_vector(1, byte_length));
+    }
+    for byte_length in [0, 255, 256, 65535, 65536] {
+        vectors.push(make_vector(2, byte_length));
+    }
+
+    let u8_valid: Vec<usize> = vectors
+        .iter()
+        .filter(|v| v.prefix_width_bytes == 1 && v.is_valid)
+        .map(|v| v.byte_length)
+        .collect();
+    assert_eq!(u8_valid, vec![0, 255]);
+    assert!(String::from_bytes(&[0x00]).unwrap().is_empty());
+
+    let test_file = StringLengthEdgeTestFile {
+        algorithm: "Length-Prefixed-UTF8-Boundaries".to_string(),
+        string_length_edge_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("string_length_edge.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to string_length_edge.yaml");
+}
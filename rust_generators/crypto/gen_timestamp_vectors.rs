@@ -0,0 +1,122 @@
+// Generate timestamp encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_timestamp_vectors
+//
+// Timestamps (CommitteeApproval, EphemeralMessage, SubmitVerdict, ...) are
+// encoded as a plain u64 BE. The wire format carries no unit: the same
+// 8 bytes mean seconds in one field and milliseconds in another. Each vector
+// records both UTC readings so a unit mix-up (x1000 scaling) shows up as an
+// obviously wrong date rather than a silent off-by-one.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+#[derive(Serialize)]
+struct TimestampVector {
+    name: String,
+    interpretation: String,
+    raw_u64_value: u64,
+    be_bytes_hex: String,
+    as_seconds_utc: String,
+    as_milliseconds_utc: String,
+}
+
+#[derive(Serialize)]
+struct TimestampTestFile {
+    algorithm: String,
+    width_bytes: usize,
+    timestamp_vectors: Vec<TimestampVector>,
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian (year, month, day).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn format_utc(secs: u64, millis: Option<u64>) -> String {
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let rem = secs % 86_400;
+    let time = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    );
+    match millis {
+        Some(ms) => format!("{}.{:03}Z", time, ms),
+        None => format!("{}Z", time),
+    }
+}
+
+fn make_vector(name: &str, interpretation: &str, value: u64) -> TimestampVector {
+    let wire = value.to_be_bytes();
+    assert_eq!(
+        u64::from_be_bytes(wire),
+        value,
+        "{}: round-trip mismatch",
+        name
+    );
+
+    TimestampVector {
+        name: name.to_string(),
+        interpretation: interpretation.to_string(),
+        raw_u64_value: value,
+        be_bytes_hex: hex::encode(wire),
+        as_seconds_utc: format_utc(value, None),
+        as_milliseconds_utc: format_utc(value / 1000, Some(value % 1000)),
+    }
+}
+
+fn main() {
+    assert_eq!(format_utc(0, None), "1970-01-01T00:00:00Z");
+    assert_eq!(format_utc(951_782_400, None), "2000-02-29T00:00:00Z");
+
+    let timestamp_vectors = vec![
+        make_vector("epoch", "Unix epoch in either unit", 0),
+        make_vector("one", "1 second (or 1 millisecond) after the epoch", 1),
+        make_vector(
+            "u32_max",
+            "Largest 32-bit seconds value (2106-02-07); a u32 seconds field overflows after this",
+            u32::MAX as u64,
+        ),
+        make_vector(
+            "nov_2023_millis",
+            "2023-11-14T22:13:20Z in milliseconds; read as seconds it lands in year 55840",
+            1_700_000_000_000,
+        ),
+        make_vector(
+            "u64_max",
+            "Largest encodable value; far beyond any real date in either unit",
+            u64::MAX,
+        ),
+    ];
+    assert_eq!(
+        timestamp_vectors[3].as_milliseconds_utc,
+        "2023-11-14T22:13:20.000Z"
+    );
+
+    let test_file = TimestampTestFile {
+        algorithm: "u64-BE-Timestamp".to_string(),
+        width_bytes: 8,
+        timestamp_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("timestamp.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to timestamp.yaml");
+}
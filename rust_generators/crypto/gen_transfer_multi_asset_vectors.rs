@@ -0,0 +1,130 @@
+// Generate multi-transfer (Type 1) wire format test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_transfer_multi_asset_vectors
+//
+// Extends the single-transfer vectors in gen_basic_vectors with several
+// transfers in one transaction. Wire format (Big-Endian):
+//
+//   count:     u16 BE
+//   transfers: TransferPayload... (count entries, in order)
+//
+// TransferPayload:
+//   asset:       32 bytes
+//   destination: 32 bytes
+//   amount:      u64 BE
+//   extra_data:  Option (0x00 for None)
+//
+// Transfer i alternates between two assets and cycles through three
+// destinations; amount = (i + 1) * 0.01 TOS.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::TransferPayload;
+
+const AMOUNT_STEP: u64 = 1_000_000; // 0.01 TOS
+
+#[derive(Serialize)]
+struct MultiTransferVector {
+    name: String,
+    description: String,
+    transfers_count: usize,
+    distinct_assets: usize,
+    distinct_destinations: usize,
+    total_amount: u64,
+    wire_hex: String,
+    total_payload_size: usize,
+}
+
+#[derive(Serialize)]
+struct MultiTransferTestFile {
+    algorithm: String,
+    version: u32,
+    multi_transfer_vectors: Vec<MultiTransferVector>,
+}
+
+fn assets() -> [Hash; 2] {
+    [Hash::zero(), Hash::new([0xAAu8; 32])]
+}
+
+fn destinations() -> [CompressedPublicKey; 3] {
+    [0x01u8, 0x02, 0x03].map(|seed| CompressedPublicKey::from_bytes(&[seed; 32]).unwrap())
+}
+
+fn build_transfers(count: usize) -> Vec<TransferPayload> {
+    let assets = assets();
+    let destinations = destinations();
+    (0..count)
+        .map(|i| {
+            TransferPayload::new(
+                assets[i % assets.len()].clone(),
+                destinations[i % destinations.len()].clone(),
+                (i as u64 + 1) * AMOUNT_STEP,
+                None,
+            )
+        })
+        .collect()
+}
+
+/// Parse the list back with TransferPayload::from_bytes and compare each entry.
+fn check_parse(name: &str, wire: &[u8], transfers: &[TransferPayload]) {
+    let count = u16::from_be_bytes([wire[0], wire[1]]) as usize;
+    assert_eq!(count, transfers.len(), "{}: count mismatch", name);
+
+    let mut offset = 2;
+    for (i, transfer) in transfers.iter().enumerate() {
+        let end = offset + transfer.size();
+        let parsed = TransferPayload::from_bytes(&wire[offset..end])
+            .unwrap_or_else(|e| panic!("{}: transfer {} does not parse: {:?}", name, i, e));
+        assert_eq!(
+            parsed.to_bytes(),
+            transfer.to_bytes(),
+            "{}: transfer {} round-trip mismatch",
+            name,
+            i
+        );
+        offset = end;
+    }
+    assert_eq!(offset, wire.len(), "{}: trailing bytes", name);
+}
+
+fn make_vector(count: usize) -> MultiTransferVector {
+    let name = format!("transfers_{}", count);
+    let transfers = build_transfers(count);
+
+    let mut wire = (count as u16).to_be_bytes().to_vec();
+    for transfer in &transfers {
+        wire.extend_from_slice(&transfer.to_bytes());
+    }
+    check_parse(&name, &wire, &transfers);
+
+    MultiTransferVector {
+        description: format!("{} transfers alternating assets and destinations", count),
+        transfers_count: count,
+        distinct_assets: count.min(assets().len()),
+        distinct_destinations: count.min(destinations().len()),
+        total_amount: (1..=count as u64).sum::<u64>() * AMOUNT_STEP,
+        total_payload_size: wire.len(),
+        wire_hex: hex::encode(&wire),
+        name,
+    }
+}
+
+fn main() {
+    let multi_transfer_vectors = [2, 5, 10, 50].into_iter().map(make_vector).collect();
+
+    let test_file = MultiTransferTestFile {
+        algorithm: "TOS-Multi-Transfer".to_string(),
+        version: 1,
+        multi_transfer_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("transfer_multi_asset.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to transfer_multi_asset.yaml");
+}
@@ -0,0 +1,246 @@
+// Generate transaction ID (txid) derivation test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_txid_derivation_vectors
+//
+// txid = Transaction::hash() = BLAKE3(full signed wire bytes)
+//
+// The hash covers everything on the wire, signature included, so any
+// one-byte change yields a different txid. Transactions are signed with the
+// seed-byte 1 key (tos_signer deterministic Schnorr) over the signing frame:
+//
+//   [version:1][chain_id:1][source:32][tx_type_id:1][payload:var]
+//   [fee:8][fee_type:1][nonce:8][ref_hash:32][ref_topo:8]
+//
+// and serialized as frame || [multisig:Option=0x00] || [signature:64].
+//
+// `mutation_of` names the vector a tampered entry was derived from; its
+// `mutated_offset` is the wire byte that was flipped (inside the payload).
+
+use bulletproofs::PedersenGens;
+use curve25519_dalek_ng::{ristretto::RistrettoPoint, scalar::Scalar};
+use serde::Serialize;
+use sha3::{Digest, Sha3_512};
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash, Hashable};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{BurnPayload, Transaction, TransferPayload};
+
+const NONCE_DOMAIN: &[u8] = b"tos-signer/deterministic-nonce/v1";
+const TX_VERSION_T1: u8 = 1;
+const CHAIN_ID: u8 = 3;
+const SEED_BYTE: u8 = 1;
+
+const TX_TYPE_BURN: u8 = 0;
+const TX_TYPE_TRANSFERS: u8 = 1;
+const TX_TYPE_DEPOSIT_ESCROW: u8 = 25;
+
+/// Offset of the payload in the wire frame: version + chain_id + source + tx_type_id.
+const PAYLOAD_OFFSET: usize = 1 + 1 + 32 + 1;
+
+#[derive(Serialize)]
+struct TxidVector {
+    name: String,
+    description: String,
+    tx_type_id: u8,
+    mutation_of: Option<String>,
+    mutated_offset: Option<usize>,
+    tx_hex: String,
+    txid_hex: String,
+}
+
+#[derive(Serialize)]
+struct TxidTestFile {
+    algorithm: String,
+    hash_function: String,
+    txid_vectors: Vec<TxidVector>,
+}
+
+struct Signer {
+    h: RistrettoPoint,
+    private_key: Scalar,
+    compressed_pub: [u8; 32],
+}
+
+impl Signer {
+    fn from_seed_byte(seed_byte: u8) -> Self {
+        let h = PedersenGens::default().B_blinding;
+        let mut priv_bytes = [0u8; 32];
+        priv_bytes[0] = seed_byte;
+        let private_key = Scalar::from_bytes_mod_order(priv_bytes);
+        let compressed_pub = (private_key.invert() * h).compress().to_bytes();
+        Signer {
+            h,
+            private_key,
+            compressed_pub,
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let mut hasher = Sha3_512::new();
+        hasher.update(NONCE_DOMAIN);
+        hasher.update(self.private_key.as_bytes());
+        hasher.update(self.compressed_pub);
+        hasher.update(message);
+        let hash: [u8; 64] = hasher.finalize().into();
+        let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+        if k == Scalar::zero() {
+            k = Scalar::one();
+        }
+        let r = k * self.h;
+
+        let mut hasher = Sha3_512::new();
+        hasher.update(self.compressed_pub);
+        hasher.update(message);
+        hasher.update(r.compress().as_bytes());
+        let hash: [u8; 64] = hasher.finalize().into();
+        let e = Scalar::from_bytes_mod_order_wide(&hash);
+
+        let s = self.private_key.invert() * e + k;
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(s.as_bytes());
+        sig[32..].copy_from_slice(e.as_bytes());
+        sig
+    }
+}
+
+fn signed_wire(signer: &Signer, tx_type_id: u8, payload: &[u8], nonce: u64) -> Vec<u8> {
+    let mut wire = Vec::with_capacity(92 + payload.len() + 1 + 64);
+    wire.push(TX_VERSION_T1);
+    wire.push(CHAIN_ID);
+    wire.extend_from_slice(&signer.compressed_pub);
+    wire.push(tx_type_id);
+    wire.extend_from_slice(payload);
+    wire.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+    wire.push(0x00); // fee_type: TOS
+    wire.extend_from_slice(&nonce.to_be_bytes());
+    wire.extend_from_slice(&[0x33; 32]); // ref_hash
+    wire.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+
+    let signature = signer.sign(&wire);
+    wire.push(0x00); // multisig: None
+    wire.extend_from_slice(&signature);
+    wire
+}
+
+/// Decode with TOS Rust and take `Transaction::hash()`; must equal BLAKE3(wire).
+fn txid(name: &str, wire: &[u8]) -> Hash {
+    let tx = Transaction::from_bytes(wire)
+        .unwrap_or_else(|e| panic!("{}: wire does not decode: {:?}", name, e));
+    assert_eq!(tx.to_bytes(), wire, "{}: re-encoding mismatch", name);
+    let txid = tx.hash();
+    assert_eq!(
+        txid.as_bytes(),
+        blake3::hash(wire).as_bytes(),
+        "{}: txid is not BLAKE3 of the wire bytes",
+        name
+    );
+    txid
+}
+
+fn make_vector(name: &str, description: &str, tx_type_id: u8, wire: &[u8]) -> TxidVector {
+    TxidVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        tx_type_id,
+        mutation_of: None,
+        mutated_offset: None,
+        tx_hex: hex::encode(wire),
+        txid_hex: hex::encode(txid(name, wire).as_bytes()),
+    }
+}
+
+/// Flip the low bit of one payload byte, keeping the original signature.
+fn make_mutation(name: &str, description: &str, base: &TxidVector, offset: usize) -> TxidVector {
+    let mut wire = hex::decode(&base.tx_hex).unwrap();
+    wire[offset] ^= 0x01;
+    let txid_hex = hex::encode(txid(name, &wire).as_bytes());
+    assert_ne!(
+        txid_hex, base.txid_hex,
+        "{}: txid must change with the payload",
+        name
+    );
+
+    TxidVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        tx_type_id: base.tx_type_id,
+        mutation_of: Some(base.name.clone()),
+        mutated_offset: Some(offset),
+        tx_hex: hex::encode(&wire),
+        txid_hex,
+    }
+}
+
+fn main() {
+    let signer = Signer::from_seed_byte(SEED_BYTE);
+    let mut vectors = Vec::new();
+
+    let burn = BurnPayload {
+        asset: Hash::zero(),
+        amount: 1_000_000_000,
+    }
+    .to_bytes();
+    vectors.push(make_vector(
+        "burn",
+        "Burn 10 TOS",
+        TX_TYPE_BURN,
+        &signed_wire(&signer, TX_TYPE_BURN, &burn, 0),
+    ));
+
+    let mut transfers = 1u16.to_be_bytes().to_vec();
+    transfers.extend_from_slice(
+        &TransferPayload::new(
+            Hash::zero(),
+            CompressedPublicKey::from_bytes(&[0x22; 32]).unwrap(),
+            100_000_000,
+            None,
+        )
+        .to_bytes(),
+    );
+    vectors.push(make_vector(
+        "transfer_single",
+        "Single 1 TOS transfer",
+        TX_TYPE_TRANSFERS,
+        &signed_wire(&signer, TX_TYPE_TRANSFERS, &transfers, 1),
+    ));
+    vectors.push(make_vector(
+        "transfer_single_next_nonce",
+        "Same transfer with the next nonce; different txid",
+        TX_TYPE_TRANSFERS,
+        &signed_wire(&signer, TX_TYPE_TRANSFERS, &transfers, 2),
+    ));
+    assert_ne!(vectors[1].txid_hex, vectors[2].txid_hex);
+
+    let mut deposit = vec![0xE5u8; 32];
+    deposit.extend_from_slice(&500_000_000u64.to_be_bytes());
+    vectors.push(make_vector(
+        "deposit_escrow",
+        "DepositEscrow of 5 TOS",
+        TX_TYPE_DEPOSIT_ESCROW,
+        &signed_wire(&signer, TX_TYPE_DEPOSIT_ESCROW, &deposit, 3),
+    ));
+
+    // Last byte of the transfer amount.
+    let amount_offset = PAYLOAD_OFFSET + 2 + 32 + 32 + 7;
+    let tampered = make_mutation(
+        "transfer_single_amount_flipped",
+        "transfer_single with one payload bit flipped in the amount",
+        &vectors[1],
+        amount_offset,
+    );
+    vectors.push(tampered);
+
+    let test_file = TxidTestFile {
+        algorithm: "TOS-Txid".to_string(),
+        hash_function: "BLAKE3".to_string(),
+        txid_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("txid.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to txid.yaml");
+}
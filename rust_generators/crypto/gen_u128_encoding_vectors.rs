@@ -0,0 +1,70 @@
+// Generate u128 big-endian encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_u128_encoding_vectors
+//
+// Matches tos_signer Writer::write_u128: 16 bytes, big-endian (u128::to_be_bytes).
+// Each vector is checked against a hand-assembled expected byte sequence.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+#[derive(Serialize)]
+struct U128Vector {
+    name: String,
+    value_decimal: String,
+    wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct U128TestFile {
+    algorithm: String,
+    width_bytes: usize,
+    test_vectors: Vec<U128Vector>,
+}
+
+fn write_u128(buf: &mut Vec<u8>, v: u128) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn read_u128(bytes: &[u8]) -> u128 {
+    let mut raw = [0u8; 16];
+    raw.copy_from_slice(&bytes[..16]);
+    u128::from_be_bytes(raw)
+}
+
+fn make_vector(name: &str, value: u128, expected: [u8; 16]) -> U128Vector {
+    let mut wire = Vec::new();
+    write_u128(&mut wire, value);
+    assert_eq!(wire, expected, "{}: encoding mismatch", name);
+    assert_eq!(read_u128(&wire), value, "{}: round-trip mismatch", name);
+
+    U128Vector {
+        name: name.to_string(),
+        value_decimal: value.to_string(),
+        wire_hex: hex::encode(&wire),
+    }
+}
+
+fn main() {
+    let mut two_pow_64 = [0u8; 16];
+    two_pow_64[7] = 0x01;
+
+    let test_vectors = vec![
+        make_vector("zero", 0, [0x00; 16]),
+        make_vector("two_pow_64", 1u128 << 64, two_pow_64),
+        make_vector("u128_max", u128::MAX, [0xFF; 16]),
+    ];
+
+    let test_file = U128TestFile {
+        algorithm: "u128-BE".to_string(),
+        width_bytes: 16,
+        test_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("u128_encoding.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to u128_encoding.yaml");
+}
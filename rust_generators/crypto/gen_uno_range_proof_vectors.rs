@@ -0,0 +1,169 @@
+// Generate UNO balance range proof test vectors (Bulletproofs)
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_uno_range_proof_vectors
+//
+// Each UNO commitment V = amount*G + blinding*H (PedersenGens::default) is
+// paired with a 64-bit range proof showing 0 <= amount < 2^64. Both the
+// proof and the commitment come from the same prove_single_with_rng call, so
+// they always share generators.
+//
+// Transcript label: "UnoRangeProof". Each blinding and the prover's own
+// randomness are drawn from ChaCha20Rng seeded with a per-vector [seed; 32]
+// so the output is reproducible.
+//
+// Every proof is serialized, parsed back and verified before it is written.
+// The invalid vector pairs a valid proof with its commitment rotated by one
+// byte; it must fail verification.
+
+use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+use curve25519_dalek_ng::ristretto::CompressedRistretto;
+use curve25519_dalek_ng::scalar::Scalar;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const TRANSCRIPT_LABEL: &[u8] = b"UnoRangeProof";
+const BIT_LENGTH: usize = 64;
+
+#[derive(Serialize)]
+struct RangeProofVector {
+    name: String,
+    description: String,
+    amount: u64,
+    blinding_hex: String,
+    commitment_hex: String,
+    range_proof_hex: String,
+    proof_size: usize,
+    should_verify: bool,
+}
+
+#[derive(Serialize)]
+struct UnoRangeProofTestFile {
+    algorithm: String,
+    transcript_label: String,
+    bit_length: usize,
+    test_vectors: Vec<RangeProofVector>,
+}
+
+fn verify(
+    bp_gens: &BulletproofGens,
+    pc_gens: &PedersenGens,
+    proof_bytes: &[u8],
+    commitment: &CompressedRistretto,
+) -> bool {
+    let proof = RangeProof::from_bytes(proof_bytes).expect("proof round-trip failed");
+    let mut transcript = merlin::Transcript::new(TRANSCRIPT_LABEL);
+    proof
+        .verify_single(bp_gens, pc_gens, &mut transcript, commitment, BIT_LENGTH)
+        .is_ok()
+}
+
+fn make_vector(
+    name: &str,
+    description: &str,
+    amount: u64,
+    seed: u8,
+    bp_gens: &BulletproofGens,
+    pc_gens: &PedersenGens,
+) -> RangeProofVector {
+    let mut rng = ChaCha20Rng::from_seed([seed; 32]);
+    let blinding = Scalar::random(&mut rng);
+
+    let mut transcript = merlin::Transcript::new(TRANSCRIPT_LABEL);
+    let (proof, commitment) = RangeProof::prove_single_with_rng(
+        bp_gens,
+        pc_gens,
+        &mut transcript,
+        amount,
+        &blinding,
+        BIT_LENGTH,
+        &mut rng,
+    )
+    .expect("proof creation failed");
+    assert_eq!(
+        commitment,
+        pc_gens.commit(Scalar::from(amount), blinding).compress(),
+        "{}: commitment mismatch",
+        name
+    );
+
+    let proof_bytes = proof.to_bytes();
+    assert!(
+        verify(bp_gens, pc_gens, &proof_bytes, &commitment),
+        "{}: valid proof rejected",
+        name
+    );
+
+    RangeProofVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        amount,
+        blinding_hex: hex::encode(blinding.as_bytes()),
+        commitment_hex: hex::encode(commitment.as_bytes()),
+        range_proof_hex: hex::encode(&proof_bytes),
+        proof_size: proof_bytes.len(),
+        should_verify: true,
+    }
+}
+
+fn main() {
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(BIT_LENGTH, 1);
+
+    let mut test_vectors = vec![
+        make_vector("amount_zero", "Zero balance", 0, 1, &bp_gens, &pc_gens),
+        make_vector(
+            "amount_one",
+            "Smallest non-zero balance",
+            1,
+            2,
+            &bp_gens,
+            &pc_gens,
+        ),
+        make_vector("amount_2_pow_31", "2^31", 1 << 31, 3, &bp_gens, &pc_gens),
+        make_vector(
+            "amount_2_pow_63_minus_1",
+            "2^63 - 1 (i64::MAX)",
+            (1 << 63) - 1,
+            4,
+            &bp_gens,
+            &pc_gens,
+        ),
+    ];
+
+    // Invalid: the proof for amount 1 against its commitment rotated by one byte
+    let mut invalid = make_vector(
+        "invalid_shifted_commitment",
+        "Valid proof for amount 1 with the commitment bytes rotated left by one",
+        1,
+        2,
+        &bp_gens,
+        &pc_gens,
+    );
+    let mut shifted = hex::decode(&invalid.commitment_hex).unwrap();
+    shifted.rotate_left(1);
+    let proof_bytes = hex::decode(&invalid.range_proof_hex).unwrap();
+    let shifted_commitment = CompressedRistretto::from_slice(&shifted);
+    assert!(
+        !verify(&bp_gens, &pc_gens, &proof_bytes, &shifted_commitment),
+        "shifted commitment verified"
+    );
+    invalid.commitment_hex = hex::encode(&shifted);
+    invalid.should_verify = false;
+    test_vectors.push(invalid);
+
+    let test_file = UnoRangeProofTestFile {
+        algorithm: "Bulletproofs-UNO-Range".to_string(),
+        transcript_label: String::from_utf8(TRANSCRIPT_LABEL.to_vec()).unwrap(),
+        bit_length: BIT_LENGTH,
+        test_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("uno_range_proof.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to uno_range_proof.yaml");
+}
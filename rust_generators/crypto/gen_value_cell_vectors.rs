@@ -0,0 +1,319 @@
+// gen_value_cell_vectors.rs - ValueCell (contract parameter) wire-format vectors
+//
+// ValueCell is the parameter type carried by InvokeContract (Type 3). Every
+// vector here is a single standalone ValueCell.
+//
+// Primitive types (tag + value):
+//   0: Null
+//   1: U8(u8)
+//   2: U16(u16 BE)
+//   3: U32(u32 BE)
+//   4: U64(u64 BE)
+//   5: U128(u128 BE)
+//   6: U256(32 bytes BE)
+//   7: Boolean(u8 0/1)
+//   8: String(u16 len BE + bytes)
+//
+// ValueCell types (tag + content):
+//   0: Default(Primitive)
+//   1: Bytes(u32 len BE + bytes)
+//   2: Object(u32 len BE + ValueCell[])
+//   3: Map(u32 len BE + [key_ValueCell + value_ValueCell]...)
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::serializer::Serializer;
+use tos_kernel::{Primitive, ValueCell, U256};
+
+#[derive(Serialize)]
+struct ValueCellVector {
+    name: String,
+    description: String,
+    cell_type: String,
+    inner_type: Option<String>,
+    value_hex: String,
+    wire_hex: String,
+    wire_size: usize,
+}
+
+#[derive(Serialize)]
+struct ValueCellTestVectors {
+    algorithm: String,
+    version: u32,
+    value_cell_vectors: Vec<ValueCellVector>,
+}
+
+/// Primitive type name and tag-less value encoding.
+fn primitive_value(primitive: &Primitive) -> (&'static str, Vec<u8>) {
+    match primitive {
+        Primitive::Null => ("Null", Vec::new()),
+        Primitive::U8(v) => ("U8", vec![*v]),
+        Primitive::U16(v) => ("U16", v.to_be_bytes().to_vec()),
+        Primitive::U32(v) => ("U32", v.to_be_bytes().to_vec()),
+        Primitive::U64(v) => ("U64", v.to_be_bytes().to_vec()),
+        Primitive::U128(v) => ("U128", v.to_be_bytes().to_vec()),
+        Primitive::U256(v) => ("U256", v.to_be_bytes().to_vec()),
+        Primitive::Boolean(v) => ("Boolean", vec![u8::from(*v)]),
+        Primitive::String(s) => {
+            let mut out = (s.len() as u16).to_be_bytes().to_vec();
+            out.extend_from_slice(s.as_bytes());
+            ("String", out)
+        }
+        other => panic!("unsupported primitive in vectors: {:?}", other),
+    }
+}
+
+/// Cell type name, inner primitive type (Default only) and tag-less content encoding.
+fn cell_value(cell: &ValueCell) -> (&'static str, Option<String>, Vec<u8>) {
+    match cell {
+        ValueCell::Default(primitive) => {
+            let (inner, value) = primitive_value(primitive);
+            ("Default", Some(inner.to_string()), value)
+        }
+        ValueCell::Bytes(bytes) => {
+            let mut out = (bytes.len() as u32).to_be_bytes().to_vec();
+            out.extend_from_slice(bytes);
+            ("Bytes", None, out)
+        }
+        ValueCell::Object(items) => {
+            let mut out = (items.len() as u32).to_be_bytes().to_vec();
+            for item in items {
+                out.extend_from_slice(&item.to_bytes());
+            }
+            ("Object", None, out)
+        }
+        ValueCell::Map(map) => {
+            let mut out = (map.len() as u32).to_be_bytes().to_vec();
+            for (key, value) in map.iter() {
+                out.extend_from_slice(&key.to_bytes());
+                out.extend_from_slice(&value.to_bytes());
+            }
+            ("Map", None, out)
+        }
+    }
+}
+
+fn cell_tag(cell: &ValueCell) -> u8 {
+    match cell {
+        ValueCell::Default(_) => 0,
+        ValueCell::Bytes(_) => 1,
+        ValueCell::Object(_) => 2,
+        ValueCell::Map(_) => 3,
+    }
+}
+
+fn primitive_tag(primitive: &Primitive) -> u8 {
+    match primitive {
+        Primitive::Null => 0,
+        Primitive::U8(_) => 1,
+        Primitive::U16(_) => 2,
+        Primitive::U32(_) => 3,
+        Primitive::U64(_) => 4,
+        Primitive::U128(_) => 5,
+        Primitive::U256(_) => 6,
+        Primitive::Boolean(_) => 7,
+        Primitive::String(_) => 8,
+        other => panic!("unsupported primitive in vectors: {:?}", other),
+    }
+}
+
+fn generate_vector(name: &str, description: &str, cell: ValueCell) -> ValueCellVector {
+    let (cell_type, inner_type, value) = cell_value(&cell);
+    let wire = cell.to_bytes();
+
+    // Rebuild the wire bytes from the documented layout to pin the format
+    let mut expected = vec![cell_tag(&cell)];
+    if let ValueCell::Default(primitive) = &cell {
+        expected.push(primitive_tag(primitive));
+    }
+    expected.extend_from_slice(&value);
+    assert_eq!(wire, expected, "{}: wire format mismatch", name);
+    assert_eq!(wire.len(), cell.size(), "{}: size mismatch", name);
+
+    ValueCellVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        cell_type: cell_type.to_string(),
+        inner_type,
+        value_hex: hex::encode(&value),
+        wire_hex: hex::encode(&wire),
+        wire_size: wire.len(),
+    }
+}
+
+fn main() {
+    let mut vectors = Vec::new();
+
+    // ========================================================================
+    // Primitive sub-types (ValueCell::Default)
+    // ========================================================================
+
+    let primitives = vec![
+        ("primitive_null", "Null primitive", Primitive::Null),
+        ("primitive_u8", "U8 = 0xAB", Primitive::U8(0xAB)),
+        ("primitive_u16", "U16 = 0x1234", Primitive::U16(0x1234)),
+        (
+            "primitive_u32",
+            "U32 = 0xDEADBEEF",
+            Primitive::U32(0xDEADBEEF),
+        ),
+        (
+            "primitive_u64",
+            "U64 = 0x0102030405060708",
+            Primitive::U64(0x0102030405060708),
+        ),
+        (
+            "primitive_u64_max",
+            "U64 = u64::MAX",
+            Primitive::U64(u64::MAX),
+        ),
+        (
+            "primitive_u128",
+            "U128 = 0x0102...10",
+            Primitive::U128(0x0102030405060708090A0B0C0D0E0F10),
+        ),
+        (
+            "primitive_u128_max",
+            "U128 = u128::MAX",
+            Primitive::U128(u128::MAX),
+        ),
+        (
+            "primitive_u256_one",
+            "U256 = 1",
+            Primitive::U256(U256::from(1u64)),
+        ),
+        (
+            "primitive_u256_u64_max",
+            "U256 = u64::MAX (high limbs zero)",
+            Primitive::U256(U256::from(u64::MAX)),
+        ),
+        (
+            "primitive_bool_true",
+            "Boolean = true",
+            Primitive::Boolean(true),
+        ),
+        (
+            "primitive_bool_false",
+            "Boolean = false",
+            Primitive::Boolean(false),
+        ),
+        (
+            "primitive_string_empty",
+            "Empty string",
+            Primitive::String(String::new()),
+        ),
+        (
+            "primitive_string",
+            "ASCII string \"hello\"",
+            Primitive::String("hello".to_string()),
+        ),
+        (
+            "primitive_string_utf8",
+            "Multi-byte UTF-8 string (length counts bytes, not chars)",
+            Primitive::String("héllo, 世界".to_string()),
+        ),
+    ];
+    for (name, description, primitive) in primitives {
+        vectors.push(generate_vector(
+            name,
+            description,
+            ValueCell::Default(primitive),
+        ));
+    }
+
+    // ========================================================================
+    // Bytes
+    // ========================================================================
+
+    vectors.push(generate_vector(
+        "bytes_empty",
+        "Empty byte array",
+        ValueCell::Bytes(Vec::new()),
+    ));
+    vectors.push(generate_vector(
+        "bytes_deadbeef",
+        "4-byte array DEADBEEF",
+        ValueCell::Bytes(vec![0xDE, 0xAD, 0xBE, 0xEF]),
+    ));
+
+    // ========================================================================
+    // Object (nested)
+    // ========================================================================
+
+    vectors.push(generate_vector(
+        "object_flat",
+        "Object of three U32 values",
+        ValueCell::Object(vec![
+            ValueCell::Default(Primitive::U32(100)),
+            ValueCell::Default(Primitive::U32(200)),
+            ValueCell::Default(Primitive::U32(300)),
+        ]),
+    ));
+    vectors.push(generate_vector(
+        "object_nested",
+        "Object containing a string, bytes and an inner Object",
+        ValueCell::Object(vec![
+            ValueCell::Default(Primitive::String("outer".to_string())),
+            ValueCell::Bytes(vec![0x01, 0x02]),
+            ValueCell::Object(vec![
+                ValueCell::Default(Primitive::Boolean(true)),
+                ValueCell::Default(Primitive::U64(42)),
+            ]),
+        ]),
+    ));
+
+    // ========================================================================
+    // Map (insertion order is preserved on the wire)
+    // ========================================================================
+
+    let mut map = IndexMap::new();
+    map.insert(
+        ValueCell::Default(Primitive::String("alice".to_string())),
+        ValueCell::Default(Primitive::U64(1000)),
+    );
+    map.insert(
+        ValueCell::Default(Primitive::String("bob".to_string())),
+        ValueCell::Default(Primitive::U64(2000)),
+    );
+    vectors.push(generate_vector(
+        "map_two_entries",
+        "Map with two String -> U64 entries",
+        ValueCell::Map(Box::new(map)),
+    ));
+
+    // ========================================================================
+    // Write Output
+    // ========================================================================
+
+    let test_file = ValueCellTestVectors {
+        algorithm: "ValueCell-Wire-Format".to_string(),
+        version: 1,
+        value_cell_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# ValueCell Wire Format Test Vectors (contract parameters)
+# Generated by TOS Rust - gen_value_cell_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# ValueCell: [cell_tag:u8] then
+#   0 Default: [primitive_tag:u8][value]
+#   1 Bytes:   [len:u32][bytes]
+#   2 Object:  [len:u32][ValueCell...]
+#   3 Map:     [len:u32][key ValueCell][value ValueCell]...
+#
+# value_hex is the content after the tag byte(s).
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("value_cell.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to value_cell.yaml");
+}
@@ -0,0 +1,102 @@
+// Generate unsigned LEB128 varint test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_varint_vectors
+//
+// Encoding (matches tos_signer Writer::write_varint):
+//   emit the low 7 bits of the value per byte, least significant group first;
+//   set bit 7 on every byte except the last.
+//
+// A u64 needs at most 10 bytes. Decoders must reject encodings longer than
+// 10 bytes and a 10th byte carrying more than the top bit of the value.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+const MAX_VARINT_LEN: usize = 10;
+
+#[derive(Serialize)]
+struct VarintVector {
+    name: String,
+    value: u64,
+    encoded_hex: String,
+    encoded_len: usize,
+}
+
+#[derive(Serialize)]
+struct VarintTestFile {
+    algorithm: String,
+    max_encoded_len: usize,
+    test_vectors: Vec<VarintVector>,
+}
+
+fn encode_varint(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(MAX_VARINT_LEN);
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+    out
+}
+
+/// Returns (value, bytes consumed).
+fn decode_varint(data: &[u8]) -> Result<(u64, usize), String> {
+    let mut value: u64 = 0;
+    for (i, byte) in data.iter().enumerate() {
+        if i == MAX_VARINT_LEN {
+            return Err("varint too long".to_string());
+        }
+        let group = (byte & 0x7f) as u64;
+        if i == MAX_VARINT_LEN - 1 && group > 1 {
+            return Err("varint overflows u64".to_string());
+        }
+        value |= group << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err("unterminated varint".to_string())
+}
+
+fn make_vector(name: &str, value: u64) -> VarintVector {
+    let encoded = encode_varint(value);
+    let (decoded, consumed) = decode_varint(&encoded).unwrap();
+    assert_eq!(decoded, value, "{}: round-trip mismatch", name);
+    assert_eq!(consumed, encoded.len(), "{}: trailing bytes", name);
+
+    VarintVector {
+        name: name.to_string(),
+        value,
+        encoded_hex: hex::encode(&encoded),
+        encoded_len: encoded.len(),
+    }
+}
+
+fn main() {
+    let vectors = vec![
+        make_vector("zero", 0),
+        make_vector("max_1_byte", 127),
+        make_vector("min_2_bytes", 128),
+        make_vector("u8_max", 255),
+        make_vector("u8_max_plus_1", 256),
+        make_vector("u32_max", u32::MAX as u64),
+        make_vector("u64_max", u64::MAX),
+    ];
+
+    assert_eq!(vectors[0].encoded_hex, "00");
+    assert_eq!(vectors[2].encoded_hex, "8001");
+    assert_eq!(vectors[6].encoded_hex, "ffffffffffffffffff01");
+
+    let test_file = VarintTestFile {
+        algorithm: "LEB128-unsigned".to_string(),
+        max_encoded_len: MAX_VARINT_LEN,
+        test_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("varint.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to varint.yaml");
+}
@@ -0,0 +1,141 @@
+// Generate transaction version byte test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_version_negotiation_vectors
+//
+// The first byte of every wire transaction is the version (TxVersion). Only
+// T1 (0x01) is defined; any other value must be rejected while decoding,
+// before the rest of the frame is interpreted.
+//
+// Each vector wraps the same Transfer payload in a complete wire frame:
+//
+//   [version:1][chain_id:1][source:32][tx_type_id:1][payload:var]
+//   [fee:8][fee_type:1][nonce:8][ref_hash:32][ref_topo:8]
+//   [multisig:Option=0x00][signature:64]
+//
+// `expected_result` is `accepted` or `UnknownVersion`. Decoders must return
+// the version error for unknown bytes without panicking and without
+// falling back to T1 parsing.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{TransferPayload, TxVersion};
+
+const TX_TYPE_TRANSFERS: u8 = 1;
+const CHAIN_ID: u8 = 3;
+
+#[derive(Serialize)]
+struct VersionVector {
+    name: String,
+    description: String,
+    version_byte: u8,
+    wire_hex: String,
+    expected_result: String,
+}
+
+#[derive(Serialize)]
+struct VersionTestFile {
+    algorithm: String,
+    supported_versions: Vec<u8>,
+    version_vectors: Vec<VersionVector>,
+}
+
+/// Transfers payload with a single transfer: [count:u16][transfer].
+fn transfer_payload() -> Vec<u8> {
+    let transfer = TransferPayload::new(
+        Hash::zero(),
+        CompressedPublicKey::from_bytes(&[0x22; 32]).unwrap(),
+        100_000_000,
+        None,
+    );
+    let mut payload = 1u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(&transfer.to_bytes());
+    payload
+}
+
+fn wire_frame(version: u8, payload: &[u8]) -> Vec<u8> {
+    let mut wire = Vec::with_capacity(92 + payload.len() + 1 + 64);
+    wire.push(version);
+    wire.push(CHAIN_ID);
+    wire.extend_from_slice(&[0x11; 32]); // source
+    wire.push(TX_TYPE_TRANSFERS);
+    wire.extend_from_slice(payload);
+    wire.extend_from_slice(&10_000u64.to_be_bytes()); // fee
+    wire.push(0x00); // fee_type: TOS
+    wire.extend_from_slice(&7u64.to_be_bytes()); // nonce
+    wire.extend_from_slice(&[0x33; 32]); // ref_hash
+    wire.extend_from_slice(&42u64.to_be_bytes()); // ref_topo
+    wire.push(0x00); // multisig: None
+    wire.extend_from_slice(&[0u8; 64]); // signature (not checked at decode)
+    wire
+}
+
+fn make_vector(name: &str, description: &str, version: u8, payload: &[u8]) -> VersionVector {
+    let accepted = TxVersion::try_from(version).is_ok();
+    assert_eq!(
+        accepted,
+        version == 1,
+        "{}: TxVersion acceptance disagrees with the spec (only T1 is defined)",
+        name
+    );
+
+    let wire = wire_frame(version, payload);
+
+    VersionVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        version_byte: version,
+        wire_hex: hex::encode(&wire),
+        expected_result: if accepted {
+            "accepted".to_string()
+        } else {
+            "UnknownVersion".to_string()
+        },
+    }
+}
+
+fn main() {
+    let payload = transfer_payload();
+
+    let version_vectors = vec![
+        make_vector(
+            "version_0",
+            "Version 0 is not defined; must not be treated as a legacy T1",
+            0,
+            &payload,
+        ),
+        make_vector(
+            "version_t1",
+            "TxVersion::T1, the only supported version",
+            1,
+            &payload,
+        ),
+        make_vector(
+            "version_2",
+            "Next unassigned version; rejected until a T2 format exists",
+            2,
+            &payload,
+        ),
+        make_vector(
+            "version_255",
+            "Largest version byte; unknown",
+            255,
+            &payload,
+        ),
+    ];
+
+    let test_file = VersionTestFile {
+        algorithm: "TxVersion-Negotiation".to_string(),
+        supported_versions: vec![1],
+        version_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("version_negotiation.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to version_negotiation.yaml");
+}
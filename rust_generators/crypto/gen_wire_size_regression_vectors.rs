@@ -0,0 +1,178 @@
+// gen_wire_size_regression_vectors.rs - Payload wire size regression vectors
+//
+// For each payload type, a canonical minimal and maximal example is encoded
+// and its exact size recorded. The generator asserts
+//
+//   payload.size() == payload.to_bytes().len()
+//
+// so a size() that drifts from the real encoding (off-by-one length prefix,
+// forgotten field) fails here. Any protocol change that alters a wire format
+// changes these vectors, which makes them a change-detection signal.
+//
+// "Maximal" means the largest payload the spec accepts (config.py limits),
+// not merely a large one:
+//   MultiSig:     MAX_MULTISIG_PARTICIPANTS = 255
+//   Energy:       MAX_DELEGATEES = 500, MAX_FREEZE_DURATION_DAYS = 365
+//   RegisterName: MIN_NAME_LENGTH = 3, MAX_NAME_LENGTH = 64
+// Fixed-size payloads (Burn, Transfer without extra_data) record both ends of
+// the amount range; their sizes must be equal.
+
+use indexmap::IndexSet;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tos_common::account::FreezeDuration;
+use tos_common::crypto::{elgamal::CompressedPublicKey, Hash, PublicKey};
+use tos_common::serializer::Serializer;
+use tos_common::transaction::{
+    BurnPayload, DelegationEntry, EnergyPayload, MultiSigPayload, RegisterNamePayload,
+    TransferPayload,
+};
+
+const MAX_MULTISIG_PARTICIPANTS: usize = 255;
+const MAX_DELEGATEES: usize = 500;
+const MIN_NAME_LENGTH: usize = 3;
+const MAX_NAME_LENGTH: usize = 64;
+
+#[derive(Serialize)]
+struct WireSizeVector {
+    payload_type: String,
+    variant_name: String,
+    expected_size: usize,
+    wire_hex: String,
+}
+
+#[derive(Serialize)]
+struct WireSizeRegressionTestVectors {
+    algorithm: String,
+    version: u32,
+    size_vectors: Vec<WireSizeVector>,
+}
+
+fn size_vector<T: Serializer>(
+    payload_type: &str,
+    variant_name: &str,
+    payload: &T,
+) -> WireSizeVector {
+    let wire = payload.to_bytes();
+    assert_eq!(
+        payload.size(),
+        wire.len(),
+        "{}/{}: size() disagrees with to_bytes()",
+        payload_type,
+        variant_name
+    );
+
+    WireSizeVector {
+        payload_type: payload_type.to_string(),
+        variant_name: variant_name.to_string(),
+        expected_size: wire.len(),
+        wire_hex: hex::encode(&wire),
+    }
+}
+
+/// Distinct 32-byte key for index i: [i_lo, i_hi, 0x5A, 0x5A, ...].
+fn indexed_key_bytes(index: usize) -> [u8; 32] {
+    let mut bytes = [0x5Au8; 32];
+    bytes[0] = index as u8;
+    bytes[1] = (index >> 8) as u8;
+    bytes
+}
+
+fn main() {
+    let asset = Hash::new([0xAAu8; 32]);
+    let destination = CompressedPublicKey::from_bytes(&[0x01u8; 32]).expect("Valid pubkey bytes");
+
+    let mut size_vectors = Vec::new();
+
+    // Burn (fixed size)
+    let burn_min = BurnPayload {
+        asset: asset.clone(),
+        amount: 1,
+    };
+    let burn_max = BurnPayload {
+        asset: asset.clone(),
+        amount: u64::MAX,
+    };
+    size_vectors.push(size_vector("Burn", "min", &burn_min));
+    size_vectors.push(size_vector("Burn", "max", &burn_max));
+
+    // Transfer (fixed size without extra_data)
+    let transfer_min = TransferPayload::new(asset.clone(), destination.clone(), 1, None);
+    let transfer_max = TransferPayload::new(asset.clone(), destination.clone(), u64::MAX, None);
+    size_vectors.push(size_vector("Transfer", "min", &transfer_min));
+    size_vectors.push(size_vector("Transfer", "max", &transfer_max));
+
+    // MultiSig: delete (no participants) .. 255 participants
+    let multisig_min = MultiSigPayload {
+        threshold: 0,
+        participants: IndexSet::new(),
+    };
+    let participants: IndexSet<CompressedPublicKey> = (0..MAX_MULTISIG_PARTICIPANTS)
+        .map(|i| {
+            CompressedPublicKey::from_bytes(&indexed_key_bytes(i)).expect("Valid pubkey bytes")
+        })
+        .collect();
+    assert_eq!(participants.len(), MAX_MULTISIG_PARTICIPANTS);
+    let multisig_max = MultiSigPayload {
+        threshold: MAX_MULTISIG_PARTICIPANTS as u8,
+        participants,
+    };
+    size_vectors.push(size_vector("MultiSig", "min", &multisig_min));
+    size_vectors.push(size_vector("MultiSig", "max", &multisig_max));
+
+    // Energy: WithdrawUnfrozen (tag only) .. FreezeTosDelegate with 500 delegatees
+    let energy_min = EnergyPayload::WithdrawUnfrozen;
+    let delegatees = (0..MAX_DELEGATEES)
+        .map(|i| DelegationEntry {
+            delegatee: PublicKey::from_bytes(&indexed_key_bytes(i)).expect("Valid pubkey bytes"),
+            amount: u64::MAX,
+        })
+        .collect();
+    let energy_max = EnergyPayload::FreezeTosDelegate {
+        delegatees,
+        duration: FreezeDuration::new(365).expect("Valid duration"),
+    };
+    size_vectors.push(size_vector("Energy", "min", &energy_min));
+    size_vectors.push(size_vector("Energy", "max", &energy_max));
+
+    // RegisterName: 3 .. 64 characters
+    let name_min = RegisterNamePayload::new("a".repeat(MIN_NAME_LENGTH));
+    let name_max = RegisterNamePayload::new("a".repeat(MAX_NAME_LENGTH));
+    size_vectors.push(size_vector("RegisterName", "min", &name_min));
+    size_vectors.push(size_vector("RegisterName", "max", &name_max));
+
+    // Fixed-size payloads must not vary with the amount
+    for pair in size_vectors.chunks(2).take(2) {
+        assert_eq!(
+            pair[0].expected_size, pair[1].expected_size,
+            "{}: fixed-size payload changed size",
+            pair[0].payload_type
+        );
+    }
+
+    let test_file = WireSizeRegressionTestVectors {
+        algorithm: "Wire-Size-Regression".to_string(),
+        version: 1,
+        size_vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).expect("YAML serialization failed");
+
+    let header = r#"# Payload Wire Size Regression Test Vectors
+# Generated by TOS Rust - gen_wire_size_regression_vectors
+# Cross-language verification between TOS Rust and Avatar C
+#
+# Each payload type has a canonical minimal and maximal example.
+# expected_size == len(wire_hex) / 2 == payload.size()
+
+"#;
+
+    let full_yaml = format!("{}{}", header, yaml);
+    println!("{}", full_yaml);
+
+    let mut file = File::create("wire_size_regression.yaml").expect("Failed to create file");
+    file.write_all(full_yaml.as_bytes())
+        .expect("Failed to write file");
+    eprintln!("Written to wire_size_regression.yaml");
+}
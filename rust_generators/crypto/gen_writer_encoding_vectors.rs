@@ -0,0 +1,210 @@
+// Generate binary Writer primitive encoding test vectors
+// Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_writer_encoding_vectors
+//
+// Every payload encoder is built from the big-endian primitives of the
+// tos_signer Writer (and Python's encoding.Writer):
+//
+//   write_u8:              [v]
+//   write_u16:             [v:2 BE]
+//   write_u64:             [v:8 BE]
+//   write_bool:            [0x00] / [0x01]
+//   write_optional_vec_u8: [0x00] / [0x01][len:u16 BE][data]
+//
+// Each vector lists its `operations` in order with the resulting `wire_hex`.
+// Single-operation vectors pin each primitive; the combination vectors
+// interleave all of them so a writer that drops or reorders bytes between
+// calls is caught before any transaction format is tested.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+
+#[derive(Clone)]
+enum Op {
+    U8(u8),
+    U16(u16),
+    U64(u64),
+    Bool(bool),
+    OptionalVecU8(Option<Vec<u8>>),
+}
+
+#[derive(Serialize)]
+struct Operation {
+    method: String,
+    /// Decimal for integers, true/false for bools, hex data for a present
+    /// optional and null for an absent one.
+    value: Option<String>,
+}
+
+#[derive(Serialize)]
+struct WriterVector {
+    name: String,
+    description: String,
+    operations: Vec<Operation>,
+    wire_hex: String,
+    wire_size: usize,
+}
+
+#[derive(Serialize)]
+struct WriterTestFile {
+    algorithm: String,
+    byte_order: String,
+    writer_vectors: Vec<WriterVector>,
+}
+
+fn apply(buf: &mut Vec<u8>, op: &Op) {
+    match op {
+        Op::U8(v) => buf.push(*v),
+        Op::U16(v) => buf.extend_from_slice(&v.to_be_bytes()),
+        Op::U64(v) => buf.extend_from_slice(&v.to_be_bytes()),
+        Op::Bool(v) => buf.push(u8::from(*v)),
+        Op::OptionalVecU8(None) => buf.push(0x00),
+        Op::OptionalVecU8(Some(data)) => {
+            assert!(
+                data.len() <= u16::MAX as usize,
+                "data too long for u16 length"
+            );
+            buf.push(0x01);
+            buf.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            buf.extend_from_slice(data);
+        }
+    }
+}
+
+fn encoded_size(op: &Op) -> usize {
+    match op {
+        Op::U8(_) | Op::Bool(_) | Op::OptionalVecU8(None) => 1,
+        Op::U16(_) => 2,
+        Op::U64(_) => 8,
+        Op::OptionalVecU8(Some(data)) => 3 + data.len(),
+    }
+}
+
+fn describe(op: &Op) -> Operation {
+    let (method, value) = match op {
+        Op::U8(v) => ("write_u8", Some(v.to_string())),
+        Op::U16(v) => ("write_u16", Some(v.to_string())),
+        Op::U64(v) => ("write_u64", Some(v.to_string())),
+        Op::Bool(v) => ("write_bool", Some(v.to_string())),
+        Op::OptionalVecU8(data) => ("write_optional_vec_u8", data.as_ref().map(hex::encode)),
+    };
+    Operation {
+        method: method.to_string(),
+        value,
+    }
+}
+
+fn make_vector(name: &str, description: &str, ops: &[Op]) -> WriterVector {
+    let mut wire = Vec::new();
+    for op in ops {
+        apply(&mut wire, op);
+    }
+    let expected_size: usize = ops.iter().map(encoded_size).sum();
+    assert_eq!(wire.len(), expected_size, "{}: size mismatch", name);
+
+    WriterVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        operations: ops.iter().map(describe).collect(),
+        wire_hex: hex::encode(&wire),
+        wire_size: wire.len(),
+    }
+}
+
+fn main() {
+    let max_data: Vec<u8> = (0..u16::MAX as usize).map(|i| i as u8).collect();
+    let mut vectors = vec![
+        make_vector("u8_zero", "write_u8(0)", &[Op::U8(0)]),
+        make_vector(
+            "u8_127",
+            "write_u8(127): largest value with bit 7 clear",
+            &[Op::U8(127)],
+        ),
+        make_vector("u8_max", "write_u8(255)", &[Op::U8(u8::MAX)]),
+        make_vector("u16_zero", "write_u16(0)", &[Op::U16(0)]),
+        make_vector(
+            "u16_256",
+            "write_u16(256): 01 00, catches little-endian writers",
+            &[Op::U16(256)],
+        ),
+        make_vector("u16_max", "write_u16(65535)", &[Op::U16(u16::MAX)]),
+        make_vector("u64_zero", "write_u64(0)", &[Op::U64(0)]),
+        make_vector(
+            "u64_one",
+            "write_u64(1): only the last byte is set",
+            &[Op::U64(1)],
+        ),
+        make_vector("u64_max", "write_u64(u64::MAX)", &[Op::U64(u64::MAX)]),
+        make_vector("bool_false", "write_bool(false)", &[Op::Bool(false)]),
+        make_vector("bool_true", "write_bool(true)", &[Op::Bool(true)]),
+        make_vector(
+            "optional_none",
+            "write_optional_vec_u8(None)",
+            &[Op::OptionalVecU8(None)],
+        ),
+        make_vector(
+            "optional_empty",
+            "write_optional_vec_u8(Some(b\"\")): flag and zero length",
+            &[Op::OptionalVecU8(Some(Vec::new()))],
+        ),
+        make_vector(
+            "optional_max_len",
+            "write_optional_vec_u8 with 65535 bytes, the largest u16 length",
+            &[Op::OptionalVecU8(Some(max_data))],
+        ),
+    ];
+
+    let sequence = [
+        Op::U8(0x01),
+        Op::U16(0x0203),
+        Op::U64(0x0405_0607_0809_0a0b),
+        Op::Bool(true),
+        Op::OptionalVecU8(Some(vec![0xaa, 0xbb])),
+        Op::Bool(false),
+        Op::OptionalVecU8(None),
+        Op::U8(0xff),
+    ];
+    vectors.push(make_vector(
+        "combined_all_methods",
+        "Every method once with distinct bytes so misordering shows",
+        &sequence,
+    ));
+
+    let mut reversed = sequence.to_vec();
+    reversed.reverse();
+    vectors.push(make_vector(
+        "combined_reversed",
+        "The same operations in reverse order",
+        &reversed,
+    ));
+
+    vectors.push(make_vector(
+        "combined_boundaries",
+        "Boundary values of every method back to back",
+        &[
+            Op::U8(0),
+            Op::U8(u8::MAX),
+            Op::U16(0),
+            Op::U16(u16::MAX),
+            Op::U64(0),
+            Op::U64(u64::MAX),
+            Op::Bool(false),
+            Op::Bool(true),
+            Op::OptionalVecU8(None),
+            Op::OptionalVecU8(Some(Vec::new())),
+        ],
+    ));
+
+    let test_file = WriterTestFile {
+        algorithm: "Binary-Writer".to_string(),
+        byte_order: "big-endian".to_string(),
+        writer_vectors: vectors,
+    };
+
+    let yaml = serde_yaml::to_string(&test_file).unwrap();
+    println!("{}", yaml);
+
+    let mut file = File::create("writer_encoding.yaml").unwrap();
+    file.write_all(yaml.as_bytes()).unwrap();
+    eprintln!("Written to writer_encoding.yaml");
+}
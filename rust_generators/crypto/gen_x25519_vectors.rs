@@ -1,7 +1,19 @@
 // Generate X25519 key exchange test vectors
 // Run: cd ~/tos-spec/rust_generators/crypto && cargo run --release --bin gen_x25519_vectors
+//
+// TOS P2P currently agrees session keys over Ristretto255; these vectors pin
+// X25519 so a future protocol change cannot silently deviate.
+//
+// `exchange_vectors` is the flat form for implementations that only need to
+// check a full exchange: both private keys, both public keys and the shared
+// secret. The low-order case has no Bob private key; its public key is the
+// u = 0 point, so the shared secret is all zeros and `contributory` is false.
+// Implementations must reject it (or detect the all-zero secret) before
+// deriving a session key.
 
-use x25519_dalek::{StaticSecret, PublicKey};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use x25519_dalek::{EphemeralSecret, StaticSecret, PublicKey};
 use serde::Serialize;
 use std::fs::File;
 use std::io::Write;
@@ -27,12 +39,170 @@ struct SharedSecretVector {
     shared_secret_hex: String,
 }
 
+/// One side of a Diffie-Hellman exchange: private key + peer public key -> shared secret
+#[derive(Serialize)]
+struct DhVector {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    secret_type: String,
+    private_hex: String,
+    peer_public_hex: String,
+    shared_secret_hex: String,
+    /// True when the peer computing with the swapped keys derives the same secret
+    commutative: bool,
+}
+
+/// Full exchange between two parties, as consumed by the P2P handshake
+#[derive(Serialize)]
+struct ExchangeVector {
+    name: String,
+    description: String,
+    alice_private_hex: String,
+    alice_public_hex: String,
+    bob_private_hex: Option<String>,
+    bob_public_hex: String,
+    shared_secret_hex: String,
+    /// False when the shared secret does not depend on Alice's private key
+    contributory: bool,
+}
+
 #[derive(Serialize)]
 struct X25519TestFile {
     algorithm: String,
     key_size: usize,
     keypair_vectors: Vec<KeypairVector>,
     shared_secret_vectors: Vec<SharedSecretVector>,
+    dh_vectors: Vec<DhVector>,
+    exchange_vectors: Vec<ExchangeVector>,
+}
+
+/// Build a DH vector from a StaticSecret and check commutativity against the peer.
+fn static_dh_vector(name: &str, description: &str, private: [u8; 32], peer: [u8; 32]) -> DhVector {
+    let secret = StaticSecret::from(private);
+    let peer_secret = StaticSecret::from(peer);
+    let peer_public = PublicKey::from(&peer_secret);
+    let shared = secret.diffie_hellman(&peer_public);
+    let reverse = peer_secret.diffie_hellman(&PublicKey::from(&secret));
+    let commutative = shared.as_bytes() == reverse.as_bytes();
+    assert!(commutative, "{}: shared secret is not commutative", name);
+
+    DhVector {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        secret_type: "StaticSecret".to_string(),
+        private_hex: hex::encode(private),
+        peer_public_hex: hex::encode(peer_public.as_bytes()),
+        shared_secret_hex: hex::encode(shared.as_bytes()),
+        commutative,
+    }
+}
+
+/// Build a DH vector from an EphemeralSecret drawn from a seeded RNG.
+///
+/// EphemeralSecret hides its scalar, so the private bytes are recovered by
+/// replaying the same RNG seed; a StaticSecret built from them must agree.
+fn ephemeral_dh_vector(name: &str, description: &str, rng_seed: u64, peer: [u8; 32]) -> DhVector {
+    let mut private = [0u8; 32];
+    StdRng::seed_from_u64(rng_seed).fill_bytes(&mut private);
+
+    let ephemeral = EphemeralSecret::random_from_rng(StdRng::seed_from_u64(rng_seed));
+    let ephemeral_public = PublicKey::from(&ephemeral);
+    assert_eq!(
+        ephemeral_public.as_bytes(),
+        PublicKey::from(&StaticSecret::from(private)).as_bytes(),
+        "{}: replayed private key does not match the ephemeral secret",
+        name
+    );
+
+    let peer_secret = StaticSecret::from(peer);
+    let peer_public = PublicKey::from(&peer_secret);
+    let reverse = peer_secret.diffie_hellman(&ephemeral_public);
+    let shared = ephemeral.diffie_hellman(&peer_public);
+    let commutative = shared.as_bytes() == reverse.as_bytes();
+    assert!(commutative, "{}: shared secret is not commutative", name);
+
+    DhVector {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        secret_type: "EphemeralSecret".to_string(),
+        private_hex: hex::encode(private),
+        peer_public_hex: hex::encode(peer_public.as_bytes()),
+        shared_secret_hex: hex::encode(shared.as_bytes()),
+        commutative,
+    }
+}
+
+/// Build an exchange vector between two private keys.
+fn exchange_vector(
+    name: &str,
+    description: &str,
+    alice: [u8; 32],
+    bob: [u8; 32],
+) -> ExchangeVector {
+    let alice_secret = StaticSecret::from(alice);
+    let alice_public = PublicKey::from(&alice_secret);
+    let bob_secret = StaticSecret::from(bob);
+    let bob_public = PublicKey::from(&bob_secret);
+
+    let shared_ab = alice_secret.diffie_hellman(&bob_public);
+    let shared_ba = bob_secret.diffie_hellman(&alice_public);
+    assert_eq!(
+        shared_ab.as_bytes(),
+        shared_ba.as_bytes(),
+        "{}: shared secret mismatch",
+        name
+    );
+    assert!(
+        shared_ab.was_contributory(),
+        "{}: shared secret is not contributory",
+        name
+    );
+
+    ExchangeVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        alice_private_hex: hex::encode(alice),
+        alice_public_hex: hex::encode(alice_public.as_bytes()),
+        bob_private_hex: Some(hex::encode(bob)),
+        bob_public_hex: hex::encode(bob_public.as_bytes()),
+        shared_secret_hex: hex::encode(shared_ab.as_bytes()),
+        contributory: true,
+    }
+}
+
+/// Build an exchange vector where Bob presents a low-order public key.
+fn low_order_exchange_vector(
+    name: &str,
+    description: &str,
+    alice: [u8; 32],
+    bob_public: [u8; 32],
+) -> ExchangeVector {
+    let alice_secret = StaticSecret::from(alice);
+    let alice_public = PublicKey::from(&alice_secret);
+    let shared = alice_secret.diffie_hellman(&PublicKey::from(bob_public));
+    assert_eq!(
+        shared.as_bytes(),
+        &[0u8; 32],
+        "{}: low-order point must give a zero secret",
+        name
+    );
+    assert!(
+        !shared.was_contributory(),
+        "{}: low-order point must not be contributory",
+        name
+    );
+
+    ExchangeVector {
+        name: name.to_string(),
+        description: description.to_string(),
+        alice_private_hex: hex::encode(alice),
+        alice_public_hex: hex::encode(alice_public.as_bytes()),
+        bob_private_hex: None,
+        bob_public_hex: hex::encode(bob_public),
+        shared_secret_hex: hex::encode(shared.as_bytes()),
+        contributory: false,
+    }
 }
 
 fn main() {
@@ -164,11 +334,96 @@ fn main() {
         shared_secret_hex: hex::encode(shared_ab.as_bytes()),
     });
 
+    // DH vectors: one side of each exchange, with the commutativity check
+    let rfc_alice: [u8; 32] = hex::decode("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let rfc_bob: [u8; 32] = hex::decode("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let rfc_vector = static_dh_vector(
+        "static_rfc7748_alice",
+        "StaticSecret (RFC 7748 Alice) with Bob's public key",
+        rfc_alice,
+        rfc_bob,
+    );
+    assert_eq!(
+        rfc_vector.shared_secret_hex,
+        "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+    );
+    let dh_vectors = vec![
+        rfc_vector,
+        static_dh_vector(
+            "static_rfc7748_bob",
+            "StaticSecret (RFC 7748 Bob) with Alice's public key",
+            rfc_bob,
+            rfc_alice,
+        ),
+        ephemeral_dh_vector(
+            "ephemeral_seed_1",
+            "EphemeralSecret from StdRng seed 1 with RFC 7748 Bob's public key",
+            1,
+            rfc_bob,
+        ),
+        ephemeral_dh_vector(
+            "ephemeral_seed_2",
+            "EphemeralSecret from StdRng seed 2 with a 0x42 static peer",
+            2,
+            [0x42u8; 32],
+        ),
+    ];
+
+    // Exchange vectors: the five cases the P2P handshake is validated against
+    let mut deterministic_alice = [0u8; 32];
+    StdRng::seed_from_u64(7).fill_bytes(&mut deterministic_alice);
+    let mut deterministic_bob = [0u8; 32];
+    StdRng::seed_from_u64(8).fill_bytes(&mut deterministic_bob);
+    let exchange_vectors = vec![
+        exchange_vector(
+            "sequential",
+            "Alice bytes 0x00-0x1F, Bob bytes 0x20-0x3F",
+            core::array::from_fn(|i| i as u8),
+            core::array::from_fn(|i| (i + 32) as u8),
+        ),
+        exchange_vector(
+            "all_ones",
+            "Alice all 0x01, Bob all 0xFF",
+            [0x01u8; 32],
+            [0xffu8; 32],
+        ),
+        exchange_vector(
+            "rfc7748",
+            "RFC 7748 section 6.1 Alice and Bob",
+            rfc_alice,
+            rfc_bob,
+        ),
+        low_order_exchange_vector(
+            "low_order_public_key",
+            "Bob's public key is the u = 0 low-order point; must be rejected",
+            rfc_alice,
+            [0u8; 32],
+        ),
+        exchange_vector(
+            "deterministic_random",
+            "Private keys from StdRng seeds 7 (Alice) and 8 (Bob)",
+            deterministic_alice,
+            deterministic_bob,
+        ),
+    ];
+    assert_eq!(
+        exchange_vectors[2].shared_secret_hex,
+        "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+    );
+
     let test_file = X25519TestFile {
         algorithm: "X25519".to_string(),
         key_size: 32,
         keypair_vectors,
         shared_secret_vectors,
+        dh_vectors,
+        exchange_vectors,
     };
 
     let yaml = serde_yaml::to_string(&test_file).unwrap();
@@ -1,5 +1,10 @@
+use indexmap::IndexSet;
 use pyo3::prelude::*;
-use pyo3::types::PyModule;
+use pyo3::types::{PyBytes, PyModule};
+use serde_json::Value;
+use tos_common::block::{BlockHeader, BlockVersion, EXTRA_NONCE_SIZE};
+use tos_common::crypto::elgamal::CompressedPublicKey;
+use tos_common::crypto::{Hash, Hashable};
 use tos_common::serializer::Serializer;
 use tos_common::transaction::Transaction;
 
@@ -18,18 +23,286 @@ fn decode_tx(hex_str: &str) -> PyResult<String> {
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Serialize error: {e}")))
 }
 
+/// Collect the paths at which two JSON values differ (object key order is ignored).
+///
+/// A key that only the decoded side has is not a difference when its value is
+/// `null`: serde reads an omitted `Option` field as `None`, which serializes
+/// back as `null`, so both sides describe the same transaction.
+fn json_diff(expected: &Value, actual: &Value, path: &str, out: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, value) in a {
+                let child = format!("{path}.{key}");
+                match b.get(key) {
+                    Some(other) => json_diff(value, other, &child, out),
+                    None => out.push(format!("{child}: dropped by encoding")),
+                }
+            }
+            for (key, value) in b.iter().filter(|(key, _)| !a.contains_key(*key)) {
+                if !value.is_null() {
+                    out.push(format!("{path}.{key}: added by decoding"));
+                }
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                json_diff(x, y, &format!("{path}[{i}]"), out);
+            }
+        }
+        _ if expected != actual => out.push(format!("{path}: {expected} != {actual}")),
+        _ => {}
+    }
+}
+
+/// Like `encode_tx`, but decodes the result again and requires the JSON to
+/// round-trip unchanged (modulo key order). Unknown or ignored fields raise
+/// `ValueError` listing every differing path; omitted optional fields that
+/// decode as `null` are accepted.
+#[pyfunction]
+fn encode_tx_strict(json_str: &str) -> PyResult<String> {
+    let input: Value = serde_json::from_str(json_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("JSON parse error: {e}")))?;
+    let tx: Transaction = serde_json::from_value(input.clone())
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("JSON parse error: {e}")))?;
+    let hex = tx.to_hex();
+
+    let decoded = Transaction::from_hex(&hex)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))?;
+    let output = serde_json::to_value(&decoded)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Serialize error: {e}")))?;
+
+    let mut diff = Vec::new();
+    json_diff(&input, &output, "$", &mut diff);
+    if !diff.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "round-trip mismatch:\n{}",
+            diff.join("\n")
+        )));
+    }
+    Ok(hex)
+}
+
+/// Like `encode_tx`, but takes the JSON as `bytes` and skips the UTF-8 `str` round-trip.
+#[pyfunction]
+fn encode_tx_from_bytes(json_bytes: &[u8]) -> PyResult<String> {
+    let tx: Transaction = serde_json::from_slice(json_bytes)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("JSON parse error: {e}")))?;
+    Ok(tx.to_hex())
+}
+
+/// Like `decode_tx`, but returns the JSON as `bytes` without building a `str`.
+#[pyfunction]
+fn decode_tx_to_bytes<'py>(py: Python<'py>, hex_str: &str) -> PyResult<Bound<'py, PyBytes>> {
+    let tx = Transaction::from_hex(hex_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))?;
+    let json = serde_json::to_vec(&tx)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Serialize error: {e}")))?;
+    Ok(PyBytes::new_bound(py, &json))
+}
+
 #[pyfunction]
 fn tx_hash(hex_str: &str) -> PyResult<String> {
-    use tos_common::crypto::Hashable;
     let tx = Transaction::from_hex(hex_str)
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))?;
     Ok(tx.hash().to_hex())
 }
 
+/// Wire header: [version:u8][chain_id:u8][source:32][tx_type:u8].
+const HEADER_LEN: usize = 35;
+
+/// Hex-decode a transaction and check it is long enough to hold the header.
+fn tx_header_bytes(hex_str: &str) -> PyResult<Vec<u8>> {
+    let bytes = hex::decode(hex_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Hex decode error: {e}")))?;
+    if bytes.len() < HEADER_LEN {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "transaction must be at least {HEADER_LEN} bytes, got {}",
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Fully decode a transaction and return its serde JSON value.
+fn decode_tx_value(hex_str: &str) -> PyResult<Value> {
+    let tx = Transaction::from_hex(hex_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))?;
+    serde_json::to_value(&tx)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Serialize error: {e}")))
+}
+
+fn json_u64(value: &Value, key: &str) -> PyResult<u64> {
+    value[key].as_u64().ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("decoded transaction has no u64 '{key}'"))
+    })
+}
+
+/// Transaction type id from the wire header, without decoding the payload.
+#[pyfunction]
+fn get_tx_type(hex_str: &str) -> PyResult<u8> {
+    Ok(tx_header_bytes(hex_str)?[34])
+}
+
+/// 32-byte source public key from the wire header, without decoding the payload.
+#[pyfunction]
+fn get_tx_source(hex_str: &str) -> PyResult<Vec<u8>> {
+    Ok(tx_header_bytes(hex_str)?[2..34].to_vec())
+}
+
+/// Transaction nonce.
+///
+/// The payload has no length prefix, so the trailer holding the nonce can only
+/// be located by decoding the whole transaction.
+#[pyfunction]
+fn get_tx_nonce(hex_str: &str) -> PyResult<u64> {
+    json_u64(&decode_tx_value(hex_str)?, "nonce")
+}
+
+/// Transaction fee as (amount, fee_type), with fee_type 0 = TOS, 1 = Energy,
+/// 2 = UNO. Decodes the whole transaction, as for `get_tx_nonce`.
+#[pyfunction]
+fn get_tx_fee(hex_str: &str) -> PyResult<(u64, u8)> {
+    let value = decode_tx_value(hex_str)?;
+    let fee = json_u64(&value, "fee")?;
+    let fee_type = match value["fee_type"].as_str() {
+        Some("TOS") => 0,
+        Some("Energy") => 1,
+        Some("UNO") => 2,
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "decoded transaction has unknown fee_type {other:?}"
+            )))
+        }
+    };
+    Ok((fee, fee_type))
+}
+
+/// Verify the embedded signature against the source key.
+///
+/// The signing bytes are the wire bytes without the trailing multisig and
+/// signature, whose sizes come from tos_common's serializers; the check itself
+/// is tos_common's `Signature::verify`. UnoTransfers, ShieldTransfers and
+/// UnshieldTransfers (types 18-20) also carry unsigned source commitments and
+/// a range proof before the reference and are rejected rather than guessed at.
+#[pyfunction]
+fn verify_tx_signature(hex_str: &str) -> PyResult<bool> {
+    let bytes = tx_header_bytes(hex_str)?;
+
+    let tx_type = bytes[34];
+    if (18..=20).contains(&tx_type) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "verify_tx_signature does not support privacy transaction type {tx_type}"
+        )));
+    }
+
+    let tx = Transaction::from_bytes(&bytes)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))?;
+    // Option<MultiSig> is a presence flag followed by the multisig itself
+    let multisig_len = tx
+        .get_multisig()
+        .as_ref()
+        .map_or(1, |multisig| 1 + multisig.size());
+    let signing_end = bytes.len() - multisig_len - tx.get_signature().size();
+
+    let Ok(source) = tx.get_source().decompress() else {
+        return Ok(false);
+    };
+    Ok(tx.get_signature().verify(&bytes[..signing_end], &source))
+}
+
+fn decode_block_header_inner(hex_str: &str) -> PyResult<BlockHeader> {
+    BlockHeader::from_hex(hex_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Decode error: {e:?}")))
+}
+
+/// Block hash (`BlockHeader::hash`) of a wire-format hex block header.
+#[pyfunction]
+fn compute_block_hash(hex_str: &str) -> PyResult<String> {
+    Ok(decode_block_header_inner(hex_str)?.hash().to_hex())
+}
+
+#[pyfunction]
+fn decode_block_header(hex_str: &str) -> PyResult<String> {
+    let header = decode_block_header_inner(hex_str)?;
+    serde_json::to_string(&header)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Serialize error: {e}")))
+}
+
+#[pyfunction]
+fn encode_block_header(json_str: &str) -> PyResult<String> {
+    let header: BlockHeader = serde_json::from_str(json_str)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("JSON parse error: {e}")))?;
+    Ok(header.to_hex())
+}
+
+/// Parse hex hashes into the ordered set a header stores, rejecting duplicates.
+fn hash_set(field: &str, hashes: &[String]) -> PyResult<IndexSet<Hash>> {
+    let mut set = IndexSet::with_capacity(hashes.len());
+    for (i, hex_str) in hashes.iter().enumerate() {
+        let bytes: [u8; 32] = hex::decode(hex_str)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "{field}[{i}]: expected 64 hex characters"
+                ))
+            })?;
+        if !set.insert(Hash::new(bytes)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{field}[{i}]: duplicate hash"
+            )));
+        }
+    }
+    Ok(set)
+}
+
+/// Header carrying only the given tips and transactions; the tips and txs
+/// hashes do not depend on any other field.
+fn header_with(tips: IndexSet<Hash>, txs_hashes: IndexSet<Hash>) -> PyResult<BlockHeader> {
+    let miner = CompressedPublicKey::from_bytes(&[0u8; 32])
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Miner key error: {e:?}")))?;
+    Ok(BlockHeader::new(
+        BlockVersion::Nobunaga,
+        0,
+        0,
+        tips,
+        [0u8; EXTRA_NONCE_SIZE],
+        miner,
+        txs_hashes,
+    ))
+}
+
+/// Tips hash (`BlockHeader::get_tips_hash`) of hex-encoded tip hashes, in order.
+#[pyfunction]
+fn compute_tips_hash(tip_hashes: Vec<String>) -> PyResult<String> {
+    let header = header_with(hash_set("tip_hashes", &tip_hashes)?, IndexSet::new())?;
+    Ok(header.get_tips_hash().to_hex())
+}
+
+/// Transactions hash (`BlockHeader::get_txs_hash`) of hex-encoded tx hashes, in order.
+#[pyfunction]
+fn compute_txs_hash(tx_hashes: Vec<String>) -> PyResult<String> {
+    let header = header_with(IndexSet::new(), hash_set("tx_hashes", &tx_hashes)?)?;
+    Ok(header.get_txs_hash().to_hex())
+}
+
 #[pymodule]
 fn tos_codec(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(encode_tx, m)?)?;
     m.add_function(wrap_pyfunction!(decode_tx, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_tx_strict, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_tx_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_tx_to_bytes, m)?)?;
     m.add_function(wrap_pyfunction!(tx_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tx_type, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tx_source, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tx_nonce, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tx_fee, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_tx_signature, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_block_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_block_header, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_block_header, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_tips_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_txs_hash, m)?)?;
     Ok(())
 }
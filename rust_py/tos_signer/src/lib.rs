@@ -1,11 +1,15 @@
 use lazy_static::lazy_static;
+use pyo3::buffer::PyBuffer;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyModule, PyTuple};
+use pyo3::types::{PyBytes, PyDict, PyList, PyModule, PyTuple};
+use rand::rngs::OsRng;
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use sha3::{Digest, Sha3_512};
+use subtle::ConstantTimeEq;
 use tos_crypto::bulletproofs::PedersenGens;
+use tos_crypto::curve25519_dalek::ristretto::CompressedRistretto;
 use tos_crypto::curve25519_dalek::{RistrettoPoint, Scalar};
 use tos_crypto::merlin::Transcript;
 
@@ -38,10 +42,36 @@ impl Writer {
         self.buf.extend_from_slice(&v.to_be_bytes());
     }
 
+    fn write_u32(&mut self, v: u32) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
     fn write_u64(&mut self, v: u64) {
         self.buf.extend_from_slice(&v.to_be_bytes());
     }
 
+    /// 16-byte big-endian amount for protocol extensions (e.g. cross-chain
+    /// bridge amounts); 32-byte amounts are written with `write_bytes`.
+    fn write_u128(&mut self, v: u128) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    /// Signed amounts (e.g. balance deltas) are 8-byte big-endian two's
+    /// complement, identical to `i64::to_be_bytes()`: -1 encodes as ff..ff.
+    fn write_i64(&mut self, v: i64) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    /// Unsigned LEB128: 7 bits per byte, least significant group first, high
+    /// bit set on every byte but the last. Existing payloads stay fixed-width.
+    fn write_varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8 & 0x7f) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
     fn write_bytes(&mut self, b: &[u8]) {
         self.buf.extend_from_slice(b);
     }
@@ -67,6 +97,174 @@ impl Writer {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Reader – big-endian binary reader, the inverse of Writer
+// ---------------------------------------------------------------------------
+
+/// A u64 LEB128 varint never needs more than 10 bytes.
+const MAX_VARINT_LEN: usize = 10;
+
+/// Every read takes the name of the field being decoded; truncation errors
+/// report it together with the byte offset and expected vs. available length.
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    fn read_bytes(&mut self, field: &str, size: usize) -> PyResult<&'a [u8]> {
+        let remaining = self.remaining();
+        if remaining < size {
+            return Err(PyValueError::new_err(format!(
+                "{field}: truncated at offset {}: expected {size} bytes, got {remaining}",
+                self.pos
+            )));
+        }
+        let out = &self.buf[self.pos..self.pos + size];
+        self.pos += size;
+        Ok(out)
+    }
+
+    fn read_u8(&mut self, field: &str) -> PyResult<u8> {
+        Ok(self.read_bytes(field, 1)?[0])
+    }
+
+    fn read_u16(&mut self, field: &str) -> PyResult<u16> {
+        let b = self.read_bytes(field, 2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn read_u64(&mut self, field: &str) -> PyResult<u64> {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.read_bytes(field, 8)?);
+        Ok(u64::from_be_bytes(b))
+    }
+
+    fn read_u128(&mut self, field: &str) -> PyResult<u128> {
+        let mut b = [0u8; 16];
+        b.copy_from_slice(self.read_bytes(field, 16)?);
+        Ok(u128::from_be_bytes(b))
+    }
+
+    fn read_i64(&mut self, field: &str) -> PyResult<i64> {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.read_bytes(field, 8)?);
+        Ok(i64::from_be_bytes(b))
+    }
+
+    /// Inverse of `Writer::write_varint`. Rejects encodings longer than
+    /// `MAX_VARINT_LEN` bytes and a final byte that overflows u64.
+    fn read_varint(&mut self, field: &str) -> PyResult<u64> {
+        let offset = self.pos;
+        let mut value = 0u64;
+        for i in 0..MAX_VARINT_LEN {
+            let byte = self.read_u8(field)?;
+            let group = u64::from(byte & 0x7f);
+            if i == MAX_VARINT_LEN - 1 && group > 1 {
+                return Err(PyValueError::new_err(format!(
+                    "{field}: varint at offset {offset} overflows u64"
+                )));
+            }
+            value |= group << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(PyValueError::new_err(format!(
+            "{field}: varint at offset {offset} is longer than {MAX_VARINT_LEN} bytes"
+        )))
+    }
+
+    #[allow(dead_code)] // no decoder reads a bare bool yet
+    fn read_bool(&mut self, field: &str) -> PyResult<bool> {
+        let offset = self.pos;
+        match self.read_u8(field)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(PyValueError::new_err(format!(
+                "{field}: invalid bool {other} at offset {offset}, expected 0 or 1"
+            ))),
+        }
+    }
+
+    /// Inverse of `Writer::write_optional_vec_u8`: [0x00] or [0x01][len:u16][data].
+    fn read_optional_vec_u8(&mut self, field: &str) -> PyResult<Option<Vec<u8>>> {
+        let flag_offset = self.pos;
+        match self.read_u8(field)? {
+            0 => Ok(None),
+            1 => {
+                let len = self.read_u16(field)? as usize;
+                Ok(Some(self.read_bytes(field, len)?.to_vec()))
+            }
+            other => Err(PyValueError::new_err(format!(
+                "{field}: invalid option flag {other} at offset {flag_offset}, expected 0 or 1"
+            ))),
+        }
+    }
+
+    /// Reject trailing bytes once every field has been read.
+    fn expect_end(&self) -> PyResult<()> {
+        if self.remaining() != 0 {
+            return Err(PyValueError::new_err(format!(
+                "{} trailing bytes at offset {}",
+                self.remaining(),
+                self.pos
+            )));
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Transaction type IDs
+// ---------------------------------------------------------------------------
+
+const TX_TYPE_BURN: u8 = 0;
+const TX_TYPE_TRANSFERS: u8 = 1;
+const TX_TYPE_ENERGY: u8 = 5;
+const TX_TYPE_SET_KYC: u8 = 9;
+const TX_TYPE_REVOKE_KYC: u8 = 10;
+const TX_TYPE_RENEW_KYC: u8 = 11;
+const TX_TYPE_BOOTSTRAP_COMMITTEE: u8 = 12;
+const TX_TYPE_REGISTER_COMMITTEE: u8 = 13;
+const TX_TYPE_UPDATE_COMMITTEE: u8 = 14;
+const TX_TYPE_EMERGENCY_SUSPEND: u8 = 15;
+const TX_TYPE_TRANSFER_KYC: u8 = 16;
+const TX_TYPE_APPEAL_KYC: u8 = 17;
+const TX_TYPE_SHIELD_TRANSFERS: u8 = 19;
+const TX_TYPE_REGISTER_NAME: u8 = 21;
+const TX_TYPE_DEPOSIT_ESCROW: u8 = 25;
+const TX_TYPE_APPEAL_ESCROW: u8 = 31;
+const TX_TYPE_UPDATE_ARBITER: u8 = 34;
+const TX_TYPE_SLASH_ARBITER: u8 = 35;
+const TX_TYPE_REQUEST_ARBITER_EXIT: u8 = 36;
+const TX_TYPE_WITHDRAW_ARBITER_STAKE: u8 = 37;
+const TX_TYPE_CANCEL_ARBITER_EXIT: u8 = 38;
+const TX_TYPE_COMMIT_VOTE_REQUEST: u8 = 45;
+const TX_TYPE_COMMIT_JUROR_VOTE: u8 = 47;
+
+// ---------------------------------------------------------------------------
+// Validation helpers
+// ---------------------------------------------------------------------------
+
+fn expect_len(field: &str, data: &[u8], size: usize) -> PyResult<()> {
+    if data.len() != size {
+        return Err(PyValueError::new_err(format!(
+            "{field} must be {size} bytes, got {}",
+            data.len()
+        )));
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Key derivation helpers
 // ---------------------------------------------------------------------------
@@ -124,6 +322,30 @@ fn sign(private_key: &Scalar, compressed_pub: &[u8; 32], message: &[u8]) -> [u8;
     sig
 }
 
+/// Verify an `s || e` signature: recompute R = s*H - e*P and check e == H(P, msg, R).
+///
+/// Malformed keys or non-canonical scalars verify as false.
+fn verify(compressed_pub: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    let Some(public) = CompressedRistretto(*compressed_pub).decompress() else {
+        return false;
+    };
+    let mut s_bytes = [0u8; 32];
+    let mut e_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(&signature[..32]);
+    e_bytes.copy_from_slice(&signature[32..]);
+    let (Some(s), Some(e)) = (
+        Option::<Scalar>::from(Scalar::from_canonical_bytes(s_bytes)),
+        Option::<Scalar>::from(Scalar::from_canonical_bytes(e_bytes)),
+    ) else {
+        return false;
+    };
+
+    let r = s * (*H) - e * public;
+    let computed = hash_and_point_to_scalar(compressed_pub, message, &r);
+    computed.ct_eq(&e).into()
+}
+
+/// seed = SHA3-512("tos-signer/chacha-seed/v1" || label || [a:u8] || [b:u64 BE])[..32]
 fn chacha_seed(label: &[u8], a: u8, b: u64) -> [u8; 32] {
     let mut hasher = Sha3_512::new();
     hasher.update(b"tos-signer/chacha-seed/v1");
@@ -136,6 +358,83 @@ fn chacha_seed(label: &[u8], a: u8, b: u64) -> [u8; 32] {
     seed
 }
 
+/// Build the T1 signing frame around `payload` and sign it with the seed-byte keypair.
+fn sign_payload(
+    seed_byte: u8,
+    chain_id: u8,
+    tx_type_id: u8,
+    payload: &[u8],
+    fee: u64,
+    fee_type: u8,
+    nonce: u64,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    let (private, public) = keypair_from_byte(seed_byte);
+    sign_payload_with_keypair(
+        &private, &public, chain_id, tx_type_id, payload, fee, fee_type, nonce, ref_hash, ref_topo,
+    )
+}
+
+/// Like `sign_payload`, but for a raw 32-byte private key.
+fn sign_payload_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    tx_type_id: u8,
+    payload: &[u8],
+    fee: u64,
+    fee_type: u8,
+    nonce: u64,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    let key: &[u8; 32] = private_key.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "private_key must be 32 bytes, got {}",
+            private_key.len()
+        ))
+    })?;
+    let (private, public) = keypair_from_private_key_bytes(key);
+    sign_payload_with_keypair(
+        &private, &public, chain_id, tx_type_id, payload, fee, fee_type, nonce, ref_hash, ref_topo,
+    )
+}
+
+fn sign_payload_with_keypair(
+    private: &Scalar,
+    public: &RistrettoPoint,
+    chain_id: u8,
+    tx_type_id: u8,
+    payload: &[u8],
+    fee: u64,
+    fee_type: u8,
+    nonce: u64,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    expect_len("ref_hash", ref_hash, 32)?;
+
+    let compressed = public.compress();
+    let source = compressed.as_bytes();
+
+    // 1 + 1 + 32 + 1 + payload + 8 + 1 + 8 + 32 + 8 = 92 + payload
+    let mut w = Writer::with_capacity(92 + payload.len());
+    w.write_u8(1); // version T1
+    w.write_u8(chain_id);
+    w.write_bytes(source);
+    w.write_u8(tx_type_id);
+    w.write_bytes(payload);
+    w.write_u64(fee);
+    w.write_u8(fee_type);
+    w.write_u64(nonce);
+    w.write_bytes(ref_hash);
+    w.write_u64(ref_topo);
+
+    let signing_bytes = w.into_vec();
+    let sig = sign(private, source, &signing_bytes);
+    Ok(sig.to_vec())
+}
+
 // ---------------------------------------------------------------------------
 // Transfer payload encoding (shared inner logic)
 // ---------------------------------------------------------------------------
@@ -204,146 +503,3126 @@ fn encode_transfer_payload_inner(transfers: &Bound<'_, PyList>) -> PyResult<Vec<
     Ok(w.into_vec())
 }
 
+fn encode_burn_payload_inner(asset: &[u8], amount: u64) -> PyResult<Vec<u8>> {
+    expect_len("asset", asset, 32)?;
+    let mut w = Writer::with_capacity(40);
+    w.write_bytes(asset);
+    w.write_u64(amount);
+    Ok(w.into_vec())
+}
+
 // ---------------------------------------------------------------------------
-// PyO3-exposed functions
+// Payload decoding (shared inner logic)
 // ---------------------------------------------------------------------------
 
-// -- Existing Level 0 (seed-byte based) ------------------------------------
+type DecodedTransfer = (Vec<u8>, Vec<u8>, u64, Option<Vec<u8>>);
 
-#[pyfunction]
-fn get_public_key(seed_byte: u8) -> PyResult<Vec<u8>> {
-    let (_, public) = keypair_from_byte(seed_byte);
-    Ok(public.compress().as_bytes().to_vec())
+/// Matches MAX_TRANSFER_COUNT in tos_spec.config.
+const MAX_TRANSFER_COUNT: usize = 500;
+
+/// Inverse of `encode_transfer_payload_inner`.
+fn decode_transfer_payload_inner(data: &[u8]) -> PyResult<Vec<DecodedTransfer>> {
+    let mut r = Reader::new(data);
+    let count = r.read_u16("count")? as usize;
+    if count == 0 {
+        return Err(PyValueError::new_err(
+            "count: transfers list must not be empty",
+        ));
+    }
+    if count > MAX_TRANSFER_COUNT {
+        return Err(PyValueError::new_err(format!(
+            "count: {count} exceeds maximum of {MAX_TRANSFER_COUNT} transfers"
+        )));
+    }
+
+    let mut transfers = Vec::with_capacity(count);
+    for i in 0..count {
+        let asset = r.read_bytes(&format!("transfers[{i}].asset"), 32)?.to_vec();
+        let dest = r
+            .read_bytes(&format!("transfers[{i}].destination"), 32)?
+            .to_vec();
+        let amount = r.read_u64(&format!("transfers[{i}].amount"))?;
+        let extra_data = r.read_optional_vec_u8(&format!("transfers[{i}].extra_data"))?;
+        transfers.push((asset, dest, amount, extra_data));
+    }
+    r.expect_end()?;
+    Ok(transfers)
 }
 
-#[pyfunction]
-fn sign_data(data: &[u8], seed_byte: u8) -> PyResult<Vec<u8>> {
-    let (private, public) = keypair_from_byte(seed_byte);
-    let compressed = public.compress();
-    let sig = sign(&private, compressed.as_bytes(), data);
-    Ok(sig.to_vec())
+/// Inverse of the burn payload encoding: [asset:32][amount:u64].
+fn decode_burn_payload_inner(data: &[u8]) -> PyResult<(Vec<u8>, u64)> {
+    let mut r = Reader::new(data);
+    let asset = r.read_bytes("asset", 32)?.to_vec();
+    let amount = r.read_u64("amount")?;
+    r.expect_end()?;
+    Ok((asset, amount))
 }
 
-// -- Level 1: Raw private key support --------------------------------------
+// ---------------------------------------------------------------------------
+// Committee approval encoding (shared inner logic)
+// ---------------------------------------------------------------------------
 
-#[pyfunction]
-fn get_public_key_from_private(private_key: &[u8]) -> PyResult<Vec<u8>> {
-    if private_key.len() != 32 {
+/// Extract a 32/64-byte field from an approval dict.
+fn approval_field(
+    dict: &Bound<'_, PyDict>,
+    path: &str,
+    key: &str,
+    size: usize,
+) -> PyResult<Vec<u8>> {
+    let value: Vec<u8> = dict
+        .get_item(key)?
+        .ok_or_else(|| PyValueError::new_err(format!("{path}.{key}: missing field")))?
+        .extract()?;
+    check_approval_len(path, key, &value, size)?;
+    Ok(value)
+}
+
+fn check_approval_len(path: &str, key: &str, value: &[u8], size: usize) -> PyResult<()> {
+    if value.len() != size {
         return Err(PyValueError::new_err(format!(
-            "private_key must be 32 bytes, got {}",
-            private_key.len()
+            "{path}.{key}: expected {size} bytes, got {}",
+            value.len()
         )));
     }
-    let key: &[u8; 32] = private_key
-        .try_into()
-        .map_err(|_| PyValueError::new_err("private_key must be 32 bytes"))?;
-    let (_, public) = keypair_from_private_key_bytes(key);
-    Ok(public.compress().as_bytes().to_vec())
+    Ok(())
 }
 
-#[pyfunction]
-fn sign_with_key(data: &[u8], private_key: &[u8]) -> PyResult<Vec<u8>> {
-    if private_key.len() != 32 {
+/// Unpack a `(pubkey, signature, timestamp)` approval tuple.
+fn approval_from_tuple(
+    tuple: &Bound<'_, PyTuple>,
+    path: &str,
+) -> PyResult<(Vec<u8>, Vec<u8>, u64)> {
+    if tuple.len() != 3 {
         return Err(PyValueError::new_err(format!(
-            "private_key must be 32 bytes, got {}",
-            private_key.len()
+            "{path}: expected (pubkey, signature, timestamp), got {} elements",
+            tuple.len()
         )));
     }
-    let key: &[u8; 32] = private_key
-        .try_into()
-        .map_err(|_| PyValueError::new_err("private_key must be 32 bytes"))?;
-    let (private, public) = keypair_from_private_key_bytes(key);
-    let compressed = public.compress();
-    let sig = sign(&private, compressed.as_bytes(), data);
-    Ok(sig.to_vec())
+    let pubkey: Vec<u8> = tuple.get_item(0)?.extract()?;
+    check_approval_len(path, "pubkey", &pubkey, 32)?;
+    let signature: Vec<u8> = tuple.get_item(1)?.extract()?;
+    check_approval_len(path, "signature", &signature, 64)?;
+    let timestamp: u64 = tuple.get_item(2)?.extract()?;
+    Ok((pubkey, signature, timestamp))
 }
 
-// -- Level 2: Transaction frame assembly -----------------------------------
-
-/// Assemble the signing-bytes frame for any transaction type.
+/// Encode a list of CommitteeApprovals, each either a dict (`pubkey`,
+/// `signature`, `timestamp`) or a `(pubkey, signature, timestamp)` tuple.
 ///
-/// Layout: [version:u8][chain_id:u8][source:32][tx_type_id:u8][encoded_payload:var]
-///         [fee:u64][fee_type:u8][nonce:u64][ref_hash:32][ref_topo:u64]
-#[pyfunction]
-fn build_signing_bytes(
-    version: u8,
-    chain_id: u8,
-    source: &[u8],
-    tx_type_id: u8,
-    encoded_payload: &[u8],
-    fee: u64,
-    fee_type: u8,
-    nonce: u64,
-    ref_hash: &[u8],
-    ref_topo: u64,
-) -> PyResult<Vec<u8>> {
-    if source.len() != 32 {
+/// Format: [count:u16] + for each: [pubkey:32][signature:64][timestamp:u64]
+fn write_approvals(w: &mut Writer, field: &str, approvals: &Bound<'_, PyList>) -> PyResult<()> {
+    let count = approvals.len();
+    if count > u16::MAX as usize {
         return Err(PyValueError::new_err(format!(
-            "source must be 32 bytes, got {}",
-            source.len()
-        )));
-    }
-    if ref_hash.len() != 32 {
-        return Err(PyValueError::new_err(format!(
-            "ref_hash must be 32 bytes, got {}",
-            ref_hash.len()
+            "{field}: at most 65535 approvals allowed, got {count}"
         )));
     }
+    w.write_u16(count as u16);
 
-    // 1 + 1 + 32 + 1 + payload + 8 + 1 + 8 + 32 + 8 = 92 + payload
-    let mut w = Writer::with_capacity(92 + encoded_payload.len());
-    w.write_u8(version);
-    w.write_u8(chain_id);
-    w.write_bytes(source);
-    w.write_u8(tx_type_id);
-    w.write_bytes(encoded_payload);
-    w.write_u64(fee);
-    w.write_u8(fee_type);
-    w.write_u64(nonce);
-    w.write_bytes(ref_hash);
-    w.write_u64(ref_topo);
+    for i in 0..count {
+        let path = format!("{field}[{i}]");
+        let item = approvals.get_item(i)?;
+        let (pubkey, signature, timestamp) = if let Ok(tuple) = item.downcast::<PyTuple>() {
+            approval_from_tuple(tuple, &path)?
+        } else {
+            let dict = item
+                .downcast::<PyDict>()
+                .map_err(|_| PyValueError::new_err(format!("{path}: expected a dict or tuple")))?;
+            let timestamp: u64 = dict
+                .get_item("timestamp")?
+                .ok_or_else(|| PyValueError::new_err(format!("{path}.timestamp: missing field")))?
+                .extract()?;
+            (
+                approval_field(dict, &path, "pubkey", 32)?,
+                approval_field(dict, &path, "signature", 64)?,
+                timestamp,
+            )
+        };
 
-    Ok(w.into_vec())
+        w.write_bytes(&pubkey);
+        w.write_bytes(&signature);
+        w.write_u64(timestamp);
+    }
+
+    Ok(())
 }
 
-// -- Level 3: Payload encoding ---------------------------------------------
+// ---------------------------------------------------------------------------
+// Arbiter payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
 
-/// Encode a list of transfers into payload bytes.
-///
-/// Each transfer is a tuple: (asset: bytes, destination: bytes, amount: int,
-///                             extra_data: Optional[bytes])
-/// 3-element tuples are accepted (extra_data defaults to None).
-///
-/// Format: [count:u16] + for each: [asset:32][dest:32][amount:u64][optional_extra_data]
-#[pyfunction]
-fn encode_transfer_payload(transfers: &Bound<'_, PyList>) -> PyResult<Vec<u8>> {
-    encode_transfer_payload_inner(transfers)
-}
+/// Highest `ExpertiseDomain` discriminant (Nft = 13).
+const MAX_EXPERTISE_DOMAIN: u8 = 13;
 
-/// Encode a burn payload.
-///
-/// Format: [asset:32][amount:u64]
-#[pyfunction]
-fn encode_burn_payload(asset: &[u8], amount: u64) -> PyResult<Vec<u8>> {
-    if asset.len() != 32 {
+/// Highest `ArbiterStatus` discriminant (Removed = 3).
+const MAX_ARBITER_STATUS: u8 = 3;
+
+/// Encode a string as [len:u8][utf8].
+fn write_string_u8(w: &mut Writer, field: &str, value: &str) -> PyResult<()> {
+    let data = value.as_bytes();
+    if data.len() > u8::MAX as usize {
         return Err(PyValueError::new_err(format!(
-            "asset must be 32 bytes, got {}",
-            asset.len()
+            "{field}: must be at most 255 bytes, got {}",
+            data.len()
         )));
     }
-    let mut w = Writer::with_capacity(40);
-    w.write_bytes(asset);
-    w.write_u64(amount);
-    Ok(w.into_vec())
+    w.write_u8(data.len() as u8);
+    w.write_bytes(data);
+    Ok(())
 }
 
-// -- Level 4: All-in-one convenience ---------------------------------------
+/// Encode expertise domains as [count:u8][domain:u8]...
+fn write_expertise_domains(w: &mut Writer, field: &str, domains: &[u8]) -> PyResult<()> {
+    if domains.len() > u8::MAX as usize {
+        return Err(PyValueError::new_err(format!(
+            "{field}: at most 255 domains allowed, got {}",
+            domains.len()
+        )));
+    }
+    for (i, domain) in domains.iter().enumerate() {
+        if *domain > MAX_EXPERTISE_DOMAIN {
+            return Err(PyValueError::new_err(format!(
+                "{field}[{i}]: unknown expertise domain {domain} (expected 0-{MAX_EXPERTISE_DOMAIN})"
+            )));
+        }
+    }
+    w.write_u8(domains.len() as u8);
+    w.write_bytes(domains);
+    Ok(())
+}
+
+/// Encode an UpdateArbiter payload.
+///
+/// Format: [flags:u8] followed by each present field in bit order:
+///   bit 0: name [len:u8][utf8]
+///   bit 1: expertise_domains [count:u8][domain:u8]...
+///   bit 2: fee_basis_points [u16]
+///   bit 3: min_escrow_value [u64]
+///   bit 4: max_escrow_value [u64]
+///   bit 5: add_stake [u64]
+///   bit 6: status [u8]
+///   bit 7: deactivate (flag only, no body)
+fn encode_update_arbiter_payload_inner(
+    name: Option<&str>,
+    expertise_domains: Option<&[u8]>,
+    fee_basis_points: Option<u16>,
+    min_escrow_value: Option<u64>,
+    max_escrow_value: Option<u64>,
+    add_stake: Option<u64>,
+    status: Option<u8>,
+    deactivate: bool,
+) -> PyResult<Vec<u8>> {
+    let mut flags = 0u8;
+    let mut body = Writer::with_capacity(64);
+
+    if let Some(name) = name {
+        flags |= 1 << 0;
+        write_string_u8(&mut body, "name", name)?;
+    }
+    if let Some(domains) = expertise_domains {
+        flags |= 1 << 1;
+        write_expertise_domains(&mut body, "expertise_domains", domains)?;
+    }
+    if let Some(bps) = fee_basis_points {
+        if bps > 10_000 {
+            return Err(PyValueError::new_err(format!(
+                "fee_basis_points: must be at most 10000, got {bps}"
+            )));
+        }
+        flags |= 1 << 2;
+        body.write_u16(bps);
+    }
+    if let Some(min) = min_escrow_value {
+        flags |= 1 << 3;
+        body.write_u64(min);
+    }
+    if let Some(max) = max_escrow_value {
+        flags |= 1 << 4;
+        body.write_u64(max);
+    }
+    if let (Some(min), Some(max)) = (min_escrow_value, max_escrow_value) {
+        if min > max {
+            return Err(PyValueError::new_err(format!(
+                "min_escrow_value ({min}) must not exceed max_escrow_value ({max})"
+            )));
+        }
+    }
+    if let Some(stake) = add_stake {
+        flags |= 1 << 5;
+        body.write_u64(stake);
+    }
+    if let Some(status) = status {
+        if status > MAX_ARBITER_STATUS {
+            return Err(PyValueError::new_err(format!(
+                "status: unknown arbiter status {status} (expected 0-{MAX_ARBITER_STATUS})"
+            )));
+        }
+        flags |= 1 << 6;
+        body.write_u8(status);
+    }
+    if deactivate {
+        flags |= 1 << 7;
+    }
+
+    let body = body.into_vec();
+    let mut w = Writer::with_capacity(1 + body.len());
+    w.write_u8(flags);
+    w.write_bytes(&body);
+    Ok(w.into_vec())
+}
+
+/// Encode a SlashArbiter payload.
+///
+/// Format: [committee_id:32][arbiter_pubkey:32][amount:u64][reason_hash:32][approvals]
+fn encode_slash_arbiter_payload_inner(
+    committee_id: &[u8],
+    arbiter_pubkey: &[u8],
+    amount: u64,
+    reason_hash: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("committee_id", committee_id, 32)?;
+    expect_len("arbiter_pubkey", arbiter_pubkey, 32)?;
+    expect_len("reason_hash", reason_hash, 32)?;
+
+    // 32 + 32 + 8 + 32 + 2 + approvals * 104
+    let mut w = Writer::with_capacity(106 + approvals.len() * 104);
+    w.write_bytes(committee_id);
+    w.write_bytes(arbiter_pubkey);
+    w.write_u64(amount);
+    w.write_bytes(reason_hash);
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// KYC payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// Encode a SetKyc payload.
+///
+/// Format: [account:32][level:u16][verified_at:u64][data_hash:32][committee_id:32][approvals]
+fn encode_set_kyc_payload_inner(
+    account: &[u8],
+    level: u16,
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("data_hash", data_hash, 32)?;
+    expect_len("committee_id", committee_id, 32)?;
+
+    // 32 + 2 + 8 + 32 + 32 + 2 + approvals * 104
+    let mut w = Writer::with_capacity(108 + approvals.len() * 104);
+    w.write_bytes(account);
+    w.write_u16(level);
+    w.write_u64(verified_at);
+    w.write_bytes(data_hash);
+    w.write_bytes(committee_id);
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+/// Encode a RevokeKyc payload.
+///
+/// Format: [account:32][reason_hash:32][committee_id:32][approvals]
+fn encode_revoke_kyc_payload_inner(
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("reason_hash", reason_hash, 32)?;
+    expect_len("committee_id", committee_id, 32)?;
+
+    // 32 + 32 + 32 + 2 + approvals * 104
+    let mut w = Writer::with_capacity(98 + approvals.len() * 104);
+    w.write_bytes(account);
+    w.write_bytes(reason_hash);
+    w.write_bytes(committee_id);
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+/// Encode a RenewKyc payload.
+///
+/// Format: [account:32][verified_at:u64][data_hash:32][committee_id:32][approvals]
+fn encode_renew_kyc_payload_inner(
+    account: &[u8],
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("data_hash", data_hash, 32)?;
+    expect_len("committee_id", committee_id, 32)?;
+
+    // 32 + 8 + 32 + 32 + 2 + approvals * 104
+    let mut w = Writer::with_capacity(106 + approvals.len() * 104);
+    w.write_bytes(account);
+    w.write_u64(verified_at);
+    w.write_bytes(data_hash);
+    w.write_bytes(committee_id);
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+/// Encode an EmergencySuspend payload.
+///
+/// Format: [account:32][reason_hash:32][committee_id:32][approvals][expires_at:u64]
+fn encode_emergency_suspend_payload_inner(
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+    expires_at: u64,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("reason_hash", reason_hash, 32)?;
+    expect_len("committee_id", committee_id, 32)?;
+
+    // 32 + 32 + 32 + 2 + approvals * 104 + 8
+    let mut w = Writer::with_capacity(106 + approvals.len() * 104);
+    w.write_bytes(account);
+    w.write_bytes(reason_hash);
+    w.write_bytes(committee_id);
+    write_approvals(&mut w, "approvals", approvals)?;
+    w.write_u64(expires_at);
+    Ok(w.into_vec())
+}
+
+/// Encode a TransferKyc payload.
+///
+/// Format: [account:32][source_committee_id:32][source_approvals]
+///         [dest_committee_id:32][dest_approvals][new_data_hash:32][transferred_at:u64]
+fn encode_transfer_kyc_payload_inner(
+    account: &[u8],
+    source_committee_id: &[u8],
+    source_approvals: &Bound<'_, PyList>,
+    dest_committee_id: &[u8],
+    dest_approvals: &Bound<'_, PyList>,
+    new_data_hash: &[u8],
+    transferred_at: u64,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("source_committee_id", source_committee_id, 32)?;
+    expect_len("dest_committee_id", dest_committee_id, 32)?;
+    expect_len("new_data_hash", new_data_hash, 32)?;
+
+    // 32 * 4 + 2 * 2 + 8 + approvals * 104
+    let approvals = source_approvals.len() + dest_approvals.len();
+    let mut w = Writer::with_capacity(140 + approvals * 104);
+    w.write_bytes(account);
+    w.write_bytes(source_committee_id);
+    write_approvals(&mut w, "source_approvals", source_approvals)?;
+    w.write_bytes(dest_committee_id);
+    write_approvals(&mut w, "dest_approvals", dest_approvals)?;
+    w.write_bytes(new_data_hash);
+    w.write_u64(transferred_at);
+    Ok(w.into_vec())
+}
+
+/// Encode an AppealKyc payload.
+///
+/// Format: [account:32][original_committee_id:32][parent_committee_id:32]
+///         [reason_hash:32][documents_hash:32][submitted_at:u64]
+fn encode_appeal_kyc_payload_inner(
+    account: &[u8],
+    original_committee_id: &[u8],
+    parent_committee_id: &[u8],
+    reason_hash: &[u8],
+    documents_hash: &[u8],
+    submitted_at: u64,
+) -> PyResult<Vec<u8>> {
+    expect_len("account", account, 32)?;
+    expect_len("original_committee_id", original_committee_id, 32)?;
+    expect_len("parent_committee_id", parent_committee_id, 32)?;
+    expect_len("reason_hash", reason_hash, 32)?;
+    expect_len("documents_hash", documents_hash, 32)?;
+
+    // 32 * 5 + 8
+    let mut w = Writer::with_capacity(168);
+    w.write_bytes(account);
+    w.write_bytes(original_committee_id);
+    w.write_bytes(parent_committee_id);
+    w.write_bytes(reason_hash);
+    w.write_bytes(documents_hash);
+    w.write_u64(submitted_at);
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// Committee payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// Highest `MemberRole` discriminant (Member = 2).
+const MAX_MEMBER_ROLE: u8 = 2;
+
+/// Encode Option<String> as a bool flag, then if present [len:u8][utf8].
+fn write_optional_string_u8(w: &mut Writer, field: &str, value: Option<&str>) -> PyResult<()> {
+    match value {
+        None => w.write_bool(false),
+        Some(s) => {
+            w.write_bool(true);
+            write_string_u8(w, field, s)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_member_role(field: &str, role: u8) -> PyResult<()> {
+    if role > MAX_MEMBER_ROLE {
+        return Err(PyValueError::new_err(format!(
+            "{field}: unknown member role {role} (expected 0-{MAX_MEMBER_ROLE})"
+        )));
+    }
+    Ok(())
+}
+
+/// Encode one CommitteeMemberInit: [pubkey:32][has_name:u8][len:u8][utf8]?[role:u8]
+fn write_committee_member(
+    w: &mut Writer,
+    path: &str,
+    pubkey: &[u8],
+    name: Option<&str>,
+    role: u8,
+) -> PyResult<()> {
+    expect_len(&format!("{path}.pubkey"), pubkey, 32)?;
+    check_member_role(&format!("{path}.role"), role)?;
+
+    w.write_bytes(pubkey);
+    write_optional_string_u8(w, &format!("{path}.name"), name)?;
+    w.write_u8(role);
+    Ok(())
+}
+
+/// Encode a list of CommitteeMemberInit dicts (`pubkey`, optional `name`, `role`).
+///
+/// Format: [count:u8] + for each: [pubkey:32][has_name:u8][len:u8][utf8]?[role:u8]
+fn write_committee_members(
+    w: &mut Writer,
+    field: &str,
+    members: &Bound<'_, PyList>,
+) -> PyResult<()> {
+    let count = members.len();
+    if count > u8::MAX as usize {
+        return Err(PyValueError::new_err(format!(
+            "{field}: at most 255 members allowed, got {count}"
+        )));
+    }
+    w.write_u8(count as u8);
+
+    for i in 0..count {
+        let path = format!("{field}[{i}]");
+        let item = members.get_item(i)?;
+        let dict = item
+            .downcast::<PyDict>()
+            .map_err(|_| PyValueError::new_err(format!("{path}: expected a dict")))?;
+
+        let pubkey = approval_field(dict, &path, "pubkey", 32)?;
+        let name: Option<String> = match dict.get_item("name")? {
+            Some(value) => value.extract()?,
+            None => None,
+        };
+        let role: u8 = dict
+            .get_item("role")?
+            .ok_or_else(|| PyValueError::new_err(format!("{path}.role: missing field")))?
+            .extract()?;
+        write_committee_member(w, &path, &pubkey, name.as_deref(), role)?;
+    }
+
+    Ok(())
+}
+
+/// Both thresholds must be at least 1 and no larger than the member count.
+fn check_committee_thresholds(threshold: u8, kyc_threshold: u8, members: usize) -> PyResult<()> {
+    for (field, value) in [("threshold", threshold), ("kyc_threshold", kyc_threshold)] {
+        if value == 0 || value as usize > members {
+            return Err(PyValueError::new_err(format!(
+                "{field} must be between 1 and the member count ({members}), got {value}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Encode a BootstrapCommittee payload.
+///
+/// Format: [name:u8 len+utf8][members][threshold:u8][kyc_threshold:u8][max_kyc_level:u16]
+fn encode_bootstrap_committee_payload_inner(
+    committee_name: &str,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+) -> PyResult<Vec<u8>> {
+    check_committee_thresholds(threshold, kyc_threshold, members.len())?;
+
+    let mut w = Writer::with_capacity(1 + committee_name.len() + 1 + members.len() * 64 + 4);
+    write_string_u8(&mut w, "committee_name", committee_name)?;
+    write_committee_members(&mut w, "members", members)?;
+    w.write_u8(threshold);
+    w.write_u8(kyc_threshold);
+    w.write_u16(max_kyc_level);
+    Ok(w.into_vec())
+}
+
+/// Encode a RegisterCommittee payload.
+///
+/// Format: [name:u8 len+utf8][region:u8][members][threshold:u8][kyc_threshold:u8]
+///         [max_kyc_level:u16][parent_id:32][approvals]
+fn encode_register_committee_payload_inner(
+    committee_name: &str,
+    region: u8,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+    parent_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("parent_id", parent_id, 32)?;
+    check_committee_thresholds(threshold, kyc_threshold, members.len())?;
+
+    let mut w = Writer::with_capacity(
+        1 + committee_name.len() + 2 + members.len() * 64 + 36 + 2 + approvals.len() * 104,
+    );
+    write_string_u8(&mut w, "committee_name", committee_name)?;
+    w.write_u8(region);
+    write_committee_members(&mut w, "members", members)?;
+    w.write_u8(threshold);
+    w.write_u8(kyc_threshold);
+    w.write_u16(max_kyc_level);
+    w.write_bytes(parent_id);
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+/// Committee update tags (`CommitteeUpdateData` discriminants).
+const COMMITTEE_UPDATE_ADD_MEMBER: u8 = 0;
+const COMMITTEE_UPDATE_REMOVE_MEMBER: u8 = 1;
+const COMMITTEE_UPDATE_THRESHOLD: u8 = 4;
+const COMMITTEE_UPDATE_SUSPEND: u8 = 7;
+
+/// Encode an UpdateCommittee payload.
+///
+/// Format: [committee_id:32][tag:u8][update data][approvals]
+///   "add_member":       tag 0, [pubkey:32][has_name:u8][len:u8][utf8]?[role:u8]
+///   "remove_member":    tag 1, [pubkey:32]
+///   "update_threshold": tag 4, [threshold:u8]
+///   "suspend":          tag 7, no data
+fn encode_update_committee_payload_inner(
+    committee_id: &[u8],
+    update_type: &str,
+    update_data: &Bound<'_, PyDict>,
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("committee_id", committee_id, 32)?;
+
+    let mut w = Writer::with_capacity(32 + 1 + 300 + 2 + approvals.len() * 104);
+    w.write_bytes(committee_id);
+
+    match update_type {
+        "add_member" => {
+            let pubkey = approval_field(update_data, "update_data", "pubkey", 32)?;
+            let name: Option<String> = match update_data.get_item("name")? {
+                Some(value) => value.extract()?,
+                None => None,
+            };
+            let role: u8 = update_data
+                .get_item("role")?
+                .ok_or_else(|| PyValueError::new_err("update_data.role: missing field"))?
+                .extract()?;
+            check_member_role("update_data.role", role)?;
+
+            w.write_u8(COMMITTEE_UPDATE_ADD_MEMBER);
+            w.write_bytes(&pubkey);
+            write_optional_string_u8(&mut w, "update_data.name", name.as_deref())?;
+            w.write_u8(role);
+        }
+        "remove_member" => {
+            let pubkey = approval_field(update_data, "update_data", "pubkey", 32)?;
+            w.write_u8(COMMITTEE_UPDATE_REMOVE_MEMBER);
+            w.write_bytes(&pubkey);
+        }
+        "update_threshold" => {
+            let threshold: u8 = update_data
+                .get_item("threshold")?
+                .ok_or_else(|| PyValueError::new_err("update_data.threshold: missing field"))?
+                .extract()?;
+            if threshold == 0 {
+                return Err(PyValueError::new_err(
+                    "update_data.threshold must be at least 1",
+                ));
+            }
+            w.write_u8(COMMITTEE_UPDATE_THRESHOLD);
+            w.write_u8(threshold);
+        }
+        "suspend" => {
+            w.write_u8(COMMITTEE_UPDATE_SUSPEND);
+        }
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "update_type: unknown update '{other}' (expected add_member, remove_member, update_threshold or suspend)"
+            )));
+        }
+    }
+
+    write_approvals(&mut w, "approvals", approvals)?;
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// Escrow payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// DepositEscrow (type 25).
+///
+/// Format: [escrow_id:32][amount:u64]
+fn encode_deposit_escrow_payload_inner(escrow_id: &[u8], amount: u64) -> PyResult<Vec<u8>> {
+    expect_len("escrow_id", escrow_id, 32)?;
+
+    let mut w = Writer::with_capacity(32 + 8);
+    w.write_bytes(escrow_id);
+    w.write_u64(amount);
+    Ok(w.into_vec())
+}
+
+/// Highest `AppealMode` discriminant (DaoGovernance = 1).
+const MAX_APPEAL_MODE: u8 = 1;
+
+/// AppealEscrow (type 31).
+///
+/// Format: [escrow_id:32][len:u16][reason:utf8][has_evidence:bool][new_evidence_hash:32]?
+///         [appeal_deposit:u64][appeal_mode:u8]
+fn encode_appeal_escrow_payload_inner(
+    escrow_id: &[u8],
+    reason: &str,
+    new_evidence_hash: Option<&[u8]>,
+    appeal_deposit: u64,
+    appeal_mode: u8,
+) -> PyResult<Vec<u8>> {
+    expect_len("escrow_id", escrow_id, 32)?;
+    if let Some(hash) = new_evidence_hash {
+        expect_len("new_evidence_hash", hash, 32)?;
+    }
+    if appeal_mode > MAX_APPEAL_MODE {
+        return Err(PyValueError::new_err(format!(
+            "appeal_mode: unknown mode {appeal_mode} (expected 0-{MAX_APPEAL_MODE})"
+        )));
+    }
+
+    let mut w = Writer::with_capacity(32 + 2 + reason.len() + 1 + 32 + 8 + 1);
+    w.write_bytes(escrow_id);
+    write_vec_u16(&mut w, "reason", reason.as_bytes())?;
+    match new_evidence_hash {
+        None => w.write_bool(false),
+        Some(hash) => {
+            w.write_bool(true);
+            w.write_bytes(hash);
+        }
+    }
+    w.write_u64(appeal_deposit);
+    w.write_u8(appeal_mode);
+    Ok(w.into_vec())
+}
+
+/// Escrow operations accepted by `sign_escrow_op`, in lifecycle order.
+const ESCROW_OPS: &str =
+    "create, deposit, release, refund, challenge, dispute, appeal, submit_verdict";
+
+/// Extract a required keyword argument for an escrow operation.
+fn escrow_kwarg<'py, T: FromPyObject<'py>>(
+    kwargs: Option<&Bound<'py, PyDict>>,
+    op_type: &str,
+    key: &str,
+) -> PyResult<T> {
+    let value = match kwargs {
+        Some(kwargs) => kwargs.get_item(key)?,
+        None => None,
+    };
+    value
+        .ok_or_else(|| PyValueError::new_err(format!("{op_type}: missing argument '{key}'")))?
+        .extract()
+}
+
+/// Encode the payload for `op_type` from keyword arguments.
+///
+/// Returns (tx_type_id, payload).
+fn encode_escrow_op_payload(
+    op_type: &str,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<(u8, Vec<u8>)> {
+    match op_type {
+        "deposit" => {
+            let escrow_id: Vec<u8> = escrow_kwarg(kwargs, op_type, "escrow_id")?;
+            let amount: u64 = escrow_kwarg(kwargs, op_type, "amount")?;
+            Ok((
+                TX_TYPE_DEPOSIT_ESCROW,
+                encode_deposit_escrow_payload_inner(&escrow_id, amount)?,
+            ))
+        }
+        "appeal" => {
+            let escrow_id: Vec<u8> = escrow_kwarg(kwargs, op_type, "escrow_id")?;
+            let reason: String = escrow_kwarg(kwargs, op_type, "reason")?;
+            let new_evidence_hash: Option<Vec<u8>> = match kwargs {
+                Some(kwargs) => match kwargs.get_item("new_evidence_hash")? {
+                    Some(value) => value.extract()?,
+                    None => None,
+                },
+                None => None,
+            };
+            let appeal_deposit: u64 = escrow_kwarg(kwargs, op_type, "appeal_deposit")?;
+            let appeal_mode: u8 = escrow_kwarg(kwargs, op_type, "appeal_mode")?;
+            Ok((
+                TX_TYPE_APPEAL_ESCROW,
+                encode_appeal_escrow_payload_inner(
+                    &escrow_id,
+                    &reason,
+                    new_evidence_hash.as_deref(),
+                    appeal_deposit,
+                    appeal_mode,
+                )?,
+            ))
+        }
+        "create" | "release" | "refund" | "challenge" | "dispute" | "submit_verdict" => {
+            Err(PyValueError::new_err(format!(
+                "op_type: '{op_type}' has no payload encoder in tos_signer yet"
+            )))
+        }
+        other => Err(PyValueError::new_err(format!(
+            "op_type: unknown escrow operation '{other}' (expected one of {ESCROW_OPS})"
+        ))),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Energy payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+const ENERGY_FREEZE_TOS: u8 = 0;
+const ENERGY_FREEZE_TOS_DELEGATE: u8 = 1;
+const ENERGY_UNFREEZE_TOS: u8 = 2;
+/// WithdrawUnfrozen has no body; its payload is the variant byte alone.
+const ENERGY_WITHDRAW_UNFROZEN: u8 = 3;
+
+/// Matches MAX_DELEGATEES in tos_spec.config.
+const MAX_DELEGATEES: usize = 500;
+
+fn energy_required<T>(variant: &str, field: &str, value: Option<T>) -> PyResult<T> {
+    value.ok_or_else(|| PyValueError::new_err(format!("{variant}: {field} is required")))
+}
+
+/// Energy payload (type 5).
+///
+/// Format:
+///   0 freeze_tos:          [0][amount:u64][duration_days:u32]
+///   1 freeze_tos_delegate: [1][count:u64]{[delegatee:32][amount:u64]}[duration_days:u32]
+///   2 unfreeze_tos:        [2][amount:u64][from_delegation:bool]
+///                          [opt record_index:u32][opt delegatee_address:32]
+///   3 withdraw_unfrozen:   [3]
+fn encode_energy_payload_inner(
+    variant: u8,
+    amount: Option<u64>,
+    duration_days: Option<u32>,
+    delegatees: Option<&Bound<'_, PyList>>,
+    from_delegation: Option<bool>,
+    record_index: Option<u32>,
+    delegatee_address: Option<&[u8]>,
+) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(64);
+    w.write_u8(variant);
+    match variant {
+        ENERGY_FREEZE_TOS => {
+            w.write_u64(energy_required("freeze_tos", "amount", amount)?);
+            w.write_u32(energy_required(
+                "freeze_tos",
+                "duration_days",
+                duration_days,
+            )?);
+        }
+        ENERGY_FREEZE_TOS_DELEGATE => {
+            let delegatees = energy_required("freeze_tos_delegate", "delegatees", delegatees)?;
+            let duration_days =
+                energy_required("freeze_tos_delegate", "duration_days", duration_days)?;
+            if delegatees.len() > MAX_DELEGATEES {
+                return Err(PyValueError::new_err(format!(
+                    "freeze_tos_delegate: at most {MAX_DELEGATEES} delegatees, got {}",
+                    delegatees.len()
+                )));
+            }
+            w.write_u64(delegatees.len() as u64);
+            for (i, item) in delegatees.iter().enumerate() {
+                let (delegatee, delegated): (Vec<u8>, u64) = item.extract().map_err(|_| {
+                    PyValueError::new_err(format!(
+                        "freeze_tos_delegate: delegatees[{i}] must be a (pubkey, amount) tuple"
+                    ))
+                })?;
+                expect_len(&format!("delegatees[{i}].pubkey"), &delegatee, 32)?;
+                w.write_bytes(&delegatee);
+                w.write_u64(delegated);
+            }
+            w.write_u32(duration_days);
+        }
+        ENERGY_UNFREEZE_TOS => {
+            w.write_u64(energy_required("unfreeze_tos", "amount", amount)?);
+            w.write_bool(energy_required(
+                "unfreeze_tos",
+                "from_delegation",
+                from_delegation,
+            )?);
+            match record_index {
+                None => w.write_bool(false),
+                Some(index) => {
+                    w.write_bool(true);
+                    w.write_u32(index);
+                }
+            }
+            match delegatee_address {
+                None => w.write_bool(false),
+                Some(address) => {
+                    expect_len("delegatee_address", address, 32)?;
+                    w.write_bool(true);
+                    w.write_bytes(address);
+                }
+            }
+        }
+        ENERGY_WITHDRAW_UNFROZEN => {}
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "unknown energy payload variant {other}, expected 0..=3"
+            )))
+        }
+    }
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// Agent account payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// AgentAccount (type 23) variant names, indexed by discriminant.
+const AGENT_VARIANTS: [&str; 8] = [
+    "register",
+    "update_policy",
+    "rotate_controller",
+    "set_status",
+    "set_energy_pool",
+    "set_session_key_root",
+    "add_session_key",
+    "revoke_session_key",
+];
+
+/// Extract a required entry of an agent operation's kwargs.
+fn agent_kwarg<'py, T: FromPyObject<'py>>(
+    kwargs: &Bound<'py, PyDict>,
+    path: &str,
+    key: &str,
+) -> PyResult<T> {
+    kwargs
+        .get_item(key)?
+        .ok_or_else(|| PyValueError::new_err(format!("{path}: missing argument '{key}'")))?
+        .extract()
+}
+
+/// Extract a 32-byte entry that may be absent or None.
+fn agent_optional_hash(
+    kwargs: &Bound<'_, PyDict>,
+    path: &str,
+    key: &str,
+) -> PyResult<Option<Vec<u8>>> {
+    let value: Option<Vec<u8>> = match kwargs.get_item(key)? {
+        Some(value) => value.extract()?,
+        None => None,
+    };
+    if let Some(data) = &value {
+        expect_len(&format!("{path}.{key}"), data, 32)?;
+    }
+    Ok(value)
+}
+
+/// Encode Option<[u8; 32]>: bool flag, then the 32 bytes if present.
+fn write_optional_hash(w: &mut Writer, value: Option<&[u8]>) {
+    match value {
+        None => w.write_bool(false),
+        Some(data) => {
+            w.write_bool(true);
+            w.write_bytes(data);
+        }
+    }
+}
+
+/// Encode a list of 32-byte items as [count:u16][item:32]...
+fn write_hash_list(w: &mut Writer, field: &str, items: &[Vec<u8>]) -> PyResult<()> {
+    if items.len() > u16::MAX as usize {
+        return Err(PyValueError::new_err(format!(
+            "{field} must have at most 65535 entries, got {}",
+            items.len()
+        )));
+    }
+    w.write_u16(items.len() as u16);
+    for (i, item) in items.iter().enumerate() {
+        expect_len(&format!("{field}[{i}]"), item, 32)?;
+        w.write_bytes(item);
+    }
+    Ok(())
+}
+
+/// AgentAccount (type 23) payload for one operation.
+///
+/// Format: [variant:u8] followed by
+///   register:             [controller:32][policy_hash:32][opt energy_pool:32][opt session_key_root:32]
+///   update_policy:        [policy_hash:32]
+///   rotate_controller:    [new_controller:32]
+///   set_status:           [status:u8]
+///   set_energy_pool:      [opt energy_pool:32]
+///   set_session_key_root: [opt session_key_root:32]
+///   add_session_key:      [key_id:u64][public_key:32][expiry_topoheight:u64]
+///                         [max_value_per_window:u64][targets:u16 x 32][assets:u16 x 32]
+///   revoke_session_key:   [key_id:u64]
+///
+/// `path` prefixes error messages.
+fn encode_agent_operation_inner(
+    path: &str,
+    variant: &str,
+    kwargs: &Bound<'_, PyDict>,
+) -> PyResult<Vec<u8>> {
+    let hash = |key: &str| -> PyResult<Vec<u8>> {
+        let value: Vec<u8> = agent_kwarg(kwargs, path, key)?;
+        expect_len(&format!("{path}.{key}"), &value, 32)?;
+        Ok(value)
+    };
+
+    let mut w = Writer::with_capacity(1 + 32 + 32 + 33 + 33);
+    match variant {
+        "register" => {
+            w.write_u8(0);
+            w.write_bytes(&hash("controller")?);
+            w.write_bytes(&hash("policy_hash")?);
+            let energy_pool = agent_optional_hash(kwargs, path, "energy_pool")?;
+            let session_key_root = agent_optional_hash(kwargs, path, "session_key_root")?;
+            write_optional_hash(&mut w, energy_pool.as_deref());
+            write_optional_hash(&mut w, session_key_root.as_deref());
+        }
+        "update_policy" => {
+            w.write_u8(1);
+            w.write_bytes(&hash("policy_hash")?);
+        }
+        "rotate_controller" => {
+            w.write_u8(2);
+            w.write_bytes(&hash("new_controller")?);
+        }
+        "set_status" => {
+            w.write_u8(3);
+            w.write_u8(agent_kwarg(kwargs, path, "status")?);
+        }
+        "set_energy_pool" => {
+            w.write_u8(4);
+            let energy_pool = agent_optional_hash(kwargs, path, "energy_pool")?;
+            write_optional_hash(&mut w, energy_pool.as_deref());
+        }
+        "set_session_key_root" => {
+            w.write_u8(5);
+            let session_key_root = agent_optional_hash(kwargs, path, "session_key_root")?;
+            write_optional_hash(&mut w, session_key_root.as_deref());
+        }
+        "add_session_key" => {
+            let key: Bound<'_, PyDict> = agent_kwarg(kwargs, path, "key")?;
+            let key_path = format!("{path}.key");
+            let public_key: Vec<u8> = agent_kwarg(&key, &key_path, "public_key")?;
+            expect_len(&format!("{key_path}.public_key"), &public_key, 32)?;
+            let allowed_targets: Vec<Vec<u8>> = match key.get_item("allowed_targets")? {
+                Some(value) => value.extract()?,
+                None => Vec::new(),
+            };
+            let allowed_assets: Vec<Vec<u8>> = match key.get_item("allowed_assets")? {
+                Some(value) => value.extract()?,
+                None => Vec::new(),
+            };
+
+            w.write_u8(6);
+            w.write_u64(agent_kwarg(&key, &key_path, "key_id")?);
+            w.write_bytes(&public_key);
+            w.write_u64(agent_kwarg(&key, &key_path, "expiry_topoheight")?);
+            w.write_u64(agent_kwarg(&key, &key_path, "max_value_per_window")?);
+            write_hash_list(
+                &mut w,
+                &format!("{key_path}.allowed_targets"),
+                &allowed_targets,
+            )?;
+            write_hash_list(
+                &mut w,
+                &format!("{key_path}.allowed_assets"),
+                &allowed_assets,
+            )?;
+        }
+        "revoke_session_key" => {
+            w.write_u8(7);
+            w.write_u64(agent_kwarg(kwargs, path, "key_id")?);
+        }
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "{path}.variant: unknown agent operation '{other}' (expected one of {})",
+                AGENT_VARIANTS.join(", ")
+            )))
+        }
+    }
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// Privacy payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// Reject `extra_data` that does not fit its u16 length prefix.
+fn check_extra_data(extra_data: Option<&[u8]>) -> PyResult<()> {
+    if let Some(data) = extra_data {
+        if data.len() > u16::MAX as usize {
+            return Err(PyValueError::new_err(format!(
+                "extra_data must be at most 65535 bytes, got {}",
+                data.len()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// ShieldTransfers payload (type 19) with a single transfer.
+///
+/// Format: [count:u16=1][asset:32][destination:32][amount:u64][extra_data:opt]
+///         [commitment:32][receiver_handle:32][proof:96]
+fn encode_shield_payload_inner(
+    asset: &[u8],
+    destination: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    receiver_handle: &[u8],
+    proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    expect_len("asset", asset, 32)?;
+    expect_len("destination", destination, 32)?;
+    check_extra_data(extra_data)?;
+    expect_len("commitment", commitment, 32)?;
+    expect_len("receiver_handle", receiver_handle, 32)?;
+    expect_len("proof", proof, 96)?;
+
+    let extra_len = extra_data.map_or(0, |data| 2 + data.len());
+    let mut w = Writer::with_capacity(2 + 32 + 32 + 8 + 1 + extra_len + 32 + 32 + 96);
+    w.write_u16(1);
+    w.write_bytes(asset);
+    w.write_bytes(destination);
+    w.write_u64(amount);
+    w.write_optional_vec_u8(extra_data);
+    w.write_bytes(commitment);
+    w.write_bytes(receiver_handle);
+    w.write_bytes(proof);
+    Ok(w.into_vec())
+}
+
+/// UnshieldTransfers payload (type 20) with a single transfer.
+///
+/// Format: [count:u16=1][asset:32][destination:32][amount:u64][extra_data:opt]
+///         [commitment:32][sender_handle:32][ct_proof:160]
+fn encode_unshield_payload_inner(
+    asset: &[u8],
+    destination: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    sender_handle: &[u8],
+    ct_proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    expect_len("asset", asset, 32)?;
+    expect_len("destination", destination, 32)?;
+    check_extra_data(extra_data)?;
+    expect_len("commitment", commitment, 32)?;
+    expect_len("sender_handle", sender_handle, 32)?;
+    expect_len("ct_proof", ct_proof, 160)?;
+
+    let extra_len = extra_data.map_or(0, |data| 2 + data.len());
+    let mut w = Writer::with_capacity(2 + 32 + 32 + 8 + 1 + extra_len + 32 + 32 + 160);
+    w.write_u16(1);
+    w.write_bytes(asset);
+    w.write_bytes(destination);
+    w.write_u64(amount);
+    w.write_optional_vec_u8(extra_data);
+    w.write_bytes(commitment);
+    w.write_bytes(sender_handle);
+    w.write_bytes(ct_proof);
+    Ok(w.into_vec())
+}
+
+/// UnoTransfers payload (type 18) with a single transfer. The amount is
+/// hidden in the commitment, so there is no plaintext amount field.
+///
+/// Format: [count:u16=1][asset:32][destination:32][extra_data:opt]
+///         [commitment:32][sender_handle:32][receiver_handle:32][ct_proof:160]
+fn encode_uno_transfer_payload_inner(
+    asset: &[u8],
+    destination: &[u8],
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    sender_handle: &[u8],
+    receiver_handle: &[u8],
+    ct_proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    expect_len("asset", asset, 32)?;
+    expect_len("destination", destination, 32)?;
+    check_extra_data(extra_data)?;
+    expect_len("commitment", commitment, 32)?;
+    expect_len("sender_handle", sender_handle, 32)?;
+    expect_len("receiver_handle", receiver_handle, 32)?;
+    expect_len("ct_proof", ct_proof, 160)?;
+
+    let extra_len = extra_data.map_or(0, |data| 2 + data.len());
+    let mut w = Writer::with_capacity(2 + 32 + 32 + 1 + extra_len + 32 + 32 + 32 + 160);
+    w.write_u16(1);
+    w.write_bytes(asset);
+    w.write_bytes(destination);
+    w.write_optional_vec_u8(extra_data);
+    w.write_bytes(commitment);
+    w.write_bytes(sender_handle);
+    w.write_bytes(receiver_handle);
+    w.write_bytes(ct_proof);
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// Arbitration commit payload encoding (shared inner logic)
+// ---------------------------------------------------------------------------
+
+/// Write a length-prefixed byte vector: [len:u16][data].
+fn write_vec_u16(w: &mut Writer, field: &str, data: &[u8]) -> PyResult<()> {
+    if data.len() > u16::MAX as usize {
+        return Err(PyValueError::new_err(format!(
+            "{field} must be at most 65535 bytes, got {}",
+            data.len()
+        )));
+    }
+    w.write_u16(data.len() as u16);
+    w.write_bytes(data);
+    Ok(())
+}
+
+/// CommitVoteRequest (type 45).
+///
+/// Format: [request_id:32][vote_request_hash:32][coordinator_signature:64]
+///         [len:u16][vote_request_payload]
+fn encode_commit_vote_request_payload_inner(
+    request_id: &[u8],
+    vote_request_hash: &[u8],
+    coordinator_signature: &[u8],
+    vote_request_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    expect_len("request_id", request_id, 32)?;
+    expect_len("vote_request_hash", vote_request_hash, 32)?;
+    expect_len("coordinator_signature", coordinator_signature, 64)?;
+
+    let mut w = Writer::with_capacity(32 + 32 + 64 + 2 + vote_request_payload.len());
+    w.write_bytes(request_id);
+    w.write_bytes(vote_request_hash);
+    w.write_bytes(coordinator_signature);
+    write_vec_u16(&mut w, "vote_request_payload", vote_request_payload)?;
+    Ok(w.into_vec())
+}
+
+/// CommitJurorVote (type 47).
+///
+/// Format: [request_id:32][juror_pubkey:32][vote_hash:32][juror_signature:64]
+///         [len:u16][vote_payload]
+fn encode_commit_juror_vote_payload_inner(
+    request_id: &[u8],
+    juror_pubkey: &[u8],
+    vote_hash: &[u8],
+    juror_signature: &[u8],
+    vote_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    expect_len("request_id", request_id, 32)?;
+    expect_len("juror_pubkey", juror_pubkey, 32)?;
+    expect_len("vote_hash", vote_hash, 32)?;
+    expect_len("juror_signature", juror_signature, 64)?;
+
+    let mut w = Writer::with_capacity(32 + 32 + 32 + 64 + 2 + vote_payload.len());
+    w.write_bytes(request_id);
+    w.write_bytes(juror_pubkey);
+    w.write_bytes(vote_hash);
+    w.write_bytes(juror_signature);
+    write_vec_u16(&mut w, "vote_payload", vote_payload)?;
+    Ok(w.into_vec())
+}
+
+// ---------------------------------------------------------------------------
+// PyO3-exposed functions
+// ---------------------------------------------------------------------------
+
+// -- Existing Level 0 (seed-byte based) ------------------------------------
+
+#[pyfunction]
+fn get_public_key(seed_byte: u8) -> PyResult<Vec<u8>> {
+    let (_, public) = keypair_from_byte(seed_byte);
+    Ok(public.compress().as_bytes().to_vec())
+}
+
+#[pyfunction]
+fn sign_data(data: &[u8], seed_byte: u8) -> PyResult<Vec<u8>> {
+    let (private, public) = keypair_from_byte(seed_byte);
+    let compressed = public.compress();
+    let sig = sign(&private, compressed.as_bytes(), data);
+    Ok(sig.to_vec())
+}
+
+// -- Level 1: Raw private key support --------------------------------------
+
+#[pyfunction]
+fn get_public_key_from_private(private_key: &[u8]) -> PyResult<Vec<u8>> {
+    if private_key.len() != 32 {
+        return Err(PyValueError::new_err(format!(
+            "private_key must be 32 bytes, got {}",
+            private_key.len()
+        )));
+    }
+    let key: &[u8; 32] = private_key
+        .try_into()
+        .map_err(|_| PyValueError::new_err("private_key must be 32 bytes"))?;
+    let (_, public) = keypair_from_private_key_bytes(key);
+    Ok(public.compress().as_bytes().to_vec())
+}
+
+/// Derive the compressed public key for a 32-byte entropy seed.
+///
+/// The seed is reduced with `Scalar::from_bytes_mod_order`; seeds that reduce
+/// to zero (e.g. all zeros or the group order) have no public key and raise.
+#[pyfunction]
+fn get_public_key_from_entropy(entropy: &[u8]) -> PyResult<Vec<u8>> {
+    let seed: &[u8; 32] = entropy.try_into().map_err(|_| {
+        PyValueError::new_err(format!("entropy must be 32 bytes, got {}", entropy.len()))
+    })?;
+    let private = Scalar::from_bytes_mod_order(*seed);
+    if private == Scalar::from(0u64) {
+        return Err(PyValueError::new_err("entropy reduces to the zero scalar"));
+    }
+    let public = private.invert() * (*H);
+    Ok(public.compress().as_bytes().to_vec())
+}
+
+#[pyfunction]
+fn sign_with_key(data: &[u8], private_key: &[u8]) -> PyResult<Vec<u8>> {
+    if private_key.len() != 32 {
+        return Err(PyValueError::new_err(format!(
+            "private_key must be 32 bytes, got {}",
+            private_key.len()
+        )));
+    }
+    let key: &[u8; 32] = private_key
+        .try_into()
+        .map_err(|_| PyValueError::new_err("private_key must be 32 bytes"))?;
+    let (private, public) = keypair_from_private_key_bytes(key);
+    let compressed = public.compress();
+    let sig = sign(&private, compressed.as_bytes(), data);
+    Ok(sig.to_vec())
+}
+
+/// Sign each message in `messages` with one raw 32-byte private key.
+///
+/// The key is validated and the keypair derived once; signatures are returned
+/// in input order and are identical to calling `sign_with_key` per message.
+#[pyfunction]
+fn sign_with_key_batch(messages: Vec<Vec<u8>>, private_key: &[u8]) -> PyResult<Vec<Vec<u8>>> {
+    let key: &[u8; 32] = private_key.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "private_key must be 32 bytes, got {}",
+            private_key.len()
+        ))
+    })?;
+    let (private, public) = keypair_from_private_key_bytes(key);
+    let compressed = public.compress();
+    Ok(messages
+        .iter()
+        .map(|message| sign(&private, compressed.as_bytes(), message).to_vec())
+        .collect())
+}
+
+/// Verify a 64-byte signature over `data` against a 32-byte compressed public key.
+///
+/// The final `e' == e` check uses `subtle::ConstantTimeEq`, so it is
+/// constant-time with respect to the scalar values being compared. Branch-free
+/// code does not guarantee constant-time execution on every CPU
+/// microarchitecture. Returns False for malformed keys or signatures.
+#[pyfunction]
+fn verify_signature(data: &[u8], signature: &[u8], public_key: &[u8]) -> PyResult<bool> {
+    let sig: &[u8; 64] = signature.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "signature must be 64 bytes, got {}",
+            signature.len()
+        ))
+    })?;
+    let key: &[u8; 32] = public_key.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "public_key must be 32 bytes, got {}",
+            public_key.len()
+        ))
+    })?;
+    Ok(verify(key, data, sig))
+}
+
+// -- Level 2: Transaction frame assembly -----------------------------------
+
+/// Assemble the signing-bytes frame for any transaction type.
+///
+/// Layout: [version:u8][chain_id:u8][source:32][tx_type_id:u8][encoded_payload:var]
+///         [fee:u64][fee_type:u8][nonce:u64][ref_hash:32][ref_topo:u64]
+///
+/// The payload may be any C-contiguous buffer (`bytes`, `bytearray`,
+/// `memoryview`). The frame is written straight into the result `bytes`
+/// object, with no intermediate `Vec<u8>` and no conversion to a Python list.
+#[pyfunction]
+fn build_signing_bytes<'py>(
+    py: Python<'py>,
+    version: u8,
+    chain_id: u8,
+    source: &[u8],
+    tx_type_id: u8,
+    encoded_payload: PyBuffer<u8>,
+    fee: u64,
+    fee_type: u8,
+    nonce: u64,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Bound<'py, PyBytes>> {
+    expect_len("source", source, 32)?;
+    expect_len("ref_hash", ref_hash, 32)?;
+    let payload = encoded_payload
+        .as_slice(py)
+        .ok_or_else(|| PyValueError::new_err("encoded_payload must be a C-contiguous buffer"))?;
+
+    // 1 + 1 + 32 + 1 + payload + 8 + 1 + 8 + 32 + 8 = 92 + payload
+    let header_len = 1 + 1 + 32 + 1;
+    let trailer_start = header_len + payload.len();
+    PyBytes::new_bound_with(py, trailer_start + 57, |buf| {
+        buf[0] = version;
+        buf[1] = chain_id;
+        buf[2..34].copy_from_slice(source);
+        buf[34] = tx_type_id;
+        for (dst, src) in buf[header_len..trailer_start].iter_mut().zip(payload) {
+            *dst = src.get();
+        }
+
+        let trailer = &mut buf[trailer_start..];
+        trailer[0..8].copy_from_slice(&fee.to_be_bytes());
+        trailer[8] = fee_type;
+        trailer[9..17].copy_from_slice(&nonce.to_be_bytes());
+        trailer[17..49].copy_from_slice(ref_hash);
+        trailer[49..57].copy_from_slice(&ref_topo.to_be_bytes());
+        Ok(())
+    })
+}
+
+/// Same as `build_signing_bytes`, kept under its original name for callers
+/// written before `build_signing_bytes` switched to the in-place frame.
+#[pyfunction]
+fn build_signing_bytes_inplace<'py>(
+    py: Python<'py>,
+    version: u8,
+    chain_id: u8,
+    source: &[u8],
+    tx_type_id: u8,
+    encoded_payload: PyBuffer<u8>,
+    fee: u64,
+    fee_type: u8,
+    nonce: u64,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Bound<'py, PyBytes>> {
+    build_signing_bytes(
+        py,
+        version,
+        chain_id,
+        source,
+        tx_type_id,
+        encoded_payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Split a signing-bytes frame back into its fields.
+///
+/// Returns a dict with keys version, chain_id, source, tx_type_id, payload,
+/// fee, fee_type, nonce, ref_hash and ref_topo. The payload is whatever lies
+/// between the 35-byte header and the 57-byte trailer, so the frame must be
+/// at least 92 bytes; field errors report the byte offset.
+#[pyfunction]
+fn decode_signing_bytes<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    if data.len() < 92 {
+        return Err(PyValueError::new_err(format!(
+            "signing bytes must be at least 92 bytes, got {}",
+            data.len()
+        )));
+    }
+
+    let mut r = Reader::new(data);
+    let version = r.read_u8("version")?;
+    let chain_id = r.read_u8("chain_id")?;
+    let source = r.read_bytes("source", 32)?;
+    let tx_type_id = r.read_u8("tx_type_id")?;
+    let payload = r.read_bytes("payload", r.remaining() - 57)?;
+    let fee = r.read_u64("fee")?;
+    let fee_type = r.read_u8("fee_type")?;
+    let nonce = r.read_u64("nonce")?;
+    let ref_hash = r.read_bytes("ref_hash", 32)?;
+    let ref_topo = r.read_u64("ref_topo")?;
+    r.expect_end()?;
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("version", version)?;
+    dict.set_item("chain_id", chain_id)?;
+    dict.set_item("source", PyBytes::new_bound(py, source))?;
+    dict.set_item("tx_type_id", tx_type_id)?;
+    dict.set_item("payload", PyBytes::new_bound(py, payload))?;
+    dict.set_item("fee", fee)?;
+    dict.set_item("fee_type", fee_type)?;
+    dict.set_item("nonce", nonce)?;
+    dict.set_item("ref_hash", PyBytes::new_bound(py, ref_hash))?;
+    dict.set_item("ref_topo", ref_topo)?;
+    Ok(dict)
+}
+
+// -- Level 3: Payload encoding ---------------------------------------------
+
+/// Encode a list of transfers into payload bytes.
+///
+/// Each transfer is a tuple: (asset: bytes, destination: bytes, amount: int,
+///                             extra_data: Optional[bytes])
+/// 3-element tuples are accepted (extra_data defaults to None).
+///
+/// Format: [count:u16] + for each: [asset:32][dest:32][amount:u64][optional_extra_data]
+#[pyfunction]
+fn encode_transfer_payload(transfers: &Bound<'_, PyList>) -> PyResult<Vec<u8>> {
+    encode_transfer_payload_inner(transfers)
+}
+
+/// Encode a burn payload.
+///
+/// Format: [asset:32][amount:u64]
+#[pyfunction]
+fn encode_burn_payload(asset: &[u8], amount: u64) -> PyResult<Vec<u8>> {
+    encode_burn_payload_inner(asset, amount)
+}
+
+/// Decode a transfer payload produced by `encode_transfer_payload`.
+///
+/// Returns a list of (asset, destination, amount, extra_data) tuples, with
+/// extra_data None when absent. Truncation errors report the field, byte
+/// offset and expected vs. available length; a count of 0 or above
+/// MAX_TRANSFER_COUNT and trailing bytes are rejected.
+#[pyfunction]
+fn decode_transfer_payload(data: &[u8]) -> PyResult<Vec<DecodedTransfer>> {
+    decode_transfer_payload_inner(data)
+}
+
+/// Decode a burn payload produced by `encode_burn_payload`.
+///
+/// Returns (asset, amount). The payload must be exactly 40 bytes.
+#[pyfunction]
+fn decode_burn_payload(data: &[u8]) -> PyResult<(Vec<u8>, u64)> {
+    decode_burn_payload_inner(data)
+}
+
+/// Encode an optional extra_data field.
+///
+/// Format: [0x00] for None, [0x01][len:u16][data] for Some.
+#[pyfunction]
+#[pyo3(signature = (data=None))]
+fn encode_optional_extra_data(data: Option<&[u8]>) -> PyResult<Vec<u8>> {
+    if let Some(d) = data {
+        if d.len() > u16::MAX as usize {
+            return Err(PyValueError::new_err(format!(
+                "data must be at most 65535 bytes, got {}",
+                d.len()
+            )));
+        }
+    }
+    let mut w = Writer::with_capacity(3 + data.map_or(0, <[u8]>::len));
+    w.write_optional_vec_u8(data);
+    Ok(w.into_vec())
+}
+
+/// Decode an optional extra_data field produced by `encode_optional_extra_data`.
+///
+/// The input must contain exactly one encoded value; trailing bytes are rejected.
+#[pyfunction]
+fn decode_optional_extra_data(raw: &[u8]) -> PyResult<Option<Vec<u8>>> {
+    let (&flag, rest) = raw
+        .split_first()
+        .ok_or_else(|| PyValueError::new_err("raw is empty, expected an option flag"))?;
+    match flag {
+        0 => {
+            if !rest.is_empty() {
+                return Err(PyValueError::new_err(format!(
+                    "{} trailing bytes after None",
+                    rest.len()
+                )));
+            }
+            Ok(None)
+        }
+        1 => {
+            if rest.len() < 2 {
+                return Err(PyValueError::new_err(
+                    "truncated length: expected 2 bytes after flag",
+                ));
+            }
+            let len = u16::from_be_bytes([rest[0], rest[1]]) as usize;
+            let data = &rest[2..];
+            if data.len() != len {
+                return Err(PyValueError::new_err(format!(
+                    "length prefix says {len} bytes, got {}",
+                    data.len()
+                )));
+            }
+            Ok(Some(data.to_vec()))
+        }
+        other => Err(PyValueError::new_err(format!(
+            "invalid option flag {other}, expected 0 or 1"
+        ))),
+    }
+}
+
+/// Encode an unsigned LEB128 varint.
+#[pyfunction]
+fn encode_varint(value: u64) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(MAX_VARINT_LEN);
+    w.write_varint(value);
+    Ok(w.into_vec())
+}
+
+/// Decode a varint produced by `encode_varint`; trailing bytes are rejected.
+#[pyfunction]
+fn decode_varint(data: &[u8]) -> PyResult<u64> {
+    let mut r = Reader::new(data);
+    let value = r.read_varint("value")?;
+    r.expect_end()?;
+    Ok(value)
+}
+
+/// Encode a 128-bit amount as 16 big-endian bytes.
+#[pyfunction]
+fn encode_u128(value: u128) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(16);
+    w.write_u128(value);
+    Ok(w.into_vec())
+}
+
+/// Decode a 128-bit amount produced by `encode_u128`; trailing bytes are rejected.
+#[pyfunction]
+fn decode_u128(data: &[u8]) -> PyResult<u128> {
+    let mut r = Reader::new(data);
+    let value = r.read_u128("value")?;
+    r.expect_end()?;
+    Ok(value)
+}
+
+/// Encode a signed amount as 8-byte big-endian two's complement.
+#[pyfunction]
+fn encode_i64(value: i64) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(8);
+    w.write_i64(value);
+    Ok(w.into_vec())
+}
+
+/// Decode a signed amount produced by `encode_i64`; trailing bytes are rejected.
+#[pyfunction]
+fn decode_i64(data: &[u8]) -> PyResult<i64> {
+    let mut r = Reader::new(data);
+    let value = r.read_i64("value")?;
+    r.expect_end()?;
+    Ok(value)
+}
+
+/// Encode an UpdateArbiter payload.
+///
+/// Every field is optional; a presence bitfield precedes the present fields.
+/// Passing all None with deactivate=False produces the valid minimal payload
+/// `b"\x00"` (no changes).
+///
+/// Format: [flags:u8][name?][expertise_domains?][fee_basis_points:u16?]
+///         [min_escrow_value:u64?][max_escrow_value:u64?][add_stake:u64?][status:u8?]
+#[pyfunction]
+#[pyo3(signature = (
+    name=None,
+    expertise_domains=None,
+    fee_basis_points=None,
+    min_escrow_value=None,
+    max_escrow_value=None,
+    add_stake=None,
+    status=None,
+    deactivate=false,
+))]
+fn encode_update_arbiter_payload(
+    name: Option<&str>,
+    expertise_domains: Option<Vec<u8>>,
+    fee_basis_points: Option<u16>,
+    min_escrow_value: Option<u64>,
+    max_escrow_value: Option<u64>,
+    add_stake: Option<u64>,
+    status: Option<u8>,
+    deactivate: bool,
+) -> PyResult<Vec<u8>> {
+    encode_update_arbiter_payload_inner(
+        name,
+        expertise_domains.as_deref(),
+        fee_basis_points,
+        min_escrow_value,
+        max_escrow_value,
+        add_stake,
+        status,
+        deactivate,
+    )
+}
+
+/// Encode a SlashArbiter payload.
+///
+/// Each approval is a dict with `pubkey` (32 bytes), `signature` (64 bytes)
+/// and `timestamp` (int), matching the CommitteeApproval wire format.
+///
+/// Format: [committee_id:32][arbiter_pubkey:32][amount:u64][reason_hash:32]
+///         [count:u16] + for each approval: [pubkey:32][signature:64][timestamp:u64]
+#[pyfunction]
+fn encode_slash_arbiter_payload(
+    committee_id: &[u8],
+    arbiter_pubkey: &[u8],
+    amount: u64,
+    reason_hash: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    encode_slash_arbiter_payload_inner(committee_id, arbiter_pubkey, amount, reason_hash, approvals)
+}
+
+/// Encode a WithdrawArbiterStake payload (type 37).
+///
+/// `amount == 0` requests withdrawal of the full remaining stake.
+///
+/// Format: [amount:u64]
+#[pyfunction]
+fn encode_withdrawal_arbiter_stake_payload(amount: u64) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(8);
+    w.write_u64(amount);
+    Ok(w.into_vec())
+}
+
+/// Encode a RequestArbiterExit payload (type 36). The payload is empty.
+#[pyfunction]
+fn encode_request_arbiter_exit_payload() -> PyResult<Vec<u8>> {
+    Ok(Vec::new())
+}
+
+/// Encode a CancelArbiterExit payload (type 38). The payload is empty.
+#[pyfunction]
+fn encode_cancel_arbiter_exit_payload() -> PyResult<Vec<u8>> {
+    Ok(Vec::new())
+}
+
+/// Encode a single committee member entry as used in the member lists of
+/// BootstrapCommittee and RegisterCommittee payloads.
+///
+/// `role` is 0 (Chair), 1 (ViceChair) or 2 (Member).
+///
+/// Format: [pubkey:32][has_name:u8][len:u8][utf8]?[role:u8]
+#[pyfunction]
+#[pyo3(signature = (pubkey, name, role))]
+fn encode_committee_member(pubkey: &[u8], name: Option<&str>, role: u8) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(32 + 2 + name.map_or(0, str::len) + 1);
+    write_committee_member(&mut w, "member", pubkey, name, role)?;
+    Ok(w.into_vec())
+}
+
+/// Encode an AppealEscrow payload (type 31).
+///
+/// `appeal_mode` is 0 (Committee) or 1 (DaoGovernance).
+///
+/// Format: [escrow_id:32][len:u16][reason:utf8][has_evidence:bool][new_evidence_hash:32]?
+///         [appeal_deposit:u64][appeal_mode:u8]
+#[pyfunction]
+#[pyo3(signature = (
+    escrow_id,
+    reason,
+    new_evidence_hash,
+    appeal_deposit,
+    appeal_mode,
+))]
+fn encode_appeal_escrow_payload(
+    escrow_id: &[u8],
+    reason: &str,
+    new_evidence_hash: Option<&[u8]>,
+    appeal_deposit: u64,
+    appeal_mode: u8,
+) -> PyResult<Vec<u8>> {
+    encode_appeal_escrow_payload_inner(
+        escrow_id,
+        reason,
+        new_evidence_hash,
+        appeal_deposit,
+        appeal_mode,
+    )
+}
+
+/// Encode a CommitVoteRequest payload (type 45).
+///
+/// Format: [request_id:32][vote_request_hash:32][coordinator_signature:64]
+///         [len:u16][vote_request_payload]
+#[pyfunction]
+fn encode_commit_vote_request_payload(
+    request_id: &[u8],
+    vote_request_hash: &[u8],
+    coordinator_signature: &[u8],
+    vote_request_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    encode_commit_vote_request_payload_inner(
+        request_id,
+        vote_request_hash,
+        coordinator_signature,
+        vote_request_payload,
+    )
+}
+
+/// Encode a CommitJurorVote payload (type 47).
+///
+/// Format: [request_id:32][juror_pubkey:32][vote_hash:32][juror_signature:64]
+///         [len:u16][vote_payload]
+#[pyfunction]
+fn encode_commit_juror_vote_payload(
+    request_id: &[u8],
+    juror_pubkey: &[u8],
+    vote_hash: &[u8],
+    juror_signature: &[u8],
+    vote_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    encode_commit_juror_vote_payload_inner(
+        request_id,
+        juror_pubkey,
+        vote_hash,
+        juror_signature,
+        vote_payload,
+    )
+}
+
+/// Encode a single-transfer ShieldTransfers payload (type 19).
+///
+/// Format: [count:u16=1][asset:32][destination:32][amount:u64][extra_data:opt]
+///         [commitment:32][receiver_handle:32][proof:96]
+#[pyfunction]
+#[pyo3(signature = (
+    asset,
+    destination,
+    amount,
+    extra_data,
+    commitment,
+    receiver_handle,
+    proof,
+))]
+fn encode_shield_payload(
+    asset: &[u8],
+    destination: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    receiver_handle: &[u8],
+    proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    encode_shield_payload_inner(
+        asset,
+        destination,
+        amount,
+        extra_data,
+        commitment,
+        receiver_handle,
+        proof,
+    )
+}
+
+/// Encode a single-transfer UnshieldTransfers payload (type 20).
+///
+/// Format: [count:u16=1][asset:32][destination:32][amount:u64][extra_data:opt]
+///         [commitment:32][sender_handle:32][ct_proof:160]
+#[pyfunction]
+#[pyo3(signature = (
+    asset,
+    destination,
+    amount,
+    extra_data,
+    commitment,
+    sender_handle,
+    ct_proof,
+))]
+fn encode_unshield_payload(
+    asset: &[u8],
+    destination: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    sender_handle: &[u8],
+    ct_proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    encode_unshield_payload_inner(
+        asset,
+        destination,
+        amount,
+        extra_data,
+        commitment,
+        sender_handle,
+        ct_proof,
+    )
+}
+
+/// Encode a single-transfer UnoTransfers payload (type 18).
+///
+/// Format: [count:u16=1][asset:32][destination:32][extra_data:opt]
+///         [commitment:32][sender_handle:32][receiver_handle:32][ct_proof:160]
+#[pyfunction]
+#[pyo3(signature = (
+    asset,
+    destination,
+    extra_data,
+    commitment,
+    sender_handle,
+    receiver_handle,
+    ct_proof,
+))]
+fn encode_uno_transfer_payload(
+    asset: &[u8],
+    destination: &[u8],
+    extra_data: Option<&[u8]>,
+    commitment: &[u8],
+    sender_handle: &[u8],
+    receiver_handle: &[u8],
+    ct_proof: &[u8],
+) -> PyResult<Vec<u8>> {
+    encode_uno_transfer_payload_inner(
+        asset,
+        destination,
+        extra_data,
+        commitment,
+        sender_handle,
+        receiver_handle,
+        ct_proof,
+    )
+}
+
+/// Encode a SetKyc payload (type 9).
+///
+/// `approvals` holds CommitteeApprovals as `(pubkey, signature, timestamp)`
+/// tuples or dicts; each is 104 bytes on the wire.
+#[pyfunction]
+fn encode_kyc_set_payload(
+    account: &[u8],
+    level: u16,
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    encode_set_kyc_payload_inner(
+        account,
+        level,
+        verified_at,
+        data_hash,
+        committee_id,
+        approvals,
+    )
+}
+
+/// Encode a RevokeKyc payload (type 10). Approvals as in `encode_kyc_set_payload`.
+#[pyfunction]
+fn encode_kyc_revoke_payload(
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    encode_revoke_kyc_payload_inner(account, reason_hash, committee_id, approvals)
+}
+
+/// Encode a RenewKyc payload (type 11). Approvals as in `encode_kyc_set_payload`.
+#[pyfunction]
+fn encode_kyc_renew_payload(
+    account: &[u8],
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    encode_renew_kyc_payload_inner(account, verified_at, data_hash, committee_id, approvals)
+}
+
+/// Encode an Energy payload (type 5).
+///
+/// Variant 0 (FreezeTos) takes amount and duration_days; 1 (FreezeTosDelegate)
+/// takes delegatees as (pubkey, amount) tuples and duration_days; 2
+/// (UnfreezeTos) takes amount, from_delegation and the optional record_index
+/// and delegatee_address; 3 (WithdrawUnfrozen) takes nothing.
+#[pyfunction]
+#[pyo3(signature = (
+    variant,
+    amount=None,
+    duration_days=None,
+    delegatees=None,
+    from_delegation=None,
+    record_index=None,
+    delegatee_address=None,
+))]
+fn encode_energy_payload(
+    variant: u8,
+    amount: Option<u64>,
+    duration_days: Option<u32>,
+    delegatees: Option<&Bound<'_, PyList>>,
+    from_delegation: Option<bool>,
+    record_index: Option<u32>,
+    delegatee_address: Option<&[u8]>,
+) -> PyResult<Vec<u8>> {
+    encode_energy_payload_inner(
+        variant,
+        amount,
+        duration_days,
+        delegatees,
+        from_delegation,
+        record_index,
+        delegatee_address,
+    )
+}
+
+/// TNS name length bounds in bytes; match MIN/MAX_NAME_LENGTH in tos_spec.config.
+const MIN_NAME_LENGTH: usize = 3;
+const MAX_NAME_LENGTH: usize = 64;
+
+/// Encode a TNS RegisterName payload (type 21): `[len:u8][name]`.
+///
+/// Rejects names outside 3-64 bytes. Charset and reserved-name rules are
+/// consensus checks and are not enforced; `sign_register_name` skips the
+/// length check so invalid names can still be signed for negative vectors.
+#[pyfunction]
+fn encode_tns_register_payload(name: &str) -> PyResult<Vec<u8>> {
+    if !(MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&name.len()) {
+        return Err(PyValueError::new_err(format!(
+            "name must be {MIN_NAME_LENGTH}-{MAX_NAME_LENGTH} bytes, got {}",
+            name.len()
+        )));
+    }
+    encode_register_name_payload(name)
+}
+
+/// Encode an AgentAccount payload (type 23) by variant discriminant.
+///
+/// `variant` is 0-7 (register through revoke_session_key); keyword arguments
+/// are that variant's fields, as for `encode_agent_batch`.
+#[pyfunction]
+#[pyo3(signature = (variant, **kwargs))]
+fn encode_agent_account_payload(
+    py: Python<'_>,
+    variant: u8,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<u8>> {
+    let name = AGENT_VARIANTS.get(variant as usize).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "variant: unknown agent account variant {variant} (expected 0-{})",
+            AGENT_VARIANTS.len() - 1
+        ))
+    })?;
+    let kwargs = match kwargs {
+        Some(kwargs) => kwargs.clone(),
+        None => PyDict::new_bound(py),
+    };
+    encode_agent_operation_inner(name, name, &kwargs)
+}
+
+/// Encode AgentAccount payloads (type 23) for a sequence of operations.
+///
+/// Each operation is a dict with `variant` and an optional `kwargs` dict holding
+/// that variant's fields. Returns one payload per operation, in order; each is
+/// meant for its own transaction.
+#[pyfunction]
+fn encode_agent_batch(operations: Vec<Bound<'_, PyDict>>) -> PyResult<Vec<Vec<u8>>> {
+    operations
+        .iter()
+        .enumerate()
+        .map(|(i, operation)| {
+            let path = format!("operations[{i}]");
+            let variant: String = agent_kwarg(operation, &path, "variant")?;
+            let kwargs = match operation.get_item("kwargs")? {
+                Some(value) => value.downcast_into::<PyDict>()?,
+                None => PyDict::new_bound(operation.py()),
+            };
+            encode_agent_operation_inner(&path, &variant, &kwargs)
+        })
+        .collect()
+}
+
+// -- Level 4: All-in-one convenience ---------------------------------------
 
 /// Build and sign a transfer transaction in one call.
 ///
 /// Returns the 64-byte signature.
 #[pyfunction]
-fn sign_transfer(
+fn sign_transfer(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    transfers: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    // Encode the transfer payload
+    let payload = encode_transfer_payload_inner(transfers)?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_TRANSFERS,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a burn transaction (type 0) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_burn(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    asset: &[u8],
+    amount: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_burn_payload_inner(asset, amount)?;
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_BURN,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_burn`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_burn_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    asset: &[u8],
+    amount: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_burn_payload_inner(asset, amount)?;
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_BURN,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign an UpdateArbiter transaction (type 34) in one call.
+///
+/// Keyword arguments match `encode_update_arbiter_payload`. Returns the
+/// 64-byte signature.
+#[pyfunction]
+#[pyo3(signature = (
+    seed_byte,
+    chain_id,
+    nonce,
+    fee,
+    fee_type,
+    ref_hash,
+    ref_topo,
+    *,
+    name=None,
+    expertise_domains=None,
+    fee_basis_points=None,
+    min_escrow_value=None,
+    max_escrow_value=None,
+    add_stake=None,
+    status=None,
+    deactivate=false,
+))]
+fn sign_update_arbiter(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    name: Option<&str>,
+    expertise_domains: Option<Vec<u8>>,
+    fee_basis_points: Option<u16>,
+    min_escrow_value: Option<u64>,
+    max_escrow_value: Option<u64>,
+    add_stake: Option<u64>,
+    status: Option<u8>,
+    deactivate: bool,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_update_arbiter_payload_inner(
+        name,
+        expertise_domains.as_deref(),
+        fee_basis_points,
+        min_escrow_value,
+        max_escrow_value,
+        add_stake,
+        status,
+        deactivate,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_UPDATE_ARBITER,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a SlashArbiter transaction (type 35) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_slash_arbiter(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_id: &[u8],
+    arbiter_pubkey: &[u8],
+    amount: u64,
+    reason_hash: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_slash_arbiter_payload_inner(
+        committee_id,
+        arbiter_pubkey,
+        amount,
+        reason_hash,
+        approvals,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_SLASH_ARBITER,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a SetKyc transaction (type 9) in one call.
+///
+/// Each approval is a dict with `pubkey`, `signature` and `timestamp`.
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_set_kyc(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    level: u16,
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_set_kyc_payload_inner(
+        account,
+        level,
+        verified_at,
+        data_hash,
+        committee_id,
+        approvals,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_SET_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_set_kyc`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_set_kyc_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    level: u16,
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_set_kyc_payload_inner(
+        account,
+        level,
+        verified_at,
+        data_hash,
+        committee_id,
+        approvals,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_SET_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a RevokeKyc transaction (type 10) in one call.
+///
+/// Each approval is a dict with `pubkey`, `signature` and `timestamp`.
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_revoke_kyc(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_revoke_kyc_payload_inner(account, reason_hash, committee_id, approvals)?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_REVOKE_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_revoke_kyc`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_revoke_kyc_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_revoke_kyc_payload_inner(account, reason_hash, committee_id, approvals)?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_REVOKE_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign an EmergencySuspend transaction (type 15) in one call.
+///
+/// Each approval is a dict with `pubkey`, `signature` and `timestamp`.
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_emergency_suspend(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+    expires_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_emergency_suspend_payload_inner(
+        account,
+        reason_hash,
+        committee_id,
+        approvals,
+        expires_at,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_EMERGENCY_SUSPEND,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_emergency_suspend`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_emergency_suspend_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    reason_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+    expires_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_emergency_suspend_payload_inner(
+        account,
+        reason_hash,
+        committee_id,
+        approvals,
+        expires_at,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_EMERGENCY_SUSPEND,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a TransferKyc transaction (type 16) in one call.
+///
+/// Both the source and destination committees supply their own approval
+/// lists. Returns the 64-byte signature.
+#[pyfunction]
+fn sign_transfer_kyc(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    source_committee_id: &[u8],
+    source_approvals: &Bound<'_, PyList>,
+    dest_committee_id: &[u8],
+    dest_approvals: &Bound<'_, PyList>,
+    new_data_hash: &[u8],
+    transferred_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_transfer_kyc_payload_inner(
+        account,
+        source_committee_id,
+        source_approvals,
+        dest_committee_id,
+        dest_approvals,
+        new_data_hash,
+        transferred_at,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_TRANSFER_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_transfer_kyc`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_transfer_kyc_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    source_committee_id: &[u8],
+    source_approvals: &Bound<'_, PyList>,
+    dest_committee_id: &[u8],
+    dest_approvals: &Bound<'_, PyList>,
+    new_data_hash: &[u8],
+    transferred_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_transfer_kyc_payload_inner(
+        account,
+        source_committee_id,
+        source_approvals,
+        dest_committee_id,
+        dest_approvals,
+        new_data_hash,
+        transferred_at,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_TRANSFER_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign an AppealKyc transaction (type 17) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_appeal_kyc(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    original_committee_id: &[u8],
+    parent_committee_id: &[u8],
+    reason_hash: &[u8],
+    documents_hash: &[u8],
+    submitted_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_appeal_kyc_payload_inner(
+        account,
+        original_committee_id,
+        parent_committee_id,
+        reason_hash,
+        documents_hash,
+        submitted_at,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_APPEAL_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_appeal_kyc`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_appeal_kyc_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    original_committee_id: &[u8],
+    parent_committee_id: &[u8],
+    reason_hash: &[u8],
+    documents_hash: &[u8],
+    submitted_at: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_appeal_kyc_payload_inner(
+        account,
+        original_committee_id,
+        parent_committee_id,
+        reason_hash,
+        documents_hash,
+        submitted_at,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_APPEAL_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a BootstrapCommittee transaction (type 12) in one call.
+///
+/// Each member is a dict with `pubkey`, optional `name` and `role`.
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_bootstrap_committee(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_name: &str,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_bootstrap_committee_payload_inner(
+        committee_name,
+        members,
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_BOOTSTRAP_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_bootstrap_committee`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_bootstrap_committee_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_name: &str,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_bootstrap_committee_payload_inner(
+        committee_name,
+        members,
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_BOOTSTRAP_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a RegisterCommittee transaction (type 13) in one call.
+///
+/// Members are encoded as in `sign_bootstrap_committee`; approvals come from
+/// the parent committee. Returns the 64-byte signature.
+#[pyfunction]
+fn sign_register_committee(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_name: &str,
+    region: u8,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+    parent_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_register_committee_payload_inner(
+        committee_name,
+        region,
+        members,
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+        parent_id,
+        approvals,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_REGISTER_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_register_committee`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_register_committee_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_name: &str,
+    region: u8,
+    members: &Bound<'_, PyList>,
+    threshold: u8,
+    kyc_threshold: u8,
+    max_kyc_level: u16,
+    parent_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_register_committee_payload_inner(
+        committee_name,
+        region,
+        members,
+        threshold,
+        kyc_threshold,
+        max_kyc_level,
+        parent_id,
+        approvals,
+    )?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_REGISTER_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign an UpdateCommittee transaction (type 14) in one call.
+///
+/// `update_type` is one of `"add_member"` (`update_data`: `pubkey`, optional
+/// `name`, `role`), `"remove_member"` (`pubkey`), `"update_threshold"`
+/// (`threshold`) or `"suspend"` (no data). Returns the 64-byte signature.
+#[pyfunction]
+fn sign_update_committee(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_id: &[u8],
+    update_type: &str,
+    update_data: &Bound<'_, PyDict>,
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload =
+        encode_update_committee_payload_inner(committee_id, update_type, update_data, approvals)?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_UPDATE_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_update_committee`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_update_committee_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    committee_id: &[u8],
+    update_type: &str,
+    update_data: &Bound<'_, PyDict>,
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload =
+        encode_update_committee_payload_inner(committee_id, update_type, update_data, approvals)?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_UPDATE_COMMITTEE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a RenewKyc transaction (type 11) in one call.
+///
+/// Each approval is a dict with `pubkey`, `signature` and `timestamp`.
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_renew_kyc(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload =
+        encode_renew_kyc_payload_inner(account, verified_at, data_hash, committee_id, approvals)?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_RENEW_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_renew_kyc`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_renew_kyc_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    account: &[u8],
+    verified_at: u64,
+    data_hash: &[u8],
+    committee_id: &[u8],
+    approvals: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    let payload =
+        encode_renew_kyc_payload_inner(account, verified_at, data_hash, committee_id, approvals)?;
+
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_RENEW_KYC,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a DepositEscrow transaction (type 25) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_deposit_escrow(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    escrow_id: &[u8],
+    amount: u64,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_deposit_escrow_payload_inner(escrow_id, amount)?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_DEPOSIT_ESCROW,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign an AppealEscrow transaction (type 31) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+#[pyo3(signature = (
+    seed_byte,
+    chain_id,
+    nonce,
+    fee,
+    fee_type,
+    ref_hash,
+    ref_topo,
+    escrow_id,
+    reason,
+    new_evidence_hash,
+    appeal_deposit,
+    appeal_mode,
+))]
+fn sign_appeal_escrow(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    escrow_id: &[u8],
+    reason: &str,
+    new_evidence_hash: Option<&[u8]>,
+    appeal_deposit: u64,
+    appeal_mode: u8,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_appeal_escrow_payload_inner(
+        escrow_id,
+        reason,
+        new_evidence_hash,
+        appeal_deposit,
+        appeal_mode,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_APPEAL_ESCROW,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign any escrow transaction, selected by `op_type`.
+///
+/// Keyword arguments are those of the matching payload encoder:
+/// `deposit` takes `escrow_id`, `amount`; `appeal` takes `escrow_id`,
+/// `reason`, `new_evidence_hash` (optional), `appeal_deposit`, `appeal_mode`.
+/// The remaining operations are recognised but not yet encodable.
+/// Returns the 64-byte signature.
+#[pyfunction]
+#[pyo3(signature = (
+    seed_byte,
+    chain_id,
+    nonce,
+    fee,
+    fee_type,
+    ref_hash,
+    ref_topo,
+    op_type,
+    **kwargs,
+))]
+fn sign_escrow_op(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    op_type: &str,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<Vec<u8>> {
+    let (tx_type_id, payload) = encode_escrow_op_payload(op_type, kwargs)?;
+
+    sign_payload(
+        seed_byte, chain_id, tx_type_id, &payload, fee, fee_type, nonce, ref_hash, ref_topo,
+    )
+}
+
+/// Build and sign an Energy WithdrawUnfrozen transaction (type 5) in one call.
+///
+/// The payload is the single variant byte `0x03`. Returns the 64-byte signature.
+#[pyfunction]
+fn sign_withdraw_unfrozen(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_ENERGY,
+        &[ENERGY_WITHDRAW_UNFROZEN],
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Like `sign_withdraw_unfrozen`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_withdraw_unfrozen_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_ENERGY,
+        &[ENERGY_WITHDRAW_UNFROZEN],
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Encode a RegisterName payload: `[len:u8][name]`.
+///
+/// Name length and charset rules are consensus checks and are not enforced here.
+fn encode_register_name_payload(tns_name: &str) -> PyResult<Vec<u8>> {
+    let mut w = Writer::with_capacity(1 + tns_name.len());
+    write_string_u8(&mut w, "tns_name", tns_name)?;
+    Ok(w.into_vec())
+}
+
+/// Build and sign a TNS RegisterName transaction (type 21) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_register_name(
     seed_byte: u8,
     chain_id: u8,
     nonce: u64,
@@ -351,39 +3630,243 @@ fn sign_transfer(
     fee_type: u8,
     ref_hash: &[u8],
     ref_topo: u64,
-    transfers: &Bound<'_, PyList>,
+    tns_name: &str,
 ) -> PyResult<Vec<u8>> {
-    if ref_hash.len() != 32 {
-        return Err(PyValueError::new_err(format!(
-            "ref_hash must be 32 bytes, got {}",
-            ref_hash.len()
-        )));
-    }
+    let payload = encode_register_name_payload(tns_name)?;
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_REGISTER_NAME,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
 
-    let (private, public) = keypair_from_byte(seed_byte);
-    let compressed = public.compress();
-    let source = compressed.as_bytes();
+/// Like `sign_register_name`, but signs with a raw 32-byte private key.
+#[pyfunction]
+fn sign_register_name_with_key(
+    private_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    tns_name: &str,
+) -> PyResult<Vec<u8>> {
+    let payload = encode_register_name_payload(tns_name)?;
+    sign_payload_with_key(
+        private_key,
+        chain_id,
+        TX_TYPE_REGISTER_NAME,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
 
-    // Encode the transfer payload
-    let payload = encode_transfer_payload_inner(transfers)?;
+/// Build and sign a RequestArbiterExit transaction (type 36) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_request_arbiter_exit(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_REQUEST_ARBITER_EXIT,
+        &[],
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
 
-    // Build the signing-bytes frame
-    // version = 1 (TxVersion::T1), tx_type_id = 1 (Transfers)
-    let mut w = Writer::with_capacity(92 + payload.len());
-    w.write_u8(1); // version T1
-    w.write_u8(chain_id);
-    w.write_bytes(source);
-    w.write_u8(1); // tx_type_id for Transfers
-    w.write_bytes(&payload);
-    w.write_u64(fee);
-    w.write_u8(fee_type);
-    w.write_u64(nonce);
-    w.write_bytes(ref_hash);
-    w.write_u64(ref_topo);
+/// Build and sign a WithdrawArbiterStake transaction (type 37) in one call.
+///
+/// `amount == 0` withdraws the full remaining stake.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_withdraw_arbiter_stake(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    amount: u64,
+) -> PyResult<Vec<u8>> {
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_WITHDRAW_ARBITER_STAKE,
+        &amount.to_be_bytes(),
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
 
-    let signing_bytes = w.into_vec();
-    let sig = sign(&private, source, &signing_bytes);
-    Ok(sig.to_vec())
+/// Build and sign a CancelArbiterExit transaction (type 38) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_cancel_arbiter_exit(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+) -> PyResult<Vec<u8>> {
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_CANCEL_ARBITER_EXIT,
+        &[],
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a CommitVoteRequest transaction (type 45) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_commit_vote_request(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    request_id: &[u8],
+    vote_request_hash: &[u8],
+    coordinator_signature: &[u8],
+    vote_request_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    let payload = encode_commit_vote_request_payload_inner(
+        request_id,
+        vote_request_hash,
+        coordinator_signature,
+        vote_request_payload,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_COMMIT_VOTE_REQUEST,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Build and sign a CommitJurorVote transaction (type 47) in one call.
+///
+/// Returns the 64-byte signature.
+#[pyfunction]
+fn sign_commit_juror_vote(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    request_id: &[u8],
+    juror_pubkey: &[u8],
+    vote_hash: &[u8],
+    juror_signature: &[u8],
+    vote_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    let payload = encode_commit_juror_vote_payload_inner(
+        request_id,
+        juror_pubkey,
+        vote_hash,
+        juror_signature,
+        vote_payload,
+    )?;
+
+    sign_payload(
+        seed_byte,
+        chain_id,
+        TX_TYPE_COMMIT_JUROR_VOTE,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
+}
+
+/// Whether `tx_type_id` is an arbitration transaction: arbiter lifecycle
+/// (33-38) or arbitration request / commit (44-47).
+fn is_arbitration_tx_type(tx_type_id: u8) -> bool {
+    matches!(tx_type_id, 33..=38 | 44..=47)
+}
+
+/// Sign an already-encoded arbitration payload in one call.
+///
+/// Covers arbitration types without a dedicated signer. `tx_type_id` must be
+/// in 33-38 or 44-47. Returns the 64-byte signature.
+#[pyfunction]
+fn sign_arbitration_transaction(
+    seed_byte: u8,
+    tx_type_id: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    encoded_payload: &[u8],
+) -> PyResult<Vec<u8>> {
+    if !is_arbitration_tx_type(tx_type_id) {
+        return Err(PyValueError::new_err(format!(
+            "tx_type_id: {tx_type_id} is not an arbitration type (expected 33-38 or 44-47)"
+        )));
+    }
+    sign_payload(
+        seed_byte,
+        chain_id,
+        tx_type_id,
+        encoded_payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )
 }
 
 // ---------------------------------------------------------------------------
@@ -397,8 +3880,17 @@ fn sign_transfer(
 #[pyfunction]
 fn make_shield_crypto(dest_seed: u8, amount: u64) -> PyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
     let (_, dest_pub) = keypair_from_byte(dest_seed);
-
     let seed = chacha_seed(b"shield-crypto", dest_seed, amount);
+    Ok(shield_crypto(&dest_pub, seed, amount))
+}
+
+/// Commitment, receiver handle and ShieldCommitmentProof for `amount` sent to
+/// `dest_pub`, with the opening and proof nonce drawn from `seed`.
+fn shield_crypto(
+    dest_pub: &RistrettoPoint,
+    seed: [u8; 32],
+    amount: u64,
+) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
     let mut rng = ChaCha20Rng::from_seed(seed);
 
     // Pedersen opening (deterministic scalar r)
@@ -409,7 +3901,7 @@ fn make_shield_crypto(dest_seed: u8, amount: u64) -> PyResult<(Vec<u8>, Vec<u8>,
     let commitment = &x * &*G + &r * &*H;
 
     // Receiver handle D = r * P_dest
-    let receiver_handle = &r * &dest_pub;
+    let receiver_handle = &r * dest_pub;
 
     // Shield commitment proof using fresh transcript (matches daemon verifier)
     let mut transcript = Transcript::new(b"shield_commitment_proof");
@@ -419,7 +3911,7 @@ fn make_shield_crypto(dest_seed: u8, amount: u64) -> PyResult<(Vec<u8>, Vec<u8>,
     // Deterministic nonce k
     let k = Scalar::random(&mut rng);
     let y_h = (&k * &*H).compress();
-    let y_p = (&k * &dest_pub).compress();
+    let y_p = (&k * dest_pub).compress();
 
     // Append proof commitments to transcript
     transcript.append_message(b"Y_H", y_h.as_bytes());
@@ -453,7 +3945,314 @@ fn make_shield_crypto(dest_seed: u8, amount: u64) -> PyResult<(Vec<u8>, Vec<u8>,
     proof.extend_from_slice(y_p.as_bytes());
     proof.extend_from_slice(z.as_bytes());
 
-    Ok((commitment_bytes, handle_bytes, proof))
+    (commitment_bytes, handle_bytes, proof)
+}
+
+/// Build the shield crypto for `dest_pub`, encode the payload and sign it.
+fn sign_shield_transfer_with_keypair(
+    private: &Scalar,
+    public: &RistrettoPoint,
+    dest_pub: &RistrettoPoint,
+    seed: [u8; 32],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    asset: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+) -> PyResult<(Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)> {
+    let (commitment, receiver_handle, proof) = shield_crypto(dest_pub, seed, amount);
+    let payload = encode_shield_payload_inner(
+        asset,
+        dest_pub.compress().as_bytes(),
+        amount,
+        extra_data,
+        &commitment,
+        &receiver_handle,
+        &proof,
+    )?;
+    let signature = sign_payload_with_keypair(
+        private,
+        public,
+        chain_id,
+        TX_TYPE_SHIELD_TRANSFERS,
+        &payload,
+        fee,
+        fee_type,
+        nonce,
+        ref_hash,
+        ref_topo,
+    )?;
+    Ok((signature, commitment, receiver_handle, proof))
+}
+
+/// Build and sign a single-transfer ShieldTransfers transaction (type 19) in one call.
+///
+/// The crypto is the same as `make_shield_crypto(dest_seed, amount)`.
+/// Returns (signature: 64 bytes, commitment: 32 bytes, receiver_handle: 32 bytes,
+/// proof: 96 bytes).
+#[pyfunction]
+#[pyo3(signature = (
+    seed_byte,
+    chain_id,
+    nonce,
+    fee,
+    fee_type,
+    ref_hash,
+    ref_topo,
+    dest_seed,
+    asset,
+    amount,
+    extra_data=None,
+))]
+fn sign_shield_transfer(
+    seed_byte: u8,
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    dest_seed: u8,
+    asset: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+) -> PyResult<(Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)> {
+    let (private, public) = keypair_from_byte(seed_byte);
+    let (_, dest_pub) = keypair_from_byte(dest_seed);
+    let seed = chacha_seed(b"shield-crypto", dest_seed, amount);
+    sign_shield_transfer_with_keypair(
+        &private, &public, &dest_pub, seed, chain_id, nonce, fee, fee_type, ref_hash, ref_topo,
+        asset, amount, extra_data,
+    )
+}
+
+/// Like `sign_shield_transfer`, but signs with a raw 32-byte private key.
+///
+/// `dest_key` is the receiver's compressed public key, not a private key.
+/// The opening and proof nonce are derived from `dest_key` and `amount`.
+#[pyfunction]
+#[pyo3(signature = (
+    private_key,
+    dest_key,
+    chain_id,
+    nonce,
+    fee,
+    fee_type,
+    ref_hash,
+    ref_topo,
+    asset,
+    amount,
+    extra_data=None,
+))]
+fn sign_shield_transfer_with_key(
+    private_key: &[u8],
+    dest_key: &[u8],
+    chain_id: u8,
+    nonce: u64,
+    fee: u64,
+    fee_type: u8,
+    ref_hash: &[u8],
+    ref_topo: u64,
+    asset: &[u8],
+    amount: u64,
+    extra_data: Option<&[u8]>,
+) -> PyResult<(Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)> {
+    let key: &[u8; 32] = private_key.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "private_key must be 32 bytes, got {}",
+            private_key.len()
+        ))
+    })?;
+    let (private, public) = keypair_from_private_key_bytes(key);
+    expect_len("dest_key", dest_key, 32)?;
+    let dest_pub = decompress_point(dest_key)
+        .ok_or_else(|| PyValueError::new_err("dest_key is not a valid Ristretto point"))?;
+    let label = [b"shield-crypto/dest-key".as_slice(), dest_key].concat();
+    let seed = chacha_seed(&label, 0, amount);
+    sign_shield_transfer_with_keypair(
+        &private, &public, &dest_pub, seed, chain_id, nonce, fee, fee_type, ref_hash, ref_topo,
+        asset, amount, extra_data,
+    )
+}
+
+/// Decompress a 32-byte Ristretto point; `None` if it is not a valid encoding.
+fn decompress_point(bytes: &[u8]) -> Option<RistrettoPoint> {
+    let mut compressed = [0u8; 32];
+    compressed.copy_from_slice(bytes);
+    CompressedRistretto(compressed).decompress()
+}
+
+/// Parse a 32-byte scalar, rejecting non-canonical encodings.
+fn canonical_scalar(bytes: &[u8]) -> Option<Scalar> {
+    let mut raw = [0u8; 32];
+    raw.copy_from_slice(bytes);
+    Option::<Scalar>::from(Scalar::from_canonical_bytes(raw))
+}
+
+/// Verify a 96-byte ShieldCommitmentProof produced by `make_shield_crypto`.
+///
+/// Replays the prover transcript and checks both equations:
+///   z*H == Y_H + c*(C - amount*G)
+///   z*P == Y_P + c*D
+///
+/// The amount is public in a Shield transfer and is required to open the
+/// commitment. Returns False on invalid points, a non-canonical z or an
+/// equation mismatch; raises ValueError only on wrong-length inputs.
+#[pyfunction]
+fn verify_shield_proof(
+    dest_pubkey: &[u8],
+    commitment: &[u8],
+    receiver_handle: &[u8],
+    proof: &[u8],
+    amount: u64,
+) -> PyResult<bool> {
+    expect_len("dest_pubkey", dest_pubkey, 32)?;
+    expect_len("commitment", commitment, 32)?;
+    expect_len("receiver_handle", receiver_handle, 32)?;
+    expect_len("proof", proof, 96)?;
+
+    let (Some(dest), Some(c_point), Some(d_point), Some(y_h), Some(y_p), Some(z)) = (
+        decompress_point(dest_pubkey),
+        decompress_point(commitment),
+        decompress_point(receiver_handle),
+        decompress_point(&proof[..32]),
+        decompress_point(&proof[32..64]),
+        canonical_scalar(&proof[64..]),
+    ) else {
+        return Ok(false);
+    };
+
+    let mut transcript = Transcript::new(b"shield_commitment_proof");
+    transcript.append_message(b"dom-sep", b"shield-commitment-proof");
+    transcript.append_message(b"Y_H", &proof[..32]);
+    transcript.append_message(b"Y_P", &proof[32..64]);
+    let c = {
+        let mut bytes = [0u8; 64];
+        transcript.challenge_bytes(b"c", &mut bytes);
+        Scalar::from_bytes_mod_order_wide(&bytes)
+    };
+
+    let opened = c_point - Scalar::from(amount) * (*G);
+    let commitment_ok = z * (*H) == y_h + c * opened;
+    let handle_ok = z * dest == y_p + c * d_point;
+    Ok(commitment_ok && handle_ok)
+}
+
+/// Verify a 160-byte T1 CiphertextValidityProof (`Y_0 || Y_1 || Y_2 || z_r || z_x`).
+///
+/// The transcript is `ciphertext_validity_proof` with domain separator
+/// `validity-proof`, followed by Y_0, Y_1, Y_2 and the challenge `c`:
+///   z_r*P_receiver == Y_0 + c*D_receiver   (skipped when receiver_handle is None)
+///   z_x*G + z_r*H  == Y_1 + c*C
+///   z_r*P_sender   == Y_2 + c*D_sender
+///
+/// Unshield carries no receiver handle; UnoTransfer does. Returns False on
+/// invalid points, non-canonical scalars or an equation mismatch; raises
+/// ValueError only on wrong-length inputs.
+#[pyfunction]
+#[pyo3(signature = (receiver_pubkey, sender_pubkey, commitment, sender_handle, receiver_handle, proof))]
+fn verify_ciphertext_validity_proof(
+    receiver_pubkey: &[u8],
+    sender_pubkey: &[u8],
+    commitment: &[u8],
+    sender_handle: &[u8],
+    receiver_handle: Option<&[u8]>,
+    proof: &[u8],
+) -> PyResult<bool> {
+    expect_len("receiver_pubkey", receiver_pubkey, 32)?;
+    expect_len("sender_pubkey", sender_pubkey, 32)?;
+    expect_len("commitment", commitment, 32)?;
+    expect_len("sender_handle", sender_handle, 32)?;
+    if let Some(handle) = receiver_handle {
+        expect_len("receiver_handle", handle, 32)?;
+    }
+    expect_len("proof", proof, 160)?;
+
+    let (
+        Some(receiver),
+        Some(sender),
+        Some(c_point),
+        Some(d_sender),
+        Some(y_0),
+        Some(y_1),
+        Some(y_2),
+        Some(z_r),
+        Some(z_x),
+    ) = (
+        decompress_point(receiver_pubkey),
+        decompress_point(sender_pubkey),
+        decompress_point(commitment),
+        decompress_point(sender_handle),
+        decompress_point(&proof[..32]),
+        decompress_point(&proof[32..64]),
+        decompress_point(&proof[64..96]),
+        canonical_scalar(&proof[96..128]),
+        canonical_scalar(&proof[128..]),
+    )
+    else {
+        return Ok(false);
+    };
+    let d_receiver = match receiver_handle {
+        Some(handle) => match decompress_point(handle) {
+            Some(point) => Some(point),
+            None => return Ok(false),
+        },
+        None => None,
+    };
+
+    let mut transcript = Transcript::new(b"ciphertext_validity_proof");
+    transcript.append_message(b"dom-sep", b"validity-proof");
+    transcript.append_message(b"Y_0", &proof[..32]);
+    transcript.append_message(b"Y_1", &proof[32..64]);
+    transcript.append_message(b"Y_2", &proof[64..96]);
+    let c = {
+        let mut bytes = [0u8; 64];
+        transcript.challenge_bytes(b"c", &mut bytes);
+        Scalar::from_bytes_mod_order_wide(&bytes)
+    };
+
+    let receiver_ok = match d_receiver {
+        Some(d) => z_r * receiver == y_0 + c * d,
+        None => true,
+    };
+    let commitment_ok = z_x * (*G) + z_r * (*H) == y_1 + c * c_point;
+    let sender_ok = z_r * sender == y_2 + c * d_sender;
+    Ok(receiver_ok && commitment_ok && sender_ok)
+}
+
+/// Commit to `amount` with a fresh random opening.
+///
+/// Returns (commitment: 32 bytes, opening: 32 bytes) with
+/// `commitment = amount*G + r*H`. `r` comes from the OS RNG, so results are
+/// not reproducible; use `make_shield_crypto` when fixtures must be stable.
+#[pyfunction]
+fn generate_random_commitment(amount: u64) -> PyResult<(Vec<u8>, Vec<u8>)> {
+    let r = Scalar::random(&mut OsRng);
+    let commitment = &Scalar::from(amount) * &*G + &r * &*H;
+    Ok((
+        commitment.compress().as_bytes().to_vec(),
+        r.as_bytes().to_vec(),
+    ))
+}
+
+/// Compute the receiver handle `D = r * P` for an opening from
+/// `generate_random_commitment` and a 32-byte compressed public key.
+///
+/// Raises ValueError on wrong lengths, a non-canonical opening or a public
+/// key that is not a valid Ristretto point.
+#[pyfunction]
+fn generate_receiver_handle(opening: &[u8], receiver_pubkey: &[u8]) -> PyResult<Vec<u8>> {
+    expect_len("opening", opening, 32)?;
+    expect_len("receiver_pubkey", receiver_pubkey, 32)?;
+    let r = canonical_scalar(opening)
+        .ok_or_else(|| PyValueError::new_err("opening is not a canonical scalar"))?;
+    let public = decompress_point(receiver_pubkey)
+        .ok_or_else(|| PyValueError::new_err("receiver_pubkey is not a valid Ristretto point"))?;
+    Ok((&r * &public).compress().as_bytes().to_vec())
 }
 
 /// Generate a random valid compressed Ristretto point (32 bytes).
@@ -469,6 +4268,28 @@ fn random_valid_point() -> PyResult<Vec<u8>> {
     Ok(point.compress().as_bytes().to_vec())
 }
 
+/// Expose the deterministic ChaCha20 seed used by the privacy helpers.
+///
+/// seed = SHA3-512("tos-signer/chacha-seed/v1" || label || [a:u8] || [b:u64 BE])[..32]
+///
+/// `make_shield_crypto(dest_seed, amount)` seeds with
+/// `chacha_seed(b"shield-crypto", dest_seed, amount)`.
+#[pyfunction]
+#[pyo3(name = "chacha_seed")]
+fn py_chacha_seed(label: &[u8], a: u8, b: u64) -> PyResult<Vec<u8>> {
+    Ok(chacha_seed(label, a, b).to_vec())
+}
+
+/// Derive a canonical scalar from `chacha_seed(label, a, b)`.
+///
+/// Seeds a ChaCha20Rng with the 32-byte seed and draws one `Scalar::random`
+/// (64 bytes reduced mod l), i.e. the first scalar `make_shield_crypto` draws.
+#[pyfunction]
+fn scalar_from_chacha_seed(label: &[u8], a: u8, b: u64) -> PyResult<Vec<u8>> {
+    let mut rng = ChaCha20Rng::from_seed(chacha_seed(label, a, b));
+    Ok(Scalar::random(&mut rng).as_bytes().to_vec())
+}
+
 /// Generate a valid CiphertextValidityProof byte sequence (160 bytes for T1+).
 ///
 /// Contains valid compressed Ristretto points and canonical scalars that
@@ -496,6 +4317,95 @@ fn make_dummy_ct_validity_proof() -> PyResult<Vec<u8>> {
     Ok(out)
 }
 
+// ---------------------------------------------------------------------------
+// Level 6: Block hashing
+// ---------------------------------------------------------------------------
+
+/// Size of the block header extra nonce in bytes.
+const EXTRA_NONCE_SIZE: usize = 32;
+
+/// BLAKE3 over the concatenation of 32-byte hashes (tips or transactions).
+///
+/// The header stores these in an `IndexSet`, so order is preserved as given
+/// and duplicates are rejected rather than silently dropped.
+fn hash_list(field: &str, hashes: &Bound<'_, PyList>) -> PyResult<[u8; 32]> {
+    let mut seen: Vec<[u8; 32]> = Vec::with_capacity(hashes.len());
+    let mut hasher = blake3::Hasher::new();
+    for i in 0..hashes.len() {
+        let item: Vec<u8> = hashes.get_item(i)?.extract()?;
+        expect_len(&format!("{field}[{i}]"), &item, 32)?;
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&item);
+        if seen.contains(&hash) {
+            return Err(PyValueError::new_err(format!(
+                "{field}[{i}]: duplicate hash"
+            )));
+        }
+        seen.push(hash);
+        hasher.update(&hash);
+    }
+    Ok(*hasher.finalize().as_bytes())
+}
+
+/// Compute the transaction set hash (`BlockHeader::get_txs_hash`).
+///
+/// Returns BLAKE3 over the concatenated 32-byte hashes. An empty list yields
+/// BLAKE3 of empty input (`af1349b9...3262`), not the zero hash.
+#[pyfunction]
+fn compute_txs_hash(tx_hashes: &Bound<'_, PyList>) -> PyResult<Vec<u8>> {
+    Ok(hash_list("tx_hashes", tx_hashes)?.to_vec())
+}
+
+/// Compute the tips hash (`BlockHeader::get_tips_hash`).
+///
+/// Same construction as `compute_txs_hash`.
+#[pyfunction]
+fn compute_tips_hash(tip_hashes: &Bound<'_, PyList>) -> PyResult<Vec<u8>> {
+    Ok(hash_list("tip_hashes", tip_hashes)?.to_vec())
+}
+
+/// Compute a block hash directly from header fields.
+///
+/// Mirrors `gen_block_hash_vectors.rs`:
+///   tips_hash  = BLAKE3(tips...)
+///   txs_hash   = BLAKE3(tx_hashes...)
+///   work_hash  = BLAKE3([version:1][height:8][tips_hash:32][txs_hash:32])
+///   block_hash = BLAKE3([work_hash:32][timestamp:8][nonce:8][extra_nonce:32][miner:32])
+#[pyfunction]
+fn compute_block_hash_from_fields(
+    version: u8,
+    height: u64,
+    timestamp: u64,
+    nonce: u64,
+    extra_nonce: &[u8],
+    miner: &[u8],
+    tips_hashes: &Bound<'_, PyList>,
+    tx_hashes: &Bound<'_, PyList>,
+) -> PyResult<Vec<u8>> {
+    expect_len("extra_nonce", extra_nonce, EXTRA_NONCE_SIZE)?;
+    expect_len("miner", miner, 32)?;
+
+    let tips_hash = hash_list("tips_hashes", tips_hashes)?;
+    let txs_hash = hash_list("tx_hashes", tx_hashes)?;
+
+    // 1 + 8 + 32 + 32 = 73
+    let mut w = Writer::with_capacity(73);
+    w.write_u8(version);
+    w.write_u64(height);
+    w.write_bytes(&tips_hash);
+    w.write_bytes(&txs_hash);
+    let work_hash = blake3::hash(&w.into_vec());
+
+    // 32 + 8 + 8 + 32 + 32 = 112
+    let mut w = Writer::with_capacity(112);
+    w.write_bytes(work_hash.as_bytes());
+    w.write_u64(timestamp);
+    w.write_u64(nonce);
+    w.write_bytes(extra_nonce);
+    w.write_bytes(miner);
+    Ok(blake3::hash(&w.into_vec()).as_bytes().to_vec())
+}
+
 // ---------------------------------------------------------------------------
 // Module registration
 // ---------------------------------------------------------------------------
@@ -507,17 +4417,101 @@ fn tos_signer(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sign_data, m)?)?;
     // Level 1: raw private key
     m.add_function(wrap_pyfunction!(get_public_key_from_private, m)?)?;
+    m.add_function(wrap_pyfunction!(get_public_key_from_entropy, m)?)?;
     m.add_function(wrap_pyfunction!(sign_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_with_key_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_signature, m)?)?;
     // Level 2: transaction frame
     m.add_function(wrap_pyfunction!(build_signing_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(build_signing_bytes_inplace, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_signing_bytes, m)?)?;
     // Level 3: payload encoding
     m.add_function(wrap_pyfunction!(encode_transfer_payload, m)?)?;
     m.add_function(wrap_pyfunction!(encode_burn_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_transfer_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_burn_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_optional_extra_data, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_optional_extra_data, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_varint, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_varint, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_u128, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_u128, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_i64, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_i64, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_update_arbiter_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_slash_arbiter_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        encode_withdrawal_arbiter_stake_payload,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(encode_request_arbiter_exit_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_cancel_arbiter_exit_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_committee_member, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_appeal_escrow_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_commit_vote_request_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_commit_juror_vote_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_shield_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_unshield_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_uno_transfer_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_kyc_set_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_kyc_revoke_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_kyc_renew_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_energy_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_tns_register_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_agent_account_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_agent_batch, m)?)?;
     // Level 4: convenience
     m.add_function(wrap_pyfunction!(sign_transfer, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_burn, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_burn_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_update_arbiter, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_slash_arbiter, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_set_kyc, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_set_kyc_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_revoke_kyc, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_revoke_kyc_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_emergency_suspend, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_emergency_suspend_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_transfer_kyc, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_transfer_kyc_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_appeal_kyc, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_appeal_kyc_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_bootstrap_committee, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_bootstrap_committee_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_register_committee, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_register_committee_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_update_committee, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_update_committee_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_renew_kyc, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_renew_kyc_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_deposit_escrow, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_appeal_escrow, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_escrow_op, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_withdraw_unfrozen, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_withdraw_unfrozen_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_register_name, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_register_name_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_request_arbiter_exit, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_withdraw_arbiter_stake, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_cancel_arbiter_exit, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_commit_vote_request, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_commit_juror_vote, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_arbitration_transaction, m)?)?;
     // Level 5: privacy crypto
     m.add_function(wrap_pyfunction!(make_shield_crypto, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_shield_transfer, m)?)?;
+    m.add_function(wrap_pyfunction!(sign_shield_transfer_with_key, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_shield_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_ciphertext_validity_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_random_commitment, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_receiver_handle, m)?)?;
     m.add_function(wrap_pyfunction!(random_valid_point, m)?)?;
+    m.add_function(wrap_pyfunction!(py_chacha_seed, m)?)?;
+    m.add_function(wrap_pyfunction!(scalar_from_chacha_seed, m)?)?;
     m.add_function(wrap_pyfunction!(make_dummy_ct_validity_proof, m)?)?;
+    // Level 6: block hashing
+    m.add_function(wrap_pyfunction!(compute_block_hash_from_fields, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_txs_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_tips_hash, m)?)?;
     Ok(())
 }
@@ -1,5 +1,8 @@
+#![allow(clippy::useless_conversion)]
+
 use pyo3::prelude::*;
 use pyo3::types::PyModule;
+use serde::Deserialize;
 
 #[pyfunction]
 fn dump_yaml(json_str: &str) -> PyResult<String> {
@@ -9,8 +12,27 @@ fn dump_yaml(json_str: &str) -> PyResult<String> {
         .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))
 }
 
+/// Parse YAML and return it as a JSON string. Only the first document of a
+/// multi-document stream is returned; an empty stream yields `null`.
+#[pyfunction]
+fn load_yaml(yaml_str: &str) -> PyResult<String> {
+    let value = match serde_yaml::Deserializer::from_str(yaml_str).next() {
+        Some(document) => serde_json::Value::deserialize(document).map_err(|err| {
+            let message = match err.location() {
+                Some(location) => format!("line {}: {err}", location.line()),
+                None => err.to_string(),
+            };
+            pyo3::exceptions::PyValueError::new_err(message)
+        })?,
+        None => serde_json::Value::Null,
+    };
+    serde_json::to_string(&value)
+        .map_err(|err| pyo3::exceptions::PyValueError::new_err(err.to_string()))
+}
+
 #[pymodule]
 fn tos_yaml(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(dump_yaml, m)?)?;
+    m.add_function(wrap_pyfunction!(load_yaml, m)?)?;
     Ok(())
 }
@@ -60,6 +60,10 @@ class Writer:
     def write_u64(self, v: int) -> None:
         self.buf.extend(int(v).to_bytes(8, "big", signed=False))
 
+    def write_i64(self, v: int) -> None:
+        # Two's complement big-endian, matching Rust's i64::to_be_bytes().
+        self.buf.extend(int(v).to_bytes(8, "big", signed=True))
+
     def write_bytes(self, b: bytes) -> None:
         self.buf.extend(b)
 
@@ -0,0 +1,57 @@
+"""tos_codec outer-frame accessors and verify_tx_signature."""
+
+from __future__ import annotations
+
+import pytest
+
+from tos_spec.config import CHAIN_ID_DEVNET
+from tos_spec.test_accounts import ALICE, sign_transaction
+from tos_spec.types import FeeType, Transaction, TransactionType, TxVersion
+
+tos_codec = pytest.importorskip("tos_codec")
+
+
+def _burn_hex() -> str:
+    from tos_spec.codec_adapter import tx_to_serde_json
+
+    tx = Transaction(
+        version=TxVersion.T1,
+        chain_id=CHAIN_ID_DEVNET,
+        source=ALICE,
+        tx_type=TransactionType.BURN,
+        payload={"asset": bytes(32), "amount": 1000},
+        fee=100_000,
+        fee_type=FeeType.TOS,
+        nonce=7,
+        reference_hash=bytes(32),
+        reference_topoheight=0,
+        signature=bytes(64),
+    )
+    tx.signature = sign_transaction(tx)
+    return tos_codec.encode_tx(tx_to_serde_json(tx))
+
+
+def test_header_accessors() -> None:
+    hex_str = _burn_hex()
+    assert tos_codec.get_tx_type(hex_str) == 0
+    assert bytes(tos_codec.get_tx_source(hex_str)) == ALICE
+
+
+def test_trailer_accessors() -> None:
+    hex_str = _burn_hex()
+    assert tos_codec.get_tx_nonce(hex_str) == 7
+    assert tos_codec.get_tx_fee(hex_str) == (100_000, int(FeeType.TOS))
+
+
+def test_verify_tx_signature() -> None:
+    hex_str = _burn_hex()
+    assert tos_codec.verify_tx_signature(hex_str)
+
+    raw = bytearray(bytes.fromhex(hex_str))
+    raw[-1] ^= 0x01
+    assert not tos_codec.verify_tx_signature(raw.hex())
+
+
+def test_rejects_short_header() -> None:
+    with pytest.raises(ValueError, match="at least 35 bytes, got 34"):
+        tos_codec.get_tx_type("00" * 34)
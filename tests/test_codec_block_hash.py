@@ -0,0 +1,30 @@
+"""tos_codec tips / txs hashes against the block hash vectors."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+
+tos_codec = pytest.importorskip("tos_codec")
+
+VECTORS = yaml.safe_load(
+    (Path(__file__).parents[1] / "rust_generators/crypto/vectors/block_hash.yaml").read_text()
+)["test_vectors"]
+
+
+@pytest.mark.parametrize("vector", VECTORS, ids=[v["name"] for v in VECTORS])
+def test_matches_block_hash_vectors(vector: dict) -> None:
+    assert tos_codec.compute_tips_hash(vector["tips_hex"]) == vector["tips_hash_hex"]
+    assert tos_codec.compute_txs_hash(vector["txs_hashes_hex"] or []) == vector["txs_hash_hex"]
+
+
+def test_rejects_duplicate_hash() -> None:
+    with pytest.raises(ValueError, match=r"tip_hashes\[1\]: duplicate hash"):
+        tos_codec.compute_tips_hash(["11" * 32, "11" * 32])
+
+
+def test_rejects_malformed_hash() -> None:
+    with pytest.raises(ValueError, match=r"tx_hashes\[0\]: expected 64 hex characters"):
+        tos_codec.compute_txs_hash(["11" * 31])
@@ -0,0 +1,55 @@
+"""tos_codec bytes-based encode/decode match the str-based API."""
+
+from __future__ import annotations
+
+import pytest
+
+from tos_spec.codec_adapter import tx_to_serde_json
+from tos_spec.config import CHAIN_ID_DEVNET
+from tos_spec.test_accounts import ALICE, BOB, sign_transaction
+from tos_spec.types import FeeType, Transaction, TransactionType, TransferPayload, TxVersion
+
+tos_codec = pytest.importorskip("tos_codec")
+
+
+def _tx(tx_type: TransactionType, payload: object) -> Transaction:
+    tx = Transaction(
+        version=TxVersion.T1,
+        chain_id=CHAIN_ID_DEVNET,
+        source=ALICE,
+        tx_type=tx_type,
+        payload=payload,
+        fee=100_000,
+        fee_type=FeeType.TOS,
+        nonce=3,
+        reference_hash=bytes(32),
+        reference_topoheight=0,
+        signature=bytes(64),
+    )
+    tx.signature = sign_transaction(tx)
+    return tx
+
+
+@pytest.mark.parametrize(
+    "tx_type,payload",
+    [
+        (TransactionType.BURN, {"asset": bytes(32), "amount": 1000}),
+        (TransactionType.TRANSFERS, [TransferPayload(asset=bytes(32), destination=BOB, amount=5)]),
+    ],
+    ids=["burn", "transfers"],
+)
+def test_bytes_round_trip_matches_str_api(tx_type: TransactionType, payload: object) -> None:
+    json_str = tx_to_serde_json(_tx(tx_type, payload))
+
+    hex_str = tos_codec.encode_tx(json_str)
+    assert tos_codec.encode_tx_from_bytes(json_str.encode()) == hex_str
+
+    decoded = tos_codec.decode_tx_to_bytes(hex_str)
+    assert isinstance(decoded, bytes)
+    assert decoded == tos_codec.decode_tx(hex_str).encode()
+    assert tos_codec.encode_tx_from_bytes(decoded) == hex_str
+
+
+def test_encode_tx_from_bytes_rejects_invalid_json() -> None:
+    with pytest.raises(ValueError, match="JSON parse error"):
+        tos_codec.encode_tx_from_bytes(b"{not json")
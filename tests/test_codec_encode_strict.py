@@ -0,0 +1,52 @@
+"""tos_codec encode_tx_strict: encode, decode again and require the same JSON."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+
+from tos_spec.codec_adapter import tx_to_serde_json
+from tos_spec.config import CHAIN_ID_DEVNET
+from tos_spec.test_accounts import ALICE, sign_transaction
+from tos_spec.types import FeeType, Transaction, TransactionType, TxVersion
+
+tos_codec = pytest.importorskip("tos_codec")
+
+
+def _burn_json() -> dict:
+    tx = Transaction(
+        version=TxVersion.T1,
+        chain_id=CHAIN_ID_DEVNET,
+        source=ALICE,
+        tx_type=TransactionType.BURN,
+        payload={"asset": bytes(32), "amount": 1000},
+        fee=100_000,
+        fee_type=FeeType.TOS,
+        nonce=1,
+        reference_hash=bytes(32),
+        reference_topoheight=0,
+        signature=bytes(64),
+    )
+    tx.signature = sign_transaction(tx)
+    return json.loads(tx_to_serde_json(tx))
+
+
+def test_clean_round_trip_matches_encode_tx() -> None:
+    json_str = json.dumps(_burn_json())
+    assert tos_codec.encode_tx_strict(json_str) == tos_codec.encode_tx(json_str)
+
+
+def test_unknown_extra_field_is_reported() -> None:
+    obj = _burn_json()
+    obj["memo"] = "ignored by serde"
+    with pytest.raises(ValueError, match="memo"):
+        tos_codec.encode_tx_strict(json.dumps(obj))
+
+
+def test_omitted_optional_field_is_accepted() -> None:
+    obj = _burn_json()
+    assert obj.pop("multisig") is None
+    json_str = json.dumps(obj)
+    assert tos_codec.encode_tx_strict(json_str) == tos_codec.encode_tx(json_str)
+    assert json.loads(tos_codec.decode_tx(tos_codec.encode_tx(json_str)))["multisig"] is None
@@ -0,0 +1,35 @@
+"""Writer primitive encodings (big-endian, signed values two's complement)."""
+
+from __future__ import annotations
+
+import pytest
+
+from tos_spec.encoding import Writer
+
+
+def test_write_i64_minus_one() -> None:
+    w = Writer(bytearray())
+    w.write_i64(-1)
+    assert w.buf.hex() == "ffffffffffffffff"
+    assert int.from_bytes(w.buf, "big", signed=True) == -1
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "0000000000000000"),
+        (1, "0000000000000001"),
+        (-(2**63), "8000000000000000"),
+        (2**63 - 1, "7fffffffffffffff"),
+    ],
+)
+def test_write_i64_matches_to_be_bytes(value: int, expected: str) -> None:
+    w = Writer(bytearray())
+    w.write_i64(value)
+    assert w.buf.hex() == expected
+
+
+def test_write_i64_out_of_range() -> None:
+    w = Writer(bytearray())
+    with pytest.raises(OverflowError):
+        w.write_i64(2**63)
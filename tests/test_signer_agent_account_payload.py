@@ -0,0 +1,56 @@
+"""tos_signer encode_agent_account_payload by variant discriminant."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CONTROLLER = b"\x01" * 32
+POLICY = b"\x02" * 32
+POOL = b"\x03" * 32
+
+CASES = [
+    (0, "register", {"controller": CONTROLLER, "policy_hash": POLICY, "energy_pool": POOL}),
+    (1, "update_policy", {"policy_hash": POLICY}),
+    (2, "rotate_controller", {"new_controller": CONTROLLER}),
+    (3, "set_status", {"status": 1}),
+    (4, "set_energy_pool", {"energy_pool": None}),
+    (5, "set_session_key_root", {"session_key_root": POOL}),
+    (
+        6,
+        "add_session_key",
+        {
+            "key": {
+                "key_id": 9,
+                "public_key": CONTROLLER,
+                "expiry_topoheight": 100,
+                "max_value_per_window": 5,
+            }
+        },
+    ),
+    (7, "revoke_session_key", {"key_id": 9}),
+]
+
+
+@pytest.mark.parametrize("variant, name, kwargs", CASES, ids=[c[1] for c in CASES])
+def test_matches_batch_encoding(variant: int, name: str, kwargs: dict) -> None:
+    payload = bytes(tos_signer.encode_agent_account_payload(variant, **kwargs))
+    (expected,) = tos_signer.encode_agent_batch([{"variant": name, "kwargs": kwargs}])
+    assert payload == bytes(expected)
+    assert payload[0] == variant
+
+
+def test_revoke_session_key_layout() -> None:
+    payload = bytes(tos_signer.encode_agent_account_payload(7, key_id=9))
+    assert payload == b"\x07" + (9).to_bytes(8, "big")
+
+
+def test_missing_field_names_variant() -> None:
+    with pytest.raises(ValueError, match="update_policy: missing argument 'policy_hash'"):
+        tos_signer.encode_agent_account_payload(1)
+
+
+def test_rejects_unknown_variant() -> None:
+    with pytest.raises(ValueError, match=r"unknown agent account variant 8 \(expected 0-7\)"):
+        tos_signer.encode_agent_account_payload(8)
@@ -0,0 +1,89 @@
+"""tos_signer AgentAccount batch payload encoding."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CONTROLLER = b"\x01" * 32
+POLICY = b"\x02" * 32
+POOL = b"\x03" * 32
+ROOT = b"\x04" * 32
+
+
+def test_agent_setup_sequence_preserves_order() -> None:
+    payloads = tos_signer.encode_agent_batch(
+        [
+            {"variant": "register", "kwargs": {"controller": CONTROLLER, "policy_hash": POLICY}},
+            {"variant": "set_energy_pool", "kwargs": {"energy_pool": POOL}},
+            {"variant": "set_session_key_root", "kwargs": {"session_key_root": None}},
+        ]
+    )
+    assert [bytes(p) for p in payloads] == [
+        b"\x00" + CONTROLLER + POLICY + b"\x00\x00",
+        b"\x04\x01" + POOL,
+        b"\x05\x00",
+    ]
+
+
+def test_register_with_optional_fields() -> None:
+    (payload,) = tos_signer.encode_agent_batch(
+        [
+            {
+                "variant": "register",
+                "kwargs": {
+                    "controller": CONTROLLER,
+                    "policy_hash": POLICY,
+                    "energy_pool": POOL,
+                    "session_key_root": ROOT,
+                },
+            }
+        ]
+    )
+    assert bytes(payload) == b"\x00" + CONTROLLER + POLICY + b"\x01" + POOL + b"\x01" + ROOT
+
+
+def test_add_session_key() -> None:
+    key = {
+        "key_id": 9,
+        "public_key": CONTROLLER,
+        "expiry_topoheight": 1000,
+        "max_value_per_window": 5,
+        "allowed_targets": [POOL],
+    }
+    (payload,) = tos_signer.encode_agent_batch([{"variant": "add_session_key", "kwargs": {"key": key}}])
+    assert bytes(payload) == (
+        b"\x06"
+        + (9).to_bytes(8, "big")
+        + CONTROLLER
+        + (1000).to_bytes(8, "big")
+        + (5).to_bytes(8, "big")
+        + b"\x00\x01"
+        + POOL
+        + b"\x00\x00"
+    )
+
+
+def test_empty_batch() -> None:
+    assert tos_signer.encode_agent_batch([]) == []
+
+
+def test_error_names_operation_index() -> None:
+    with pytest.raises(ValueError, match=r"operations\[1\]: missing argument 'policy_hash'"):
+        tos_signer.encode_agent_batch(
+            [
+                {"variant": "set_status", "kwargs": {"status": 1}},
+                {"variant": "update_policy"},
+            ]
+        )
+
+
+def test_rejects_unknown_variant() -> None:
+    with pytest.raises(ValueError, match="unknown agent operation 'delete'"):
+        tos_signer.encode_agent_batch([{"variant": "delete"}])
+
+
+def test_rejects_bad_hash_length() -> None:
+    with pytest.raises(ValueError, match=r"operations\[0\]\.energy_pool must be 32 bytes"):
+        tos_signer.encode_agent_batch([{"variant": "set_energy_pool", "kwargs": {"energy_pool": b"\x03"}}])
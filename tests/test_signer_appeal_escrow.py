@@ -0,0 +1,81 @@
+"""tos_signer AppealEscrow (type 31) encoder and signer."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+ESCROW_ID = b"\xe5" * 32
+EVIDENCE = b"\xee" * 32
+
+
+def test_payload_layout_without_evidence() -> None:
+    payload = bytes(
+        tos_signer.encode_appeal_escrow_payload(ESCROW_ID, "late", None, 500, 0)
+    )
+    assert payload == (
+        ESCROW_ID
+        + (4).to_bytes(2, "big")
+        + b"late"
+        + b"\x00"
+        + (500).to_bytes(8, "big")
+        + b"\x00"
+    )
+
+
+def test_payload_layout_with_evidence() -> None:
+    payload = bytes(
+        tos_signer.encode_appeal_escrow_payload(ESCROW_ID, "", EVIDENCE, 1, 1)
+    )
+    assert payload == (
+        ESCROW_ID + b"\x00\x00" + b"\x01" + EVIDENCE + (1).to_bytes(8, "big") + b"\x01"
+    )
+
+
+def test_reason_length_counts_utf8_bytes() -> None:
+    payload = bytes(
+        tos_signer.encode_appeal_escrow_payload(ESCROW_ID, "é", None, 1, 0)
+    )
+    assert payload[32:34] == (2).to_bytes(2, "big")
+
+
+def test_signature_covers_frame() -> None:
+    payload = bytes(
+        tos_signer.encode_appeal_escrow_payload(ESCROW_ID, "late", EVIDENCE, 500, 1)
+    )
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, 31, payload, 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(
+        tos_signer.sign_appeal_escrow(
+            SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42,
+            ESCROW_ID, "late", EVIDENCE, 500, 1,
+        )
+    )
+    assert tos_signer.verify_signature(frame, signature, source)
+
+
+@pytest.mark.parametrize(
+    "args,match",
+    [
+        ((b"\xe5" * 31, "r", None, 1, 0), "escrow_id"),
+        ((ESCROW_ID, "r", b"\xee" * 31, 1, 0), "new_evidence_hash"),
+        ((ESCROW_ID, "r" * 65536, None, 1, 0), "reason"),
+        ((ESCROW_ID, "r", None, 1, 2), "appeal_mode"),
+    ],
+)
+def test_rejects_invalid_fields(args, match: str) -> None:
+    with pytest.raises(ValueError, match=match):
+        tos_signer.encode_appeal_escrow_payload(*args)
+
+
+def test_rejects_deposit_beyond_u64() -> None:
+    with pytest.raises(OverflowError):
+        tos_signer.encode_appeal_escrow_payload(ESCROW_ID, "r", None, 1 << 64, 0)
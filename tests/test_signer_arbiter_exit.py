@@ -0,0 +1,50 @@
+"""tos_signer RequestArbiterExit / CancelArbiterExit convenience signers."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+
+CASES = [
+    (tos_signer.sign_request_arbiter_exit, 36),
+    (tos_signer.sign_cancel_arbiter_exit, 38),
+]
+
+
+@pytest.mark.parametrize("signer,tx_type_id", CASES)
+def test_signature_covers_empty_payload_frame(signer, tx_type_id: int) -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, tx_type_id, b"", 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    assert len(frame) == 92
+
+    signature = bytes(signer(SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42))
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(frame, signature, source)
+
+
+def test_request_and_cancel_signatures_differ() -> None:
+    args = (SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+    assert bytes(tos_signer.sign_request_arbiter_exit(*args)) != bytes(
+        tos_signer.sign_cancel_arbiter_exit(*args)
+    )
+
+
+@pytest.mark.parametrize("signer,tx_type_id", CASES)
+def test_rejects_bad_ref_hash(signer, tx_type_id: int) -> None:
+    with pytest.raises(ValueError, match="ref_hash"):
+        signer(SEED, CHAIN_ID, 7, 10_000, 0, b"\x11" * 31, 42)
+
+
+@pytest.mark.parametrize("signer,tx_type_id", CASES)
+def test_rejects_ref_topo_beyond_u64(signer, tx_type_id: int) -> None:
+    with pytest.raises(OverflowError):
+        signer(SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 1 << 64)
@@ -0,0 +1,77 @@
+"""tos_signer WithdrawArbiterStake signer and the arbiter exit / stake encoders."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+AMOUNT = 250_000_000
+
+
+def test_withdraw_payload_layout() -> None:
+    assert bytes(tos_signer.encode_withdrawal_arbiter_stake_payload(AMOUNT)) == (
+        AMOUNT.to_bytes(8, "big")
+    )
+
+
+def test_withdraw_full_stake_is_zero_amount() -> None:
+    assert bytes(tos_signer.encode_withdrawal_arbiter_stake_payload(0)) == b"\x00" * 8
+
+
+@pytest.mark.parametrize(
+    "encoder",
+    [
+        tos_signer.encode_request_arbiter_exit_payload,
+        tos_signer.encode_cancel_arbiter_exit_payload,
+    ],
+)
+def test_exit_payloads_are_empty(encoder) -> None:
+    assert bytes(encoder()) == b""
+
+
+def test_withdraw_signature_covers_frame() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, 37, AMOUNT.to_bytes(8, "big"), 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    assert frame == (
+        bytes([1, CHAIN_ID])
+        + source
+        + bytes([37])
+        + AMOUNT.to_bytes(8, "big")
+        + (10_000).to_bytes(8, "big")
+        + b"\x00"
+        + (7).to_bytes(8, "big")
+        + REF_HASH
+        + (42).to_bytes(8, "big")
+    )
+
+    signature = bytes(
+        tos_signer.sign_withdraw_arbiter_stake(SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42, AMOUNT)
+    )
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(frame, signature, source)
+
+
+def test_withdraw_signature_binds_amount() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, 37, (AMOUNT + 1).to_bytes(8, "big"), 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(
+        tos_signer.sign_withdraw_arbiter_stake(SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42, AMOUNT)
+    )
+    assert not tos_signer.verify_signature(frame, signature, source)
+
+
+def test_withdraw_rejects_amount_beyond_u64() -> None:
+    with pytest.raises(OverflowError):
+        tos_signer.sign_withdraw_arbiter_stake(SEED, CHAIN_ID, 7, 10_000, 0, REF_HASH, 42, 1 << 64)
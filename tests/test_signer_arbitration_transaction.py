@@ -0,0 +1,39 @@
+"""tos_signer sign_arbitration_transaction generic signer."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+
+@pytest.mark.parametrize("tx_type_id", [33, 38, 44, 47])
+def test_signature_covers_frame(tx_type_id: int) -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    payload = b"\x01\x02\x03"
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, tx_type_id, payload, 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(
+        tos_signer.sign_arbitration_transaction(SEED, tx_type_id, *FRAME_ARGS, payload)
+    )
+    assert tos_signer.verify_signature(frame, signature, source)
+
+
+def test_matches_dedicated_signer() -> None:
+    assert tos_signer.sign_arbitration_transaction(SEED, 36, *FRAME_ARGS, b"") == (
+        tos_signer.sign_request_arbiter_exit(SEED, *FRAME_ARGS)
+    )
+
+
+@pytest.mark.parametrize("tx_type_id", [0, 32, 39, 43, 48])
+def test_rejects_non_arbitration_type(tx_type_id: int) -> None:
+    with pytest.raises(ValueError, match="is not an arbitration type"):
+        tos_signer.sign_arbitration_transaction(SEED, tx_type_id, *FRAME_ARGS, b"")
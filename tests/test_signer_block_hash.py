@@ -0,0 +1,61 @@
+"""tos_signer block, tips and txs hashes against the block hash vectors."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+
+import tos_signer
+
+# BaseLoader keeps every scalar a string; safe_load turns the all-zero
+# extra_nonce_hex of genesis_like into the integer 0.
+VECTORS = yaml.load(
+    (Path(__file__).parents[1] / "rust_generators/crypto/vectors/block_hash.yaml").read_text(),
+    Loader=yaml.BaseLoader,
+)["test_vectors"]
+
+
+def _hashes(hex_list: list[str] | None) -> list[bytes]:
+    return [bytes.fromhex(h) for h in hex_list or []]
+
+
+@pytest.mark.parametrize("vector", VECTORS, ids=[v["name"] for v in VECTORS])
+def test_matches_block_hash_vectors(vector: dict) -> None:
+    tips_hash = bytes(tos_signer.compute_tips_hash(_hashes(vector["tips_hex"])))
+    txs_hash = bytes(tos_signer.compute_txs_hash(_hashes(vector["txs_hashes_hex"])))
+    assert tips_hash.hex() == vector["tips_hash_hex"]
+    assert txs_hash.hex() == vector["txs_hash_hex"]
+
+
+@pytest.mark.parametrize("vector", VECTORS, ids=[v["name"] for v in VECTORS])
+def test_block_hash_from_fields(vector: dict) -> None:
+    block_hash = tos_signer.compute_block_hash_from_fields(
+        int(vector["version"]),
+        int(vector["height"]),
+        int(vector["timestamp"]),
+        int(vector["nonce"]),
+        bytes.fromhex(vector["extra_nonce_hex"]),
+        bytes.fromhex(vector["miner_hex"]),
+        _hashes(vector["tips_hex"]),
+        _hashes(vector["txs_hashes_hex"]),
+    )
+    assert bytes(block_hash).hex() == vector["block_hash_hex"]
+
+
+def test_block_hash_rejects_short_extra_nonce() -> None:
+    with pytest.raises(ValueError, match="extra_nonce must be 32 bytes"):
+        tos_signer.compute_block_hash_from_fields(
+            0, 1, 0, 0, b"\x00" * 31, b"\x01" * 32, [b"\x11" * 32], []
+        )
+
+
+def test_rejects_duplicate_hash() -> None:
+    with pytest.raises(ValueError, match=r"tip_hashes\[1\]: duplicate hash"):
+        tos_signer.compute_tips_hash([b"\x11" * 32, b"\x11" * 32])
+
+
+def test_rejects_wrong_length_hash() -> None:
+    with pytest.raises(ValueError, match=r"tx_hashes\[0\] must be 32 bytes"):
+        tos_signer.compute_txs_hash([b"\x11" * 31])
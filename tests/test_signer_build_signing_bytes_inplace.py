@@ -0,0 +1,47 @@
+"""tos_signer build_signing_bytes takes any buffer payload and returns bytes."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SOURCE = b"\x01" * 32
+REF_HASH = b"\x22" * 32
+
+
+def _frames(payload):
+    args = (1, 3, SOURCE, 1)
+    tail = (10_000, 0, 7, REF_HASH, 42)
+    frame = tos_signer.build_signing_bytes(*args, payload, *tail)
+    inplace_frame = tos_signer.build_signing_bytes_inplace(*args, payload, *tail)
+    return frame, inplace_frame
+
+
+@pytest.mark.parametrize(
+    "payload",
+    [b"", b"\x00", bytes(range(256)), bytearray(b"\xab" * 1024), memoryview(b"\xcd" * 10_000)],
+)
+def test_frame_layout(payload) -> None:
+    frame, inplace_frame = _frames(payload)
+    assert isinstance(frame, bytes)
+    assert inplace_frame == frame
+    assert len(frame) == 92 + len(payload)
+    assert frame[:35] == b"\x01\x03" + SOURCE + b"\x01"
+    assert frame[35:-57] == bytes(payload)
+    assert frame[-57:] == (
+        (10_000).to_bytes(8, "big") + b"\x00" + (7).to_bytes(8, "big") + REF_HASH + (42).to_bytes(8, "big")
+    )
+
+
+def test_rejects_bad_lengths() -> None:
+    with pytest.raises(ValueError, match="source"):
+        tos_signer.build_signing_bytes(1, 3, SOURCE[:31], 1, b"", 0, 0, 0, REF_HASH, 0)
+    with pytest.raises(ValueError, match="ref_hash"):
+        tos_signer.build_signing_bytes(1, 3, SOURCE, 1, b"", 0, 0, 0, REF_HASH[:31], 0)
+
+
+def test_rejects_non_contiguous_buffer() -> None:
+    strided = memoryview(b"\x00\x01" * 64)[::2]
+    with pytest.raises((ValueError, BufferError)):
+        tos_signer.build_signing_bytes(1, 3, SOURCE, 1, strided, 0, 0, 0, REF_HASH, 0)
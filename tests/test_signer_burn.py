@@ -0,0 +1,36 @@
+"""tos_signer sign_burn / sign_burn_with_key."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+PRIVATE_KEY = b"\x01" + b"\x00" * 31
+FRAME_ARGS = (CHAIN_ID, 7, 1_000, 0, REF_HASH, 42)
+ASSET = b"\x00" * 32
+
+
+def test_sign_burn_verifies_against_frame() -> None:
+    source = bytes(tos_signer.get_public_key(1))
+    payload = bytes(tos_signer.encode_burn_payload(ASSET, 500))
+    frame = tos_signer.build_signing_bytes(1, CHAIN_ID, source, 0, payload, 1_000, 0, 7, REF_HASH, 42)
+    signature = bytes(tos_signer.sign_burn(1, *FRAME_ARGS, ASSET, 500))
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(bytes(frame), signature, source)
+
+
+def test_with_key_matches_seed_byte_key() -> None:
+    assert tos_signer.sign_burn_with_key(PRIVATE_KEY, *FRAME_ARGS, ASSET, 500) == (
+        tos_signer.sign_burn(1, *FRAME_ARGS, ASSET, 500)
+    )
+
+
+@pytest.mark.parametrize("asset", [b"", b"\x00" * 31, b"\x00" * 33])
+def test_rejects_bad_asset_length(asset: bytes) -> None:
+    with pytest.raises(ValueError, match="asset must be 32 bytes"):
+        tos_signer.sign_burn(1, *FRAME_ARGS, asset, 500)
+    with pytest.raises(ValueError, match="asset must be 32 bytes"):
+        tos_signer.sign_burn_with_key(PRIVATE_KEY, *FRAME_ARGS, asset, 500)
@@ -0,0 +1,34 @@
+"""tos_signer deterministic seed derivation matches the documented algorithm."""
+
+from __future__ import annotations
+
+import hashlib
+
+import pytest
+
+import tos_signer
+
+
+def _expected_seed(label: bytes, a: int, b: int) -> bytes:
+    h = hashlib.sha3_512()
+    h.update(b"tos-signer/chacha-seed/v1")
+    h.update(label)
+    h.update(bytes([a]))
+    h.update(b.to_bytes(8, "big"))
+    return h.digest()[:32]
+
+
+@pytest.mark.parametrize(
+    "label,a,b",
+    [(b"shield-crypto", 2, 100_000_000), (b"", 0, 0), (b"random-valid-point", 255, 2**64 - 1)],
+)
+def test_chacha_seed(label: bytes, a: int, b: int) -> None:
+    assert bytes(tos_signer.chacha_seed(label, a, b)) == _expected_seed(label, a, b)
+
+
+def test_scalar_is_deterministic_and_canonical() -> None:
+    first = bytes(tos_signer.scalar_from_chacha_seed(b"label", 1, 2))
+    assert first == bytes(tos_signer.scalar_from_chacha_seed(b"label", 1, 2))
+    assert first != bytes(tos_signer.scalar_from_chacha_seed(b"label", 1, 3))
+    l = 2**252 + 27742317777372353535851937790883648493
+    assert int.from_bytes(first, "little") < l
@@ -0,0 +1,119 @@
+"""tos_signer CommitVoteRequest / CommitJurorVote encoders and signers."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+REQUEST_ID = b"\x11" * 32
+VOTE_REQUEST_HASH = b"\x22" * 32
+COORDINATOR_SIGNATURE = b"\x33" * 64
+JUROR_PUBKEY = b"\x44" * 32
+VOTE_HASH = b"\x55" * 32
+JUROR_SIGNATURE = b"\x66" * 64
+
+# BaseLoader keeps every scalar a string; safe_load turns all-digit wire_hex
+# values into integers.
+VECTORS = yaml.load(
+    (
+        Path(__file__).parents[1] / "rust_generators/crypto/vectors/arbitration_commit_length.yaml"
+    ).read_text(),
+    Loader=yaml.BaseLoader,
+)["commit_length_vectors"]
+VALID_VECTORS = [v for v in VECTORS if v["is_valid"] == "true"]
+
+
+def _encode(tx_type_id: int, blob: bytes) -> bytes:
+    if tx_type_id == 45:
+        return bytes(
+            tos_signer.encode_commit_vote_request_payload(
+                REQUEST_ID, VOTE_REQUEST_HASH, COORDINATOR_SIGNATURE, blob
+            )
+        )
+    return bytes(
+        tos_signer.encode_commit_juror_vote_payload(
+            REQUEST_ID, JUROR_PUBKEY, VOTE_HASH, JUROR_SIGNATURE, blob
+        )
+    )
+
+
+@pytest.mark.parametrize("vector", VALID_VECTORS, ids=[v["name"] for v in VALID_VECTORS])
+def test_matches_commit_length_vectors(vector: dict) -> None:
+    blob = bytes(i & 0xFF for i in range(int(vector["payload_len"])))
+    payload = _encode(int(vector["tx_type_id"]), blob)
+    assert len(payload) == int(vector["expected_size"])
+    assert payload.hex() == vector["wire_hex"]
+
+
+def test_vote_request_payload_layout() -> None:
+    assert _encode(45, b"\xaa\xbb") == (
+        REQUEST_ID + VOTE_REQUEST_HASH + COORDINATOR_SIGNATURE + b"\x00\x02\xaa\xbb"
+    )
+
+
+def test_juror_vote_payload_layout() -> None:
+    assert _encode(47, b"\xaa\xbb") == (
+        REQUEST_ID + JUROR_PUBKEY + VOTE_HASH + JUROR_SIGNATURE + b"\x00\x02\xaa\xbb"
+    )
+
+
+@pytest.mark.parametrize("tx_type_id", [45, 47])
+def test_rejects_blob_beyond_u16(tx_type_id: int) -> None:
+    with pytest.raises(ValueError, match="must be at most 65535 bytes, got 65536"):
+        _encode(tx_type_id, b"\x00" * 65536)
+
+
+def test_vote_request_rejects_short_coordinator_signature() -> None:
+    with pytest.raises(ValueError, match="coordinator_signature must be 64 bytes, got 63"):
+        tos_signer.encode_commit_vote_request_payload(
+            REQUEST_ID, VOTE_REQUEST_HASH, b"\x33" * 63, b""
+        )
+
+
+def test_juror_vote_rejects_short_juror_pubkey() -> None:
+    with pytest.raises(ValueError, match="juror_pubkey must be 32 bytes, got 31"):
+        tos_signer.encode_commit_juror_vote_payload(
+            REQUEST_ID, b"\x44" * 31, VOTE_HASH, JUROR_SIGNATURE, b""
+        )
+
+
+def _frame(tx_type_id: int, payload: bytes) -> bytes:
+    source = bytes(tos_signer.get_public_key(SEED))
+    return bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, tx_type_id, payload, 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+
+
+def test_vote_request_signature_covers_frame() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    signature = bytes(
+        tos_signer.sign_commit_vote_request(
+            SEED, *FRAME_ARGS, REQUEST_ID, VOTE_REQUEST_HASH, COORDINATOR_SIGNATURE, b"\x01"
+        )
+    )
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(_frame(45, _encode(45, b"\x01")), signature, source)
+    assert not tos_signer.verify_signature(_frame(45, _encode(45, b"\x02")), signature, source)
+
+
+def test_juror_vote_signature_covers_frame() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    signature = bytes(
+        tos_signer.sign_commit_juror_vote(
+            SEED, *FRAME_ARGS, REQUEST_ID, JUROR_PUBKEY, VOTE_HASH, JUROR_SIGNATURE, b"\x01"
+        )
+    )
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(_frame(47, _encode(47, b"\x01")), signature, source)
+    assert not tos_signer.verify_signature(_frame(45, _encode(47, b"\x01")), signature, source)
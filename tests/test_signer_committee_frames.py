@@ -0,0 +1,139 @@
+"""tos_signer committee signers: payload bytes inside the signed frame."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+CHAIR = b"\x31" * 32
+MEMBER = b"\x32" * 32
+PARENT_ID = b"\xc1" * 32
+COMMITTEE_ID = b"\xc2" * 32
+PUBKEY = b"\x24" * 32
+SIGNATURE = b"\x25" * 64
+TIMESTAMP = 1_700_000_000
+
+MEMBERS = [
+    {"pubkey": CHAIR, "name": "chair", "role": 0},
+    {"pubkey": MEMBER, "role": 2},
+]
+MEMBERS_WIRE = b"\x02" + CHAIR + b"\x01\x05chair\x00" + MEMBER + b"\x00\x02"
+APPROVALS = [(PUBKEY, SIGNATURE, TIMESTAMP)]
+APPROVALS_WIRE = b"\x00\x01" + PUBKEY + SIGNATURE + TIMESTAMP.to_bytes(8, "big")
+
+
+def _assert_signs(name: str, tx_type_id: int, payload: bytes, payload_args: tuple) -> None:
+    """The signer's signature verifies over a frame carrying exactly `payload`."""
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, tx_type_id, payload, 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(getattr(tos_signer, name)(SEED, *FRAME_ARGS, *payload_args))
+    assert tos_signer.verify_signature(frame, signature, source)
+
+    # Flipping the first payload byte (after version, chain_id, source and
+    # tx_type) must break the signature.
+    tampered = bytearray(frame)
+    tampered[35] ^= 0x01
+    assert not tos_signer.verify_signature(bytes(tampered), signature, source)
+
+
+def test_members_match_committee_member_encoder() -> None:
+    assert MEMBERS_WIRE == (
+        b"\x02"
+        + bytes(tos_signer.encode_committee_member(CHAIR, "chair", 0))
+        + bytes(tos_signer.encode_committee_member(MEMBER, None, 2))
+    )
+
+
+def test_bootstrap_committee_payload() -> None:
+    payload = b"\x06Global" + MEMBERS_WIRE + b"\x02\x01" + (32767).to_bytes(2, "big")
+    _assert_signs("sign_bootstrap_committee", 12, payload, ("Global", MEMBERS, 2, 1, 32767))
+
+
+def test_register_committee_payload() -> None:
+    payload = (
+        b"\x04APAC"
+        + b"\x01"
+        + MEMBERS_WIRE
+        + b"\x01\x01"
+        + (255).to_bytes(2, "big")
+        + PARENT_ID
+        + APPROVALS_WIRE
+    )
+    _assert_signs(
+        "sign_register_committee",
+        13,
+        payload,
+        ("APAC", 1, MEMBERS, 1, 1, 255, PARENT_ID, APPROVALS),
+    )
+
+
+@pytest.mark.parametrize(
+    ("update_type", "update_data", "update_wire"),
+    [
+        (
+            "add_member",
+            {"pubkey": MEMBER, "name": "bob", "role": 1},
+            b"\x00" + MEMBER + b"\x01\x03bob\x01",
+        ),
+        ("remove_member", {"pubkey": MEMBER}, b"\x01" + MEMBER),
+        ("update_threshold", {"threshold": 3}, b"\x04\x03"),
+        ("suspend", {}, b"\x07"),
+    ],
+)
+def test_update_committee_payload(update_type: str, update_data: dict, update_wire: bytes) -> None:
+    payload = COMMITTEE_ID + update_wire + APPROVALS_WIRE
+    _assert_signs(
+        "sign_update_committee", 14, payload, (COMMITTEE_ID, update_type, update_data, APPROVALS)
+    )
+
+
+@pytest.mark.parametrize(
+    ("update_type", "update_data", "message"),
+    [
+        ("rename", {}, "update_type: unknown update 'rename'"),
+        ("update_threshold", {"threshold": 0}, "update_data.threshold must be at least 1"),
+        (
+            "add_member",
+            {"pubkey": MEMBER, "role": 3},
+            r"update_data.role: unknown member role 3 \(expected 0-2\)",
+        ),
+    ],
+)
+def test_update_committee_rejects(update_type: str, update_data: dict, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        tos_signer.sign_update_committee(
+            SEED, *FRAME_ARGS, COMMITTEE_ID, update_type, update_data, APPROVALS
+        )
+
+
+@pytest.mark.parametrize(
+    ("threshold", "kyc_threshold", "message"),
+    [
+        (0, 1, r"threshold must be between 1 and the member count \(2\), got 0"),
+        (3, 1, r"threshold must be between 1 and the member count \(2\), got 3"),
+        (1, 3, r"kyc_threshold must be between 1 and the member count \(2\), got 3"),
+    ],
+)
+def test_bootstrap_rejects_threshold(threshold: int, kyc_threshold: int, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        tos_signer.sign_bootstrap_committee(
+            SEED, *FRAME_ARGS, "Global", MEMBERS, threshold, kyc_threshold, 32767
+        )
+
+
+def test_register_rejects_unknown_member_role() -> None:
+    members = [{"pubkey": CHAIR, "role": 3}]
+    with pytest.raises(ValueError, match=r"members\[0\]\.role: unknown member role 3"):
+        tos_signer.sign_register_committee(
+            SEED, *FRAME_ARGS, "APAC", 1, members, 1, 1, 255, PARENT_ID, APPROVALS
+        )
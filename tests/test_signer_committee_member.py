@@ -0,0 +1,40 @@
+"""tos_signer.encode_committee_member."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+PUBKEY = b"\x11" * 32
+
+
+def test_without_name() -> None:
+    assert bytes(tos_signer.encode_committee_member(PUBKEY, None, 2)) == (
+        PUBKEY + b"\x00" + b"\x02"
+    )
+
+
+def test_with_name() -> None:
+    assert bytes(tos_signer.encode_committee_member(PUBKEY, "chair", 0)) == (
+        PUBKEY + b"\x01" + b"\x05" + b"chair" + b"\x00"
+    )
+
+
+def test_empty_name_is_present() -> None:
+    assert bytes(tos_signer.encode_committee_member(PUBKEY, "", 1)) == (
+        PUBKEY + b"\x01\x00" + b"\x01"
+    )
+
+
+@pytest.mark.parametrize(
+    "args,match",
+    [
+        ((b"\x11" * 31, None, 0), "member.pubkey must be 32 bytes"),
+        ((PUBKEY, None, 3), r"member.role: unknown member role 3 \(expected 0-2\)"),
+        ((PUBKEY, "x" * 256, 0), "member.name: must be at most 255 bytes"),
+    ],
+)
+def test_rejects_invalid_fields(args, match: str) -> None:
+    with pytest.raises(ValueError, match=match):
+        tos_signer.encode_committee_member(*args)
@@ -0,0 +1,43 @@
+"""tos_signer CiphertextValidityProof verifier input handling."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+
+def _inputs():
+    receiver = bytes(tos_signer.get_public_key(2))
+    sender = bytes(tos_signer.get_public_key(1))
+    point = bytes(tos_signer.random_valid_point())
+    proof = bytes(tos_signer.make_dummy_ct_validity_proof())
+    return receiver, sender, point, proof
+
+
+@pytest.mark.parametrize("with_receiver_handle", [False, True])
+def test_dummy_proof_does_not_verify(with_receiver_handle: bool) -> None:
+    receiver, sender, point, proof = _inputs()
+    handle = point if with_receiver_handle else None
+    assert not tos_signer.verify_ciphertext_validity_proof(
+        receiver, sender, point, point, handle, proof
+    )
+
+
+def test_invalid_point_is_false() -> None:
+    receiver, sender, point, proof = _inputs()
+    assert not tos_signer.verify_ciphertext_validity_proof(
+        receiver, sender, b"\xff" * 32, point, None, proof
+    )
+
+
+def test_wrong_length_raises() -> None:
+    receiver, sender, point, proof = _inputs()
+    with pytest.raises(ValueError):
+        tos_signer.verify_ciphertext_validity_proof(
+            receiver, sender, point, point, None, proof[:128]
+        )
+    with pytest.raises(ValueError):
+        tos_signer.verify_ciphertext_validity_proof(
+            receiver, sender, point, point, point[:31], proof
+        )
@@ -0,0 +1,36 @@
+"""tos_signer decode_signing_bytes splits a frame built by build_signing_bytes."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SOURCE = bytes(range(32))
+REF_HASH = b"\xab" * 32
+
+
+@pytest.mark.parametrize("payload", [b"", b"\x01", bytes(range(256)) * 4])
+def test_round_trip(payload: bytes) -> None:
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, 3, SOURCE, 8, payload, 2**64 - 1, 1, 42, REF_HASH, 7
+        )
+    )
+    assert tos_signer.decode_signing_bytes(frame) == {
+        "version": 1,
+        "chain_id": 3,
+        "source": SOURCE,
+        "tx_type_id": 8,
+        "payload": payload,
+        "fee": 2**64 - 1,
+        "fee_type": 1,
+        "nonce": 42,
+        "ref_hash": REF_HASH,
+        "ref_topo": 7,
+    }
+
+
+def test_rejects_short_frame() -> None:
+    with pytest.raises(ValueError, match="at least 92 bytes, got 91"):
+        tos_signer.decode_signing_bytes(b"\x00" * 91)
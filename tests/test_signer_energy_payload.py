@@ -0,0 +1,89 @@
+"""tos_signer encode_energy_payload."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+DELEGATEE = b"\x33" * 32
+
+
+def _u32(v: int) -> bytes:
+    return v.to_bytes(4, "big")
+
+
+def _u64(v: int) -> bytes:
+    return v.to_bytes(8, "big")
+
+
+def test_freeze_tos() -> None:
+    payload = bytes(tos_signer.encode_energy_payload(0, amount=1_000, duration_days=7))
+    assert payload == b"\x00" + _u64(1_000) + _u32(7)
+
+
+def test_freeze_tos_delegate() -> None:
+    payload = bytes(
+        tos_signer.encode_energy_payload(
+            1, delegatees=[(DELEGATEE, 5), (b"\x44" * 32, 6)], duration_days=30
+        )
+    )
+    assert payload == (
+        b"\x01"
+        + _u64(2)
+        + DELEGATEE
+        + _u64(5)
+        + b"\x44" * 32
+        + _u64(6)
+        + _u32(30)
+    )
+
+
+@pytest.mark.parametrize(
+    "record_index, delegatee_address, tail",
+    [
+        (None, None, b"\x00\x00"),
+        (3, DELEGATEE, b"\x01" + _u32(3) + b"\x01" + DELEGATEE),
+    ],
+)
+def test_unfreeze_tos(record_index, delegatee_address, tail: bytes) -> None:
+    payload = bytes(
+        tos_signer.encode_energy_payload(
+            2,
+            amount=1_000,
+            from_delegation=True,
+            record_index=record_index,
+            delegatee_address=delegatee_address,
+        )
+    )
+    assert payload == b"\x02" + _u64(1_000) + b"\x01" + tail
+
+
+def test_withdraw_unfrozen() -> None:
+    assert bytes(tos_signer.encode_energy_payload(3)) == b"\x03"
+
+
+@pytest.mark.parametrize(
+    "variant, kwargs, message",
+    [
+        (0, {"duration_days": 7}, "freeze_tos: amount is required"),
+        (0, {"amount": 1}, "freeze_tos: duration_days is required"),
+        (1, {"duration_days": 7}, "freeze_tos_delegate: delegatees is required"),
+        (1, {"delegatees": []}, "freeze_tos_delegate: duration_days is required"),
+        (2, {"amount": 1}, "unfreeze_tos: from_delegation is required"),
+        (2, {"from_delegation": False}, "unfreeze_tos: amount is required"),
+    ],
+)
+def test_missing_field_names_variant(variant: int, kwargs: dict, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        tos_signer.encode_energy_payload(variant, **kwargs)
+
+
+def test_rejects_unknown_variant() -> None:
+    with pytest.raises(ValueError, match="unknown energy payload variant 4"):
+        tos_signer.encode_energy_payload(4)
+
+
+def test_rejects_bad_delegatee_pubkey() -> None:
+    with pytest.raises(ValueError, match=r"delegatees\[0\]\.pubkey must be 32 bytes"):
+        tos_signer.encode_energy_payload(1, delegatees=[(b"\x33" * 31, 5)], duration_days=7)
@@ -0,0 +1,44 @@
+"""tos_signer.get_public_key_from_entropy: 32-byte seeds."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+# Ristretto group order l, little-endian; reduces to zero mod l.
+GROUP_ORDER_LE = bytes.fromhex(
+    "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010"
+)
+
+
+def test_canonical_entropy_matches_private_key_derivation() -> None:
+    entropy = bytes(range(1, 32)) + b"\x00"
+    assert tos_signer.get_public_key_from_entropy(entropy) == (
+        tos_signer.get_public_key_from_private(entropy)
+    )
+
+
+def test_single_byte_entropy_matches_seed_byte_key() -> None:
+    entropy = b"\x07" + b"\x00" * 31
+    assert tos_signer.get_public_key_from_entropy(entropy) == tos_signer.get_public_key(7)
+
+
+def test_entropy_is_reduced_mod_group_order() -> None:
+    one = (1).to_bytes(32, "little")
+    l_plus_one = (int.from_bytes(GROUP_ORDER_LE, "little") + 1).to_bytes(32, "little")
+    assert tos_signer.get_public_key_from_entropy(l_plus_one) == (
+        tos_signer.get_public_key_from_entropy(one)
+    )
+
+
+@pytest.mark.parametrize("entropy", [b"\x00" * 32, GROUP_ORDER_LE])
+def test_rejects_zero_scalar(entropy: bytes) -> None:
+    with pytest.raises(ValueError, match="zero scalar"):
+        tos_signer.get_public_key_from_entropy(entropy)
+
+
+@pytest.mark.parametrize("length", [0, 1, 31, 33, 64])
+def test_rejects_wrong_length(length: int) -> None:
+    with pytest.raises(ValueError, match="entropy must be 32 bytes"):
+        tos_signer.get_public_key_from_entropy(b"\x01" * length)
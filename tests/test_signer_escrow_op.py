@@ -0,0 +1,53 @@
+"""tos_signer.sign_escrow_op dispatcher."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+ARGS = (1, 3, 7, 10_000, 0, b"\x11" * 32, 42)
+ESCROW_ID = b"\xe5" * 32
+
+
+def test_deposit_matches_dedicated_signer() -> None:
+    assert tos_signer.sign_escrow_op(
+        *ARGS, "deposit", escrow_id=ESCROW_ID, amount=500
+    ) == tos_signer.sign_deposit_escrow(*ARGS, ESCROW_ID, 500)
+
+
+@pytest.mark.parametrize("evidence", [None, b"\xee" * 32])
+def test_appeal_matches_dedicated_signer(evidence) -> None:
+    assert tos_signer.sign_escrow_op(
+        *ARGS,
+        "appeal",
+        escrow_id=ESCROW_ID,
+        reason="late",
+        new_evidence_hash=evidence,
+        appeal_deposit=500,
+        appeal_mode=1,
+    ) == tos_signer.sign_appeal_escrow(*ARGS, ESCROW_ID, "late", evidence, 500, 1)
+
+
+def test_appeal_evidence_defaults_to_none() -> None:
+    assert tos_signer.sign_escrow_op(
+        *ARGS, "appeal", escrow_id=ESCROW_ID, reason="r", appeal_deposit=1, appeal_mode=0
+    ) == tos_signer.sign_appeal_escrow(*ARGS, ESCROW_ID, "r", None, 1, 0)
+
+
+def test_missing_argument() -> None:
+    with pytest.raises(ValueError, match="deposit: missing argument 'amount'"):
+        tos_signer.sign_escrow_op(*ARGS, "deposit", escrow_id=ESCROW_ID)
+
+
+@pytest.mark.parametrize(
+    "op_type", ["create", "release", "refund", "challenge", "dispute", "submit_verdict"]
+)
+def test_recognised_ops_without_encoder(op_type: str) -> None:
+    with pytest.raises(ValueError, match="no payload encoder"):
+        tos_signer.sign_escrow_op(*ARGS, op_type)
+
+
+def test_unknown_op_lists_valid_ops() -> None:
+    with pytest.raises(ValueError, match="expected one of create, deposit, release"):
+        tos_signer.sign_escrow_op(*ARGS, "withdraw")
@@ -0,0 +1,47 @@
+"""tos_signer optional extra_data encode/decode round-trip."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+
+def test_encode_none() -> None:
+    assert bytes(tos_signer.encode_optional_extra_data(None)) == b"\x00"
+    assert bytes(tos_signer.encode_optional_extra_data()) == b"\x00"
+
+
+def test_encode_some() -> None:
+    data = b"hello"
+    encoded = bytes(tos_signer.encode_optional_extra_data(data))
+    assert encoded == b"\x01" + len(data).to_bytes(2, "big") + data
+
+
+@pytest.mark.parametrize(
+    "data",
+    [None, b"", b"\x00", b"memo", bytes(range(256)), b"\xff" * 128],
+)
+def test_round_trip(data: bytes | None) -> None:
+    encoded = bytes(tos_signer.encode_optional_extra_data(data))
+    decoded = tos_signer.decode_optional_extra_data(encoded)
+    if data is None:
+        assert decoded is None
+    else:
+        assert bytes(decoded) == data
+
+
+@pytest.mark.parametrize(
+    "raw",
+    [
+        b"",  # missing flag
+        b"\x02",  # invalid flag
+        b"\x00\x00",  # trailing bytes after None
+        b"\x01\x00",  # truncated length
+        b"\x01\x00\x05abc",  # data shorter than length prefix
+        b"\x01\x00\x01ab",  # trailing bytes after data
+    ],
+)
+def test_decode_rejects_malformed(raw: bytes) -> None:
+    with pytest.raises(ValueError):
+        tos_signer.decode_optional_extra_data(raw)
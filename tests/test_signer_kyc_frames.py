@@ -0,0 +1,157 @@
+"""tos_signer KYC signers: payload bytes inside the signed frame."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+ACCOUNT = b"\x21" * 32
+REASON_HASH = b"\x22" * 32
+COMMITTEE_ID = b"\x23" * 32
+PUBKEY = b"\x24" * 32
+SIGNATURE = b"\x25" * 64
+TIMESTAMP = 1_700_000_000
+EXPIRES_AT = 1_700_600_000
+DEST_COMMITTEE_ID = b"\x26" * 32
+DOCUMENTS_HASH = b"\x27" * 32
+
+APPROVALS = [(PUBKEY, SIGNATURE, TIMESTAMP)]
+APPROVALS_WIRE = b"\x00\x01" + PUBKEY + SIGNATURE + TIMESTAMP.to_bytes(8, "big")
+
+
+def _assert_signs(name: str, tx_type_id: int, payload: bytes, payload_args: tuple) -> None:
+    """The signer's signature verifies over a frame carrying exactly `payload`."""
+    source = bytes(tos_signer.get_public_key(SEED))
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, tx_type_id, payload, 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(getattr(tos_signer, name)(SEED, *FRAME_ARGS, *payload_args))
+    assert tos_signer.verify_signature(frame, signature, source)
+
+    # Flipping the first payload byte (after version, chain_id, source and
+    # tx_type) must break the signature.
+    tampered = bytearray(frame)
+    tampered[35] ^= 0x01
+    assert not tos_signer.verify_signature(bytes(tampered), signature, source)
+
+
+def test_revoke_kyc_payload() -> None:
+    payload = ACCOUNT + REASON_HASH + COMMITTEE_ID + APPROVALS_WIRE
+    assert bytes(
+        tos_signer.encode_kyc_revoke_payload(ACCOUNT, REASON_HASH, COMMITTEE_ID, APPROVALS)
+    ) == payload
+    _assert_signs("sign_revoke_kyc", 10, payload, (ACCOUNT, REASON_HASH, COMMITTEE_ID, APPROVALS))
+
+
+def test_renew_kyc_payload() -> None:
+    payload = ACCOUNT + TIMESTAMP.to_bytes(8, "big") + REASON_HASH + COMMITTEE_ID + APPROVALS_WIRE
+    assert bytes(
+        tos_signer.encode_kyc_renew_payload(
+            ACCOUNT, TIMESTAMP, REASON_HASH, COMMITTEE_ID, APPROVALS
+        )
+    ) == payload
+    _assert_signs(
+        "sign_renew_kyc", 11, payload, (ACCOUNT, TIMESTAMP, REASON_HASH, COMMITTEE_ID, APPROVALS)
+    )
+
+
+def test_emergency_suspend_payload() -> None:
+    payload = (
+        ACCOUNT + REASON_HASH + COMMITTEE_ID + APPROVALS_WIRE + EXPIRES_AT.to_bytes(8, "big")
+    )
+    _assert_signs(
+        "sign_emergency_suspend",
+        15,
+        payload,
+        (ACCOUNT, REASON_HASH, COMMITTEE_ID, APPROVALS, EXPIRES_AT),
+    )
+
+
+def test_emergency_suspend_without_approvals() -> None:
+    payload = ACCOUNT + REASON_HASH + COMMITTEE_ID + b"\x00\x00" + EXPIRES_AT.to_bytes(8, "big")
+    _assert_signs(
+        "sign_emergency_suspend", 15, payload, (ACCOUNT, REASON_HASH, COMMITTEE_ID, [], EXPIRES_AT)
+    )
+
+
+def test_transfer_kyc_payload() -> None:
+    dest_approvals = [(b"\x28" * 32, SIGNATURE, TIMESTAMP + 1), (PUBKEY, SIGNATURE, TIMESTAMP)]
+    payload = (
+        ACCOUNT
+        + COMMITTEE_ID
+        + APPROVALS_WIRE
+        + DEST_COMMITTEE_ID
+        + b"\x00\x02"
+        + b"\x28" * 32
+        + SIGNATURE
+        + (TIMESTAMP + 1).to_bytes(8, "big")
+        + PUBKEY
+        + SIGNATURE
+        + TIMESTAMP.to_bytes(8, "big")
+        + REASON_HASH
+        + TIMESTAMP.to_bytes(8, "big")
+    )
+    _assert_signs(
+        "sign_transfer_kyc",
+        16,
+        payload,
+        (
+            ACCOUNT,
+            COMMITTEE_ID,
+            APPROVALS,
+            DEST_COMMITTEE_ID,
+            dest_approvals,
+            REASON_HASH,
+            TIMESTAMP,
+        ),
+    )
+
+
+def test_appeal_kyc_payload() -> None:
+    payload = (
+        ACCOUNT
+        + COMMITTEE_ID
+        + DEST_COMMITTEE_ID
+        + REASON_HASH
+        + DOCUMENTS_HASH
+        + TIMESTAMP.to_bytes(8, "big")
+    )
+    assert len(payload) == 168
+    _assert_signs(
+        "sign_appeal_kyc",
+        17,
+        payload,
+        (ACCOUNT, COMMITTEE_ID, DEST_COMMITTEE_ID, REASON_HASH, DOCUMENTS_HASH, TIMESTAMP),
+    )
+
+
+def test_transfer_kyc_names_bad_dest_approval() -> None:
+    with pytest.raises(ValueError, match=r"dest_approvals\[0\]\.signature: expected 64 bytes"):
+        tos_signer.sign_transfer_kyc(
+            SEED,
+            *FRAME_ARGS,
+            ACCOUNT,
+            COMMITTEE_ID,
+            APPROVALS,
+            DEST_COMMITTEE_ID,
+            [(PUBKEY, SIGNATURE[:63], TIMESTAMP)],
+            REASON_HASH,
+            TIMESTAMP,
+        )
+
+
+@pytest.mark.parametrize("name", ["sign_revoke_kyc", "sign_emergency_suspend"])
+def test_rejects_short_reason_hash(name: str) -> None:
+    args = (ACCOUNT, b"\x22" * 31, COMMITTEE_ID, APPROVALS)
+    if name == "sign_emergency_suspend":
+        args += (EXPIRES_AT,)
+    with pytest.raises(ValueError, match="reason_hash must be 32 bytes, got 31"):
+        getattr(tos_signer, name)(SEED, *FRAME_ARGS, *args)
@@ -0,0 +1,87 @@
+"""tos_signer encode_kyc_set / revoke / renew payload encoders."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+ACCOUNT = b"\x21" * 32
+DATA_HASH = b"\x22" * 32
+COMMITTEE_ID = b"\x23" * 32
+PUBKEY = b"\x24" * 32
+SIGNATURE = b"\x25" * 64
+TIMESTAMP = 1_700_000_000
+
+APPROVAL_TUPLE = (PUBKEY, SIGNATURE, TIMESTAMP)
+APPROVAL_DICT = {"pubkey": PUBKEY, "signature": SIGNATURE, "timestamp": TIMESTAMP}
+APPROVALS_WIRE = b"\x00\x01" + PUBKEY + SIGNATURE + TIMESTAMP.to_bytes(8, "big")
+
+
+def test_set_payload_layout() -> None:
+    payload = bytes(
+        tos_signer.encode_kyc_set_payload(
+            ACCOUNT, 7, TIMESTAMP, DATA_HASH, COMMITTEE_ID, [APPROVAL_TUPLE]
+        )
+    )
+    assert payload == (
+        ACCOUNT
+        + (7).to_bytes(2, "big")
+        + TIMESTAMP.to_bytes(8, "big")
+        + DATA_HASH
+        + COMMITTEE_ID
+        + APPROVALS_WIRE
+    )
+
+
+def test_revoke_payload_layout() -> None:
+    payload = bytes(
+        tos_signer.encode_kyc_revoke_payload(ACCOUNT, DATA_HASH, COMMITTEE_ID, [APPROVAL_TUPLE])
+    )
+    assert payload == ACCOUNT + DATA_HASH + COMMITTEE_ID + APPROVALS_WIRE
+
+
+def test_renew_payload_layout() -> None:
+    payload = bytes(
+        tos_signer.encode_kyc_renew_payload(
+            ACCOUNT, TIMESTAMP, DATA_HASH, COMMITTEE_ID, [APPROVAL_TUPLE]
+        )
+    )
+    assert payload == (
+        ACCOUNT + TIMESTAMP.to_bytes(8, "big") + DATA_HASH + COMMITTEE_ID + APPROVALS_WIRE
+    )
+
+
+def test_tuple_and_dict_approvals_encode_identically() -> None:
+    args = (ACCOUNT, DATA_HASH, COMMITTEE_ID)
+    assert tos_signer.encode_kyc_revoke_payload(*args, [APPROVAL_TUPLE]) == (
+        tos_signer.encode_kyc_revoke_payload(*args, [APPROVAL_DICT])
+    )
+
+
+def test_each_approval_is_104_bytes() -> None:
+    args = (ACCOUNT, DATA_HASH, COMMITTEE_ID)
+    one = bytes(tos_signer.encode_kyc_revoke_payload(*args, [APPROVAL_TUPLE]))
+    three = bytes(tos_signer.encode_kyc_revoke_payload(*args, [APPROVAL_TUPLE] * 3))
+    assert len(three) - len(one) == 2 * 104
+
+
+@pytest.mark.parametrize(
+    "approval, message",
+    [
+        ((PUBKEY[:31], SIGNATURE, TIMESTAMP), r"approvals\[0\]\.pubkey: expected 32 bytes, got 31"),
+        ((PUBKEY, SIGNATURE[:63], TIMESTAMP), r"approvals\[0\]\.signature: expected 64 bytes, got 63"),
+        ((PUBKEY, SIGNATURE), r"approvals\[0\]: expected \(pubkey, signature, timestamp\)"),
+        (b"\x00" * 104, r"approvals\[0\]: expected a dict or tuple"),
+    ],
+)
+def test_rejects_malformed_approval(approval, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        tos_signer.encode_kyc_revoke_payload(ACCOUNT, DATA_HASH, COMMITTEE_ID, [approval])
+
+
+def test_rejects_wrong_length_account() -> None:
+    with pytest.raises(ValueError, match="account must be 32 bytes, got 31"):
+        tos_signer.encode_kyc_renew_payload(
+            ACCOUNT[:31], TIMESTAMP, DATA_HASH, COMMITTEE_ID, []
+        )
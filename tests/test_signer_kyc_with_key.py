@@ -0,0 +1,73 @@
+"""tos_signer raw-key variants of the KYC and committee signers."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+PRIVATE_KEY = b"\x01" + b"\x00" * 31
+FRAME_ARGS = (CHAIN_ID, 7, 0, 0, REF_HASH, 42)
+
+ACCOUNT = b"\x21" * 32
+DATA_HASH = b"\x22" * 32
+COMMITTEE_ID = b"\x23" * 32
+APPROVALS = [{"pubkey": b"\x24" * 32, "signature": b"\x00" * 64, "timestamp": 1_700_000_000}]
+MEMBERS = [{"pubkey": b"\x25" * 32, "name": "chair", "role": 0}]
+
+CASES = [
+    ("sign_set_kyc", (ACCOUNT, 7, 1_700_000_000, DATA_HASH, COMMITTEE_ID, APPROVALS)),
+    ("sign_revoke_kyc", (ACCOUNT, DATA_HASH, COMMITTEE_ID, APPROVALS)),
+    ("sign_renew_kyc", (ACCOUNT, 1_700_000_000, DATA_HASH, COMMITTEE_ID, APPROVALS)),
+    ("sign_emergency_suspend", (ACCOUNT, DATA_HASH, COMMITTEE_ID, APPROVALS, 1_700_600_000)),
+    (
+        "sign_transfer_kyc",
+        (ACCOUNT, COMMITTEE_ID, APPROVALS, b"\x26" * 32, APPROVALS, DATA_HASH, 1_700_000_000),
+    ),
+    (
+        "sign_appeal_kyc",
+        (ACCOUNT, COMMITTEE_ID, b"\x26" * 32, DATA_HASH, b"\x27" * 32, 1_700_000_000),
+    ),
+    ("sign_bootstrap_committee", ("Global", MEMBERS, 1, 1, 32767)),
+    (
+        "sign_register_committee",
+        ("APAC", 1, MEMBERS, 1, 1, 255, COMMITTEE_ID, APPROVALS),
+    ),
+    ("sign_update_committee", (COMMITTEE_ID, "update_threshold", {"threshold": 1}, APPROVALS)),
+]
+
+
+@pytest.mark.parametrize("name, payload_args", CASES, ids=[c[0] for c in CASES])
+def test_with_key_matches_seed_byte_key(name: str, payload_args: tuple) -> None:
+    seed_fn = getattr(tos_signer, name)
+    key_fn = getattr(tos_signer, f"{name}_with_key")
+    assert key_fn(PRIVATE_KEY, *FRAME_ARGS, *payload_args) == seed_fn(
+        1, *FRAME_ARGS, *payload_args
+    )
+
+
+@pytest.mark.parametrize("name, payload_args", CASES, ids=[c[0] for c in CASES])
+def test_with_key_rejects_bad_private_key(name: str, payload_args: tuple) -> None:
+    key_fn = getattr(tos_signer, f"{name}_with_key")
+    with pytest.raises(ValueError, match="private_key must be 32 bytes"):
+        key_fn(b"\x01" * 16, *FRAME_ARGS, *payload_args)
+
+
+def test_set_kyc_signature_covers_payload() -> None:
+    source = bytes(tos_signer.get_public_key(1))
+    payload = (
+        ACCOUNT
+        + (7).to_bytes(2, "big")
+        + (1_700_000_000).to_bytes(8, "big")
+        + DATA_HASH
+        + COMMITTEE_ID
+        + b"\x00\x01"
+        + b"\x24" * 32
+        + b"\x00" * 64
+        + (1_700_000_000).to_bytes(8, "big")
+    )
+    frame = tos_signer.build_signing_bytes(1, CHAIN_ID, source, 9, payload, 0, 0, 7, REF_HASH, 42)
+    signature = tos_signer.sign_set_kyc(1, *FRAME_ARGS, *CASES[0][1])
+    assert tos_signer.verify_signature(bytes(frame), bytes(signature), source)
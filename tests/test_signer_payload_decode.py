@@ -0,0 +1,87 @@
+"""tos_signer transfer / burn payload decoders."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+ASSET = b"\x00" * 32
+DEST = b"\x22" * 32
+
+
+def _transfers(decoded):
+    return [
+        (bytes(a), bytes(d), amount, None if extra is None else bytes(extra))
+        for a, d, amount, extra in decoded
+    ]
+
+
+def test_transfer_round_trip() -> None:
+    transfers = [(ASSET, DEST, 100, None), (ASSET, b"\x33" * 32, 2**64 - 1, b"memo")]
+    payload = bytes(tos_signer.encode_transfer_payload(transfers))
+    assert _transfers(tos_signer.decode_transfer_payload(payload)) == transfers
+
+
+def test_burn_round_trip() -> None:
+    payload = bytes(tos_signer.encode_burn_payload(ASSET, 1_000))
+    asset, amount = tos_signer.decode_burn_payload(payload)
+    assert (bytes(asset), amount) == (ASSET, 1_000)
+
+
+def test_transfer_truncation_reports_offset() -> None:
+    payload = bytes(tos_signer.encode_transfer_payload([(ASSET, DEST, 100, None)]))
+    with pytest.raises(
+        ValueError,
+        match=r"transfers\[0\]\.amount: truncated at offset 66: expected 8 bytes, got 4",
+    ):
+        tos_signer.decode_transfer_payload(payload[:70])
+
+
+def test_burn_truncation_reports_offset() -> None:
+    with pytest.raises(
+        ValueError, match="amount: truncated at offset 32: expected 8 bytes, got 7"
+    ):
+        tos_signer.decode_burn_payload(ASSET + b"\x00" * 7)
+
+
+def test_rejects_bad_extra_data_flag() -> None:
+    payload = bytearray(tos_signer.encode_transfer_payload([(ASSET, DEST, 1, None)]))
+    payload[-1] = 2
+    with pytest.raises(ValueError, match="invalid option flag 2 at offset 74"):
+        tos_signer.decode_transfer_payload(bytes(payload))
+
+
+@pytest.mark.parametrize(
+    "decoder,payload",
+    [
+        (tos_signer.decode_burn_payload, ASSET + b"\x00" * 9),
+        (
+            tos_signer.decode_transfer_payload,
+            bytes(tos_signer.encode_transfer_payload([(ASSET, DEST, 1, None)])) + b"\x00",
+        ),
+    ],
+)
+def test_rejects_trailing_bytes(decoder, payload: bytes) -> None:
+    with pytest.raises(ValueError, match="1 trailing bytes"):
+        decoder(payload)
+
+
+def test_rejects_empty_transfer_list() -> None:
+    with pytest.raises(ValueError, match="must not be empty"):
+        tos_signer.decode_transfer_payload(b"\x00\x00")
+
+
+def test_rejects_count_above_max_transfer_count() -> None:
+    with pytest.raises(ValueError, match="count: 501 exceeds maximum of 500"):
+        tos_signer.decode_transfer_payload((501).to_bytes(2, "big"))
+
+
+def test_overstated_count_reports_missing_transfer() -> None:
+    payload = bytearray(tos_signer.encode_transfer_payload([(ASSET, DEST, 1, None)]))
+    payload[1] = 2
+    with pytest.raises(
+        ValueError,
+        match=r"transfers\[1\]\.asset: truncated at offset 75: expected 32 bytes, got 0",
+    ):
+        tos_signer.decode_transfer_payload(bytes(payload))
@@ -0,0 +1,92 @@
+"""tos_signer Writer/Reader primitives outside the fixed transaction formats."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+from tos_spec.encoding import Writer
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "00"),
+        (127, "7f"),
+        (128, "8001"),
+        (255, "ff01"),
+        (256, "8002"),
+        (2**32 - 1, "ffffffff0f"),
+        (2**64 - 1, "ffffffffffffffffff01"),
+    ],
+)
+def test_varint_round_trip(value: int, expected: str) -> None:
+    encoded = bytes(tos_signer.encode_varint(value))
+    assert encoded.hex() == expected
+    assert tos_signer.decode_varint(encoded) == value
+
+
+@pytest.mark.parametrize(
+    "data,match",
+    [
+        (b"", "truncated"),
+        (b"\x80", "truncated"),
+        (b"\xff" * 9 + b"\x02", "overflows u64"),
+        (b"\x80" * 10 + b"\x01", "longer than 10 bytes"),
+        (b"\x00\x00", "trailing"),
+    ],
+)
+def test_decode_varint_rejects_malformed(data: bytes, match: str) -> None:
+    with pytest.raises(ValueError, match=match):
+        tos_signer.decode_varint(data)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "00" * 16),
+        (1 << 64, "0000000000000001" + "00" * 8),
+        (2**128 - 1, "ff" * 16),
+    ],
+)
+def test_u128_round_trip(value: int, expected: str) -> None:
+    encoded = bytes(tos_signer.encode_u128(value))
+    assert encoded.hex() == expected
+    assert tos_signer.decode_u128(encoded) == value
+
+
+def test_u128_out_of_range() -> None:
+    with pytest.raises(OverflowError):
+        tos_signer.encode_u128(2**128)
+
+
+@pytest.mark.parametrize("data", [b"", b"\x00" * 15, b"\x00" * 17])
+def test_decode_u128_rejects_wrong_length(data: bytes) -> None:
+    with pytest.raises(ValueError):
+        tos_signer.decode_u128(data)
+
+
+def test_i64_minus_one() -> None:
+    encoded = bytes(tos_signer.encode_i64(-1))
+    assert encoded.hex() == "ffffffffffffffff"
+    assert tos_signer.decode_i64(encoded) == -1
+
+
+@pytest.mark.parametrize("value", [0, 1, -1, -(2**63), 2**63 - 1])
+def test_i64_round_trip_matches_python_writer(value: int) -> None:
+    w = Writer(bytearray())
+    w.write_i64(value)
+    encoded = bytes(tos_signer.encode_i64(value))
+    assert encoded == bytes(w.buf)
+    assert tos_signer.decode_i64(encoded) == value
+
+
+def test_i64_out_of_range() -> None:
+    with pytest.raises(OverflowError):
+        tos_signer.encode_i64(2**63)
+
+
+@pytest.mark.parametrize("data", [b"", b"\xff" * 7, b"\xff" * 9])
+def test_decode_i64_rejects_wrong_length(data: bytes) -> None:
+    with pytest.raises(ValueError):
+        tos_signer.decode_i64(data)
@@ -0,0 +1,87 @@
+"""tos_signer Shield/Unshield/UnoTransfers payload encoders."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+ASSET = b"\x00" * 32
+DEST = b"\x22" * 32
+COMMITMENT = b"\x33" * 32
+HANDLE = b"\x44" * 32
+
+
+def _head(extra: bytes) -> bytes:
+    return b"\x00\x01" + ASSET + DEST + (500).to_bytes(8, "big") + extra
+
+
+def test_shield_payload_layout() -> None:
+    proof = b"\x55" * 96
+    payload = tos_signer.encode_shield_payload(ASSET, DEST, 500, None, COMMITMENT, HANDLE, proof)
+    assert bytes(payload) == _head(b"\x00") + COMMITMENT + HANDLE + proof
+
+
+def test_unshield_payload_with_extra_data() -> None:
+    ct_proof = b"\x66" * 160
+    payload = tos_signer.encode_unshield_payload(
+        ASSET, DEST, 500, b"memo", COMMITMENT, HANDLE, ct_proof
+    )
+    assert bytes(payload) == _head(b"\x01\x00\x04memo") + COMMITMENT + HANDLE + ct_proof
+
+
+def test_shield_payload_matches_sign_shield_transfer() -> None:
+    commitment, handle, proof = tos_signer.make_shield_crypto(2, 500)
+    dest = bytes(tos_signer.get_public_key(2))
+    payload = tos_signer.encode_shield_payload(
+        ASSET, dest, 500, None, bytes(commitment), bytes(handle), bytes(proof)
+    )
+    frame = tos_signer.build_signing_bytes(
+        1, 0, bytes(tos_signer.get_public_key(1)), 19, bytes(payload), 0, 0, 7, b"\x11" * 32, 42
+    )
+    signature = tos_signer.sign_shield_transfer(1, 0, 7, 0, 0, b"\x11" * 32, 42, 2, ASSET, 500)[0]
+    assert tos_signer.verify_signature(
+        bytes(frame), bytes(signature), bytes(tos_signer.get_public_key(1))
+    )
+
+
+@pytest.mark.parametrize(
+    "field, args",
+    [
+        ("destination", (ASSET, DEST[:31], 1, None, COMMITMENT, HANDLE, b"\x00" * 96)),
+        ("receiver_handle", (ASSET, DEST, 1, None, COMMITMENT, HANDLE + b"\x00", b"\x00" * 96)),
+        ("proof", (ASSET, DEST, 1, None, COMMITMENT, HANDLE, b"\x00" * 160)),
+    ],
+)
+def test_shield_rejects_bad_lengths(field: str, args: tuple) -> None:
+    with pytest.raises(ValueError, match=f"{field} must be"):
+        tos_signer.encode_shield_payload(*args)
+
+
+def test_unshield_rejects_short_ct_proof() -> None:
+    with pytest.raises(ValueError, match="ct_proof must be 160 bytes, got 128"):
+        tos_signer.encode_unshield_payload(
+            ASSET, DEST, 1, None, COMMITMENT, HANDLE, b"\x00" * 128
+        )
+
+
+def test_uno_transfer_payload_layout() -> None:
+    receiver_handle = b"\x77" * 32
+    ct_proof = b"\x66" * 160
+    payload = tos_signer.encode_uno_transfer_payload(
+        ASSET, DEST, None, COMMITMENT, HANDLE, receiver_handle, ct_proof
+    )
+    assert bytes(payload) == (
+        b"\x00\x01" + ASSET + DEST + b"\x00" + COMMITMENT + HANDLE + receiver_handle + ct_proof
+    )
+
+
+@pytest.mark.parametrize(
+    "field, index, size",
+    [("sender_handle", 4, 32), ("receiver_handle", 5, 32), ("ct_proof", 6, 160)],
+)
+def test_uno_transfer_rejects_bad_lengths(field: str, index: int, size: int) -> None:
+    args = [ASSET, DEST, None, COMMITMENT, HANDLE, HANDLE, b"\x00" * 160]
+    args[index] = b"\x00" * (size - 1)
+    with pytest.raises(ValueError, match=f"{field} must be {size} bytes, got {size - 1}"):
+        tos_signer.encode_uno_transfer_payload(*args)
@@ -0,0 +1,51 @@
+"""tos_signer.generate_random_commitment / generate_receiver_handle."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+RECEIVER = bytes(tos_signer.get_public_key(2))
+
+
+def test_commitment_and_opening_sizes() -> None:
+    commitment, opening = tos_signer.generate_random_commitment(1_000)
+    assert len(commitment) == 32
+    assert len(opening) == 32
+
+
+def test_openings_are_fresh() -> None:
+    first = tos_signer.generate_random_commitment(1_000)
+    second = tos_signer.generate_random_commitment(1_000)
+    assert first[1] != second[1]
+    assert first[0] != second[0]
+
+
+def test_receiver_handle_is_deterministic_in_opening() -> None:
+    _, opening = tos_signer.generate_random_commitment(5)
+    handle = tos_signer.generate_receiver_handle(bytes(opening), RECEIVER)
+    assert len(handle) == 32
+    assert handle == tos_signer.generate_receiver_handle(bytes(opening), RECEIVER)
+
+
+def test_receiver_handle_rejects_invalid_pubkey() -> None:
+    _, opening = tos_signer.generate_random_commitment(5)
+    with pytest.raises(ValueError, match="receiver_pubkey is not a valid Ristretto point"):
+        tos_signer.generate_receiver_handle(bytes(opening), b"\xff" * 32)
+
+
+def test_receiver_handle_rejects_non_canonical_opening() -> None:
+    with pytest.raises(ValueError, match="opening is not a canonical scalar"):
+        tos_signer.generate_receiver_handle(b"\xff" * 32, RECEIVER)
+
+
+@pytest.mark.parametrize("opening,pubkey", [(b"\x01" * 31, RECEIVER), (b"\x01" * 32, b"\x00" * 33)])
+def test_receiver_handle_rejects_wrong_lengths(opening: bytes, pubkey: bytes) -> None:
+    with pytest.raises(ValueError, match="must be 32 bytes"):
+        tos_signer.generate_receiver_handle(opening, pubkey)
+
+
+def test_amount_beyond_u64() -> None:
+    with pytest.raises(OverflowError):
+        tos_signer.generate_random_commitment(1 << 64)
@@ -0,0 +1,54 @@
+"""tos_signer TNS RegisterName signers."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+
+
+def _frame(source: bytes, name: str) -> bytes:
+    payload = bytes([len(name)]) + name.encode()
+    return bytes(
+        tos_signer.build_signing_bytes(1, CHAIN_ID, source, 21, payload, 0, 0, 7, REF_HASH, 42)
+    )
+
+
+def test_seed_byte_signature_covers_name() -> None:
+    source = bytes(tos_signer.get_public_key(1))
+    signature = bytes(tos_signer.sign_register_name(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, "alice"))
+    assert tos_signer.verify_signature(_frame(source, "alice"), signature, source)
+    assert not tos_signer.verify_signature(_frame(source, "alicf"), signature, source)
+
+
+def test_with_key_matches_seed_byte_key() -> None:
+    private_key = b"\x01" + b"\x00" * 31
+    assert tos_signer.sign_register_name_with_key(
+        private_key, CHAIN_ID, 7, 0, 0, REF_HASH, 42, "alice"
+    ) == tos_signer.sign_register_name(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, "alice")
+
+
+def test_with_key_rejects_bad_private_key() -> None:
+    with pytest.raises(ValueError, match="private_key must be 32 bytes"):
+        tos_signer.sign_register_name_with_key(
+            b"\x01" * 33, CHAIN_ID, 7, 0, 0, REF_HASH, 42, "alice"
+        )
+
+
+def test_rejects_name_over_255_bytes() -> None:
+    with pytest.raises(ValueError, match="tns_name"):
+        tos_signer.sign_register_name(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, "a" * 256)
+
+
+@pytest.mark.parametrize("name", ["abc", "a" * 64, "alice"])
+def test_encode_tns_register_payload(name: str) -> None:
+    assert bytes(tos_signer.encode_tns_register_payload(name)) == bytes([len(name)]) + name.encode()
+
+
+@pytest.mark.parametrize("name", ["", "ab", "a" * 65])
+def test_encode_tns_register_payload_rejects_length(name: str) -> None:
+    with pytest.raises(ValueError, match=f"name must be 3-64 bytes, got {len(name)}"):
+        tos_signer.encode_tns_register_payload(name)
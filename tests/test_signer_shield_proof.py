@@ -0,0 +1,46 @@
+"""tos_signer ShieldCommitmentProof verification against make_shield_crypto."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+
+def _shield(dest_seed: int = 2, amount: int = 100_000_000):
+    commitment, handle, proof = tos_signer.make_shield_crypto(dest_seed, amount)
+    dest = bytes(tos_signer.get_public_key(dest_seed))
+    return dest, bytes(commitment), bytes(handle), bytes(proof), amount
+
+
+def test_valid_proof() -> None:
+    dest, commitment, handle, proof, amount = _shield()
+    assert tos_signer.verify_shield_proof(dest, commitment, handle, proof, amount)
+
+
+def test_wrong_amount() -> None:
+    dest, commitment, handle, proof, amount = _shield()
+    assert not tos_signer.verify_shield_proof(dest, commitment, handle, proof, amount + 1)
+
+
+def test_wrong_destination() -> None:
+    _, commitment, handle, proof, amount = _shield()
+    other = bytes(tos_signer.get_public_key(3))
+    assert not tos_signer.verify_shield_proof(other, commitment, handle, proof, amount)
+
+
+def test_tampered_proof() -> None:
+    dest, commitment, handle, proof, amount = _shield()
+    tampered = proof[:64] + bytes([proof[64] ^ 1]) + proof[65:]
+    assert not tos_signer.verify_shield_proof(dest, commitment, handle, tampered, amount)
+
+
+def test_invalid_point_is_false() -> None:
+    dest, commitment, handle, proof, amount = _shield()
+    assert not tos_signer.verify_shield_proof(dest, b"\xff" * 32, handle, proof, amount)
+
+
+def test_wrong_length_raises() -> None:
+    dest, commitment, handle, proof, amount = _shield()
+    with pytest.raises(ValueError):
+        tos_signer.verify_shield_proof(dest, commitment, handle, proof[:95], amount)
@@ -0,0 +1,74 @@
+"""tos_signer ShieldTransfers signers."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+ASSET = b"\x00" * 32
+AMOUNT = 100_000_000
+
+
+def _frame(source: bytes, dest: bytes, commitment, handle, proof) -> bytes:
+    payload = (
+        b"\x00\x01"
+        + ASSET
+        + dest
+        + AMOUNT.to_bytes(8, "big")
+        + b"\x00"
+        + bytes(commitment)
+        + bytes(handle)
+        + bytes(proof)
+    )
+    return bytes(
+        tos_signer.build_signing_bytes(1, CHAIN_ID, source, 19, payload, 0, 0, 7, REF_HASH, 42)
+    )
+
+
+def test_seed_byte_matches_make_shield_crypto_and_verifies() -> None:
+    signature, commitment, handle, proof = tos_signer.sign_shield_transfer(
+        1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, 2, ASSET, AMOUNT
+    )
+    assert (commitment, handle, proof) == tos_signer.make_shield_crypto(2, AMOUNT)
+
+    source = bytes(tos_signer.get_public_key(1))
+    dest = bytes(tos_signer.get_public_key(2))
+    frame = _frame(source, dest, commitment, handle, proof)
+    assert tos_signer.verify_signature(frame, bytes(signature), source)
+    assert tos_signer.verify_shield_proof(dest, bytes(commitment), bytes(handle), bytes(proof), AMOUNT)
+
+
+def test_with_key_takes_receiver_public_key() -> None:
+    private_key = b"\x01" + b"\x00" * 31
+    dest = bytes(tos_signer.get_public_key(2))
+    signature, commitment, handle, proof = tos_signer.sign_shield_transfer_with_key(
+        private_key, dest, CHAIN_ID, 7, 0, 0, REF_HASH, 42, ASSET, AMOUNT
+    )
+    source = bytes(tos_signer.get_public_key(1))
+    frame = _frame(source, dest, commitment, handle, proof)
+    assert tos_signer.verify_signature(frame, bytes(signature), source)
+    assert tos_signer.verify_shield_proof(dest, bytes(commitment), bytes(handle), bytes(proof), AMOUNT)
+
+
+def test_extra_data_is_signed() -> None:
+    plain = tos_signer.sign_shield_transfer(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, 2, ASSET, AMOUNT)
+    memo = tos_signer.sign_shield_transfer(
+        1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, 2, ASSET, AMOUNT, b"memo"
+    )
+    assert plain[0] != memo[0]
+    assert plain[1:] == memo[1:]
+
+
+def test_with_key_rejects_invalid_dest_key() -> None:
+    with pytest.raises(ValueError, match="dest_key"):
+        tos_signer.sign_shield_transfer_with_key(
+            b"\x01" + b"\x00" * 31, b"\xff" * 32, CHAIN_ID, 7, 0, 0, REF_HASH, 42, ASSET, AMOUNT
+        )
+
+
+def test_rejects_bad_asset() -> None:
+    with pytest.raises(ValueError, match="asset must be 32 bytes"):
+        tos_signer.sign_shield_transfer(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42, 2, b"\x00" * 31, AMOUNT)
@@ -0,0 +1,29 @@
+"""tos_signer.sign_with_key_batch: one key, many messages."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+PRIVATE_KEY = bytes(range(1, 33))
+
+
+def test_matches_single_message_signing() -> None:
+    messages = [b"", b"\x00", b"frame-1", bytes(range(256))]
+    signatures = tos_signer.sign_with_key_batch(messages, PRIVATE_KEY)
+    assert len(signatures) == len(messages)
+    public_key = bytes(tos_signer.get_public_key_from_private(PRIVATE_KEY))
+    for message, signature in zip(messages, signatures):
+        assert bytes(signature) == bytes(tos_signer.sign_with_key(message, PRIVATE_KEY))
+        assert tos_signer.verify_signature(message, bytes(signature), public_key)
+
+
+def test_empty_batch() -> None:
+    assert tos_signer.sign_with_key_batch([], PRIVATE_KEY) == []
+
+
+@pytest.mark.parametrize("length", [0, 31, 33])
+def test_rejects_bad_private_key(length: int) -> None:
+    with pytest.raises(ValueError, match="private_key must be 32 bytes"):
+        tos_signer.sign_with_key_batch([b"msg"], b"\x01" * length)
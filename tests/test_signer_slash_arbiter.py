@@ -0,0 +1,93 @@
+"""tos_signer SlashArbiter encoder and signer."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+COMMITTEE_ID = b"\xc1" * 32
+ARBITER = b"\x22" * 32
+AMOUNT = 1_000_000
+REASON_HASH = b"\x33" * 32
+APPROVER = b"\x44" * 32
+APPROVAL_SIGNATURE = b"\x55" * 64
+TIMESTAMP = 1_700_000_000
+
+HEADER = COMMITTEE_ID + ARBITER + AMOUNT.to_bytes(8, "big") + REASON_HASH
+APPROVAL_WIRE = APPROVER + APPROVAL_SIGNATURE + TIMESTAMP.to_bytes(8, "big")
+
+
+def _encode(approvals: list) -> bytes:
+    return bytes(
+        tos_signer.encode_slash_arbiter_payload(
+            COMMITTEE_ID, ARBITER, AMOUNT, REASON_HASH, approvals
+        )
+    )
+
+
+def test_no_approvals() -> None:
+    assert _encode([]) == HEADER + b"\x00\x00"
+
+
+def test_dict_approval_layout() -> None:
+    approval = {"pubkey": APPROVER, "signature": APPROVAL_SIGNATURE, "timestamp": TIMESTAMP}
+    assert _encode([approval, approval]) == HEADER + b"\x00\x02" + APPROVAL_WIRE * 2
+
+
+def test_tuple_approval_matches_dict() -> None:
+    approval = {"pubkey": APPROVER, "signature": APPROVAL_SIGNATURE, "timestamp": TIMESTAMP}
+    assert _encode([(APPROVER, APPROVAL_SIGNATURE, TIMESTAMP)]) == _encode([approval])
+
+
+@pytest.mark.parametrize(
+    ("approval", "message"),
+    [
+        (
+            (APPROVER[:31], APPROVAL_SIGNATURE, TIMESTAMP),
+            r"approvals\[0\]\.pubkey: expected 32 bytes, got 31",
+        ),
+        (
+            {"pubkey": APPROVER, "signature": APPROVAL_SIGNATURE},
+            r"approvals\[0\]\.timestamp: missing field",
+        ),
+        ((APPROVER, APPROVAL_SIGNATURE), r"approvals\[0\]: expected \(pubkey, signature"),
+    ],
+)
+def test_rejects_malformed_approval(approval, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        _encode([approval])
+
+
+def test_rejects_short_reason_hash() -> None:
+    with pytest.raises(ValueError, match="reason_hash must be 32 bytes, got 31"):
+        tos_signer.encode_slash_arbiter_payload(COMMITTEE_ID, ARBITER, AMOUNT, b"\x33" * 31, [])
+
+
+def test_signature_covers_frame() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    approvals = [(APPROVER, APPROVAL_SIGNATURE, TIMESTAMP)]
+    frame = bytes(
+        tos_signer.build_signing_bytes(
+            1, CHAIN_ID, source, 35, _encode(approvals), 10_000, 0, 7, REF_HASH, 42
+        )
+    )
+    signature = bytes(
+        tos_signer.sign_slash_arbiter(
+            SEED, *FRAME_ARGS, COMMITTEE_ID, ARBITER, AMOUNT, REASON_HASH, approvals
+        )
+    )
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(frame, signature, source)
+
+    other = bytes(
+        tos_signer.sign_slash_arbiter(
+            SEED, *FRAME_ARGS, COMMITTEE_ID, ARBITER, AMOUNT + 1, REASON_HASH, approvals
+        )
+    )
+    assert not tos_signer.verify_signature(frame, other, source)
@@ -0,0 +1,113 @@
+"""tos_signer UpdateArbiter presence-flag encoder and signer."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import yaml
+
+import tos_signer
+
+SEED = 1
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+FRAME_ARGS = (CHAIN_ID, 7, 10_000, 0, REF_HASH, 42)
+
+# Generated from tos_common by gen_arbiter_status_vectors; not committed yet
+# (see AVATAR_TEST_PROGRESS.md), so the cross-check skips until it is.
+STATUS_VECTORS = Path(__file__).parents[1] / "rust_generators/crypto/vectors/arbiter_status.yaml"
+
+
+def test_no_fields_is_flags_only() -> None:
+    assert bytes(tos_signer.encode_update_arbiter_payload()) == b"\x00"
+
+
+def test_all_fields_in_bit_order() -> None:
+    payload = bytes(
+        tos_signer.encode_update_arbiter_payload(
+            name="ab",
+            expertise_domains=[0, 13],
+            fee_basis_points=250,
+            min_escrow_value=1_000,
+            max_escrow_value=2_000,
+            add_stake=5_000,
+            status=1,
+            deactivate=True,
+        )
+    )
+    assert payload == (
+        b"\xff"
+        + b"\x02ab"
+        + b"\x02\x00\x0d"
+        + (250).to_bytes(2, "big")
+        + (1_000).to_bytes(8, "big")
+        + (2_000).to_bytes(8, "big")
+        + (5_000).to_bytes(8, "big")
+        + b"\x01"
+    )
+
+
+@pytest.mark.parametrize(
+    ("kwargs", "expected"),
+    [
+        ({"name": ""}, b"\x01\x00"),
+        ({"expertise_domains": []}, b"\x02\x00"),
+        ({"fee_basis_points": 10_000}, b"\x04\x27\x10"),
+        ({"add_stake": 1}, b"\x20" + (1).to_bytes(8, "big")),
+        ({"status": 3}, b"\x40\x03"),
+        ({"deactivate": True}, b"\x80"),
+    ],
+)
+def test_single_field_sets_its_bit(kwargs: dict, expected: bytes) -> None:
+    assert bytes(tos_signer.encode_update_arbiter_payload(**kwargs)) == expected
+
+
+@pytest.mark.skipif(not STATUS_VECTORS.exists(), reason="arbiter_status.yaml not generated")
+def test_status_matches_arbiter_status_vectors() -> None:
+    vectors = yaml.load(STATUS_VECTORS.read_text(), Loader=yaml.BaseLoader)
+    for vector in vectors["arbiter_status_vectors"]:
+        status = int(vector["status_byte"])
+        if vector["expected_valid"] == "true":
+            payload = bytes(tos_signer.encode_update_arbiter_payload(status=status))
+            assert payload.hex() == vector["wire_hex"]
+        else:
+            with pytest.raises(ValueError, match="unknown arbiter status"):
+                tos_signer.encode_update_arbiter_payload(status=status)
+
+
+@pytest.mark.parametrize(
+    ("kwargs", "message"),
+    [
+        ({"status": 4}, r"status: unknown arbiter status 4 \(expected 0-3\)"),
+        ({"fee_basis_points": 10_001}, "fee_basis_points: must be at most 10000, got 10001"),
+        (
+            {"min_escrow_value": 2, "max_escrow_value": 1},
+            r"min_escrow_value \(2\) must not exceed max_escrow_value \(1\)",
+        ),
+        (
+            {"expertise_domains": [14]},
+            r"expertise_domains\[0\]: unknown expertise domain 14",
+        ),
+        ({"name": "x" * 256}, "name: must be at most 255 bytes, got 256"),
+    ],
+)
+def test_rejects_invalid_field(kwargs: dict, message: str) -> None:
+    with pytest.raises(ValueError, match=message):
+        tos_signer.encode_update_arbiter_payload(**kwargs)
+
+
+def test_signature_covers_frame() -> None:
+    source = bytes(tos_signer.get_public_key(SEED))
+    payload = bytes(tos_signer.encode_update_arbiter_payload(fee_basis_points=250, status=2))
+    frame = bytes(
+        tos_signer.build_signing_bytes(1, CHAIN_ID, source, 34, payload, 10_000, 0, 7, REF_HASH, 42)
+    )
+    signature = bytes(
+        tos_signer.sign_update_arbiter(SEED, *FRAME_ARGS, fee_basis_points=250, status=2)
+    )
+    assert len(signature) == 64
+    assert tos_signer.verify_signature(frame, signature, source)
+
+    other = bytes(tos_signer.sign_update_arbiter(SEED, *FRAME_ARGS, fee_basis_points=250, status=1))
+    assert not tos_signer.verify_signature(frame, other, source)
@@ -0,0 +1,64 @@
+"""tos_signer.verify_signature edge cases."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+# Ristretto group order l
+GROUP_ORDER = 2**252 + 27742317777372353535851937790883648493
+MESSAGE = b"tos-spec verify_signature"
+
+
+def _signed() -> tuple[bytes, bytes]:
+    public_key = bytes(tos_signer.get_public_key(1))
+    return public_key, bytes(tos_signer.sign_data(MESSAGE, 1))
+
+
+def _add_order(scalar: bytes) -> bytes:
+    return (int.from_bytes(scalar, "little") + GROUP_ORDER).to_bytes(32, "little")
+
+
+def test_accepts_sign_data_and_sign_with_key() -> None:
+    public_key, signature = _signed()
+    assert tos_signer.verify_signature(MESSAGE, signature, public_key)
+    with_key = bytes(tos_signer.sign_with_key(MESSAGE, b"\x01" + b"\x00" * 31))
+    assert tos_signer.verify_signature(MESSAGE, with_key, public_key)
+
+
+def test_rejects_other_message_and_key() -> None:
+    public_key, signature = _signed()
+    assert not tos_signer.verify_signature(MESSAGE + b"!", signature, public_key)
+    other_key = bytes(tos_signer.get_public_key(2))
+    assert not tos_signer.verify_signature(MESSAGE, signature, other_key)
+
+
+def test_all_zero_signature_is_false() -> None:
+    public_key, _ = _signed()
+    assert not tos_signer.verify_signature(MESSAGE, b"\x00" * 64, public_key)
+
+
+def test_non_canonical_scalars_are_false() -> None:
+    public_key, signature = _signed()
+    s, e = signature[:32], signature[32:]
+    assert not tos_signer.verify_signature(MESSAGE, _add_order(s) + e, public_key)
+    assert not tos_signer.verify_signature(MESSAGE, s + _add_order(e), public_key)
+
+
+def test_invalid_point_is_false() -> None:
+    _, signature = _signed()
+    assert not tos_signer.verify_signature(MESSAGE, signature, b"\xff" * 32)
+
+
+@pytest.mark.parametrize("length", [0, 31, 33])
+def test_rejects_wrong_public_key_length(length: int) -> None:
+    _, signature = _signed()
+    with pytest.raises(ValueError, match=f"public_key must be 32 bytes, got {length}"):
+        tos_signer.verify_signature(MESSAGE, signature, b"\x01" * length)
+
+
+def test_rejects_wrong_signature_length() -> None:
+    public_key, signature = _signed()
+    with pytest.raises(ValueError, match="signature must be 64 bytes, got 63"):
+        tos_signer.verify_signature(MESSAGE, signature[:63], public_key)
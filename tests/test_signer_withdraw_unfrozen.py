@@ -0,0 +1,39 @@
+"""tos_signer Energy WithdrawUnfrozen signers."""
+
+from __future__ import annotations
+
+import pytest
+
+import tos_signer
+
+CHAIN_ID = 3
+REF_HASH = b"\x11" * 32
+
+
+def _frame(source: bytes) -> bytes:
+    return bytes(
+        tos_signer.build_signing_bytes(1, CHAIN_ID, source, 5, b"\x03", 0, 0, 7, REF_HASH, 42)
+    )
+
+
+def test_seed_byte_signature_covers_variant_byte() -> None:
+    source = bytes(tos_signer.get_public_key(1))
+    signature = bytes(tos_signer.sign_withdraw_unfrozen(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42))
+    assert tos_signer.verify_signature(_frame(source), signature, source)
+
+
+def test_with_key_matches_seed_byte_key() -> None:
+    private_key = b"\x01" + b"\x00" * 31
+    assert tos_signer.sign_withdraw_unfrozen_with_key(
+        private_key, CHAIN_ID, 7, 0, 0, REF_HASH, 42
+    ) == tos_signer.sign_withdraw_unfrozen(1, CHAIN_ID, 7, 0, 0, REF_HASH, 42)
+
+
+def test_with_key_rejects_bad_private_key() -> None:
+    with pytest.raises(ValueError, match="private_key must be 32 bytes"):
+        tos_signer.sign_withdraw_unfrozen_with_key(b"\x01" * 31, CHAIN_ID, 7, 0, 0, REF_HASH, 42)
+
+
+def test_rejects_bad_ref_hash() -> None:
+    with pytest.raises(ValueError, match="ref_hash"):
+        tos_signer.sign_withdraw_unfrozen(1, CHAIN_ID, 7, 0, 0, b"\x11" * 31, 42)
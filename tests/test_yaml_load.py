@@ -0,0 +1,29 @@
+"""tos_yaml load_yaml, the inverse of dump_yaml."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+
+tos_yaml = pytest.importorskip("tos_yaml")
+
+
+def test_round_trips_dump_yaml() -> None:
+    data = {"algorithm": "TOS-Schnorr", "vectors": [{"seed": 1, "ok": True, "note": None}]}
+    yaml_str = tos_yaml.dump_yaml(json.dumps(data))
+    assert json.loads(tos_yaml.load_yaml(yaml_str)) == data
+
+
+def test_preserves_key_order() -> None:
+    loaded = tos_yaml.load_yaml("b: 1\na: 2\n")
+    assert list(json.loads(loaded)) == ["b", "a"]
+
+
+def test_returns_first_document() -> None:
+    assert json.loads(tos_yaml.load_yaml("---\nfirst: 1\n---\nsecond: 2\n")) == {"first": 1}
+
+
+def test_parse_error_reports_line() -> None:
+    with pytest.raises(ValueError, match=r"line \d+"):
+        tos_yaml.load_yaml("ok: 1\nbad: [unclosed\n")